@@ -8,6 +8,7 @@ use crate::context::Context;
 use crate::git::current_branch;
 use crate::path::canonicalize;
 use crate::prelude::*;
+use crate::shell::completer::OBSERVED_ROWS;
 use futures_codec::FramedRead;
 
 use nu_errors::ShellError;
@@ -195,9 +196,17 @@ pub fn load_plugins(context: &mut Context) -> Result<(), ShellError> {
 
 pub struct History;
 
+static HISTORY_CLEARED: AtomicBool = AtomicBool::new(false);
+
 impl History {
+    /// The history file, which can be moved with the `NU_HISTORY_PATH` environment variable.
     pub fn path() -> PathBuf {
         const FNAME: &str = "history.txt";
+
+        if let Some(path) = std::env::var_os("NU_HISTORY_PATH") {
+            return PathBuf::from(path);
+        }
+
         config::user_data()
             .map(|mut p| {
                 p.push(FNAME);
@@ -205,6 +214,26 @@ impl History {
             })
             .unwrap_or_else(|_| PathBuf::from(FNAME))
     }
+
+    /// Notes that `history --clear` emptied the file, so the REPL forgets its own copy too
+    /// instead of writing it back out.
+    pub fn mark_cleared() {
+        HISTORY_CLEARED.store(true, Ordering::SeqCst);
+    }
+
+    fn take_cleared() -> bool {
+        HISTORY_CLEARED.swap(false, Ordering::SeqCst)
+    }
+
+    fn add_entry<H: rustyline::Helper>(rl: &mut Editor<H>, line: String) {
+        rl.add_history_entry(line);
+
+        if History::take_cleared() {
+            rl.clear_history();
+        }
+
+        let _ = rl.save_history(&History::path());
+    }
 }
 
 #[allow(dead_code)]
@@ -241,6 +270,8 @@ pub fn create_default_context(
             whole_stream_command(Pwd),
             whole_stream_command(Ls),
             whole_stream_command(Du),
+            whole_stream_command(Glob),
+            whole_stream_command(PathCommand),
             whole_stream_command(Cd),
             whole_stream_command(Remove),
             whole_stream_command(Open),
@@ -251,6 +282,9 @@ pub fn create_default_context(
             whole_stream_command(Touch),
             whole_stream_command(Cpy),
             whole_stream_command(Date),
+            whole_stream_command(DateFormat),
+            whole_stream_command(DateToTimezone),
+            whole_stream_command(SeqDate),
             whole_stream_command(Calc),
             whole_stream_command(Mkdir),
             whole_stream_command(Move),
@@ -259,8 +293,16 @@ pub fn create_default_context(
             whole_stream_command(Clear),
             whole_stream_command(What),
             whole_stream_command(Which),
+            whole_stream_command(IsAdmin),
+            whole_stream_command(Sleep),
+            whole_stream_command(Poll),
+            whole_stream_command(Timeout),
+            whole_stream_command(Benchmark),
+            whole_stream_command(Complete),
             whole_stream_command(Debug),
             whole_stream_command(Alias),
+            whole_stream_command(Def),
+            whole_stream_command(Source),
             // Statistics
             whole_stream_command(Size),
             whole_stream_command(Count),
@@ -272,6 +314,7 @@ pub fn create_default_context(
             whole_stream_command(Shells),
             whole_stream_command(Enter),
             whole_stream_command(Exit),
+            whole_stream_command(Exec),
             // Viewers
             whole_stream_command(Autoview),
             whole_stream_command(Table),
@@ -280,52 +323,95 @@ pub fn create_default_context(
             whole_stream_command(SplitRow),
             whole_stream_command(Lines),
             whole_stream_command(Trim),
+            whole_stream_command(Matches),
+            whole_stream_command(Eol),
+            whole_stream_command(Fill),
             whole_stream_command(Echo),
+            whole_stream_command(Print),
+            whole_stream_command(Input),
+            whole_stream_command(Keypress),
+            whole_stream_command(TermSize),
             whole_stream_command(Parse),
+            whole_stream_command(DetectColumns),
             // Column manipulation
             whole_stream_command(Reject),
             whole_stream_command(Pick),
             whole_stream_command(Get),
             whole_stream_command(Edit),
             whole_stream_command(Insert),
+            whole_stream_command(Join),
+            whole_stream_command(Upsert),
+            whole_stream_command(MoveColumn),
+            whole_stream_command(IntoType),
             whole_stream_command(SplitBy),
             // Row manipulation
             whole_stream_command(Reverse),
             whole_stream_command(Append),
             whole_stream_command(Prepend),
             whole_stream_command(SortBy),
+            whole_stream_command(Sort),
             whole_stream_command(GroupBy),
             whole_stream_command(First),
             whole_stream_command(Last),
             whole_stream_command(Skip),
             whole_stream_command(Nth),
             whole_stream_command(Drop),
+            whole_stream_command(Take),
             whole_stream_command(Format),
             whole_stream_command(Where),
+            whole_stream_command(All),
+            whole_stream_command(Any),
+            whole_stream_command(Find),
             whole_stream_command(Compact),
+            whole_stream_command(CleanNumbers),
             whole_stream_command(Default),
             whole_stream_command(SkipWhile),
             whole_stream_command(Range),
             whole_stream_command(Rename),
             whole_stream_command(Uniq),
             whole_stream_command(Each),
+            whole_stream_command(ParEach),
             whole_stream_command(IsEmpty),
+            whole_stream_command(IsEmptyStream),
             // Table manipulation
             whole_stream_command(Shuffle),
             whole_stream_command(Wrap),
+            whole_stream_command(As),
+            whole_stream_command(Unwrap),
             whole_stream_command(Pivot),
+            whole_stream_command(FromPairs),
+            whole_stream_command(Roll),
+            whole_stream_command(Cycle),
+            whole_stream_command(Rotate),
             whole_stream_command(Headers),
+            whole_stream_command(Flatten),
+            whole_stream_command(Enumerate),
+            whole_stream_command(Collect),
+            whole_stream_command(Normalize),
             // Data processing
             whole_stream_command(Histogram),
+            whole_stream_command(Aggregate),
             whole_stream_command(Sum),
+            whole_stream_command(Stddev),
+            whole_stream_command(Variance),
+            whole_stream_command(Mode),
+            whole_stream_command(Round),
+            whole_stream_command(Filesize),
+            whole_stream_command(Abs),
+            whole_stream_command(Sqrt),
+            whole_stream_command(Pow),
+            whole_stream_command(Bytes),
+            whole_stream_command(Base32),
             // File format output
             whole_stream_command(ToBSON),
             whole_stream_command(ToCSV),
             whole_stream_command(ToHTML),
             whole_stream_command(ToJSON),
+            whole_stream_command(ToText),
             whole_stream_command(ToSQLite),
             whole_stream_command(ToDB),
             whole_stream_command(ToMarkdown),
+            whole_stream_command(ToNUON),
             whole_stream_command(ToTOML),
             whole_stream_command(ToTSV),
             whole_stream_command(ToURL),
@@ -338,6 +424,7 @@ pub fn create_default_context(
             whole_stream_command(FromINI),
             whole_stream_command(FromBSON),
             whole_stream_command(FromJSON),
+            whole_stream_command(FromNUON),
             whole_stream_command(FromODS),
             whole_stream_command(FromDB),
             whole_stream_command(FromSQLite),
@@ -624,6 +711,7 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             readline = rl.readline_with_initial(&prompt, (&cmd, ""));
             initial_command = None;
         }
+        let readline = read_continuation_lines(&mut rl, readline);
 
         let line = process_line(readline, &mut context, false, true).await;
 
@@ -636,14 +724,12 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
 
         match line {
             LineResult::Success(line) => {
-                rl.add_history_entry(line.clone());
-                let _ = rl.save_history(&History::path());
+                History::add_entry(&mut rl, line.clone());
                 context.maybe_print_errors(Text::from(line));
             }
 
             LineResult::Error(line, err) => {
-                rl.add_history_entry(line.clone());
-                let _ = rl.save_history(&History::path());
+                History::add_entry(&mut rl, line.clone());
 
                 context.with_host(|host| {
                     print_err(err, host, &Text::from(line.clone()));
@@ -688,6 +774,24 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Keeps reading lines while the pipeline so far ends in a `|` or has an open
+/// bracket, so a long pipeline can be split over several lines
+fn read_continuation_lines<H: rustyline::Helper>(
+    rl: &mut Editor<H>,
+    mut readline: Result<String, ReadlineError>,
+) -> Result<String, ReadlineError> {
+    loop {
+        let line = match &readline {
+            Ok(line) if nu_parser::continues_on_next_line(line) => line.clone(),
+            _ => return readline,
+        };
+
+        readline = rl
+            .readline("::: ")
+            .map(|next| format!("{}\n{}", line, next));
+    }
+}
+
 fn chomp_newline(s: &str) -> &str {
     if s.ends_with('\n') {
         &s[..s.len() - 1]
@@ -850,6 +954,18 @@ async fn process_line(
 
             trace!("{:#?}", classified_block);
 
+            let last_command = classified_block
+                .block
+                .block
+                .last()
+                .and_then(|pipeline| pipeline.list.last())
+                .and_then(|command| match command {
+                    ClassifiedCommand::Internal(InternalCommand { args, .. }) => {
+                        Some(args.span.slice(line).to_string())
+                    }
+                    _ => None,
+                });
+
             match run_block(&classified_block.block, ctx, input_stream, &Scope::empty()).await {
                 Ok(input) => {
                     // Running a pipeline gives us back a stream that we can then
@@ -857,6 +973,25 @@ async fn process_line(
                     // values to compute them.
                     use futures::stream::TryStreamExt;
 
+                    // Remember the columns the pipeline ended with, for completing column names
+                    let input = match last_command {
+                        Some(command) => {
+                            let columns = ctx.shell_manager.columns.clone();
+                            columns.reset(&command);
+
+                            let mut observed = 0;
+
+                            InputStream::from_stream(input.map(move |value| {
+                                if observed < OBSERVED_ROWS {
+                                    columns.observe(&command, &value);
+                                    observed += 1;
+                                }
+                                value
+                            }))
+                        }
+                        None => input,
+                    };
+
                     let context = RunnableContext {
                         input,
                         shell_manager: ctx.shell_manager.clone(),
@@ -4,32 +4,55 @@ pub(crate) mod macros;
 mod from_delimited_data;
 mod to_delimited_data;
 
+pub(crate) mod abs;
+pub(crate) mod aggregate;
 pub(crate) mod alias;
+pub(crate) mod all;
+pub(crate) mod any;
 pub(crate) mod append;
 pub(crate) mod args;
+pub(crate) mod as_;
 pub(crate) mod autoview;
+pub(crate) mod base32;
+pub(crate) mod benchmark;
+pub(crate) mod bytes_;
 pub(crate) mod calc;
 pub(crate) mod cd;
 pub(crate) mod classified;
+pub(crate) mod clean_numbers;
 pub(crate) mod clip;
+pub(crate) mod collect;
 pub(crate) mod command;
 pub(crate) mod compact;
+pub(crate) mod complete;
 pub(crate) mod config;
 pub(crate) mod count;
 pub(crate) mod cp;
+pub(crate) mod cycle;
 pub(crate) mod date;
+pub(crate) mod date_format;
+pub(crate) mod date_to_timezone;
 pub(crate) mod debug;
+pub(crate) mod def;
 pub(crate) mod default;
+pub(crate) mod detect_columns;
 pub(crate) mod drop;
 pub(crate) mod du;
 pub(crate) mod each;
 pub(crate) mod echo;
 pub(crate) mod edit;
 pub(crate) mod enter;
+pub(crate) mod enumerate;
+pub(crate) mod eol;
 #[allow(unused)]
 pub(crate) mod evaluate_by;
+pub(crate) mod exec;
 pub(crate) mod exit;
+pub(crate) mod filesize;
+pub(crate) mod fill;
+pub(crate) mod find;
 pub(crate) mod first;
+pub(crate) mod flatten;
 pub(crate) mod format;
 pub(crate) mod from_bson;
 pub(crate) mod from_csv;
@@ -37,7 +60,9 @@ pub(crate) mod from_eml;
 pub(crate) mod from_ics;
 pub(crate) mod from_ini;
 pub(crate) mod from_json;
+pub(crate) mod from_nuon;
 pub(crate) mod from_ods;
+pub(crate) mod from_pairs;
 pub(crate) mod from_sqlite;
 pub(crate) mod from_ssv;
 pub(crate) mod from_toml;
@@ -48,29 +73,44 @@ pub(crate) mod from_xlsx;
 pub(crate) mod from_xml;
 pub(crate) mod from_yaml;
 pub(crate) mod get;
+pub(crate) mod glob;
 pub(crate) mod group_by;
 pub(crate) mod headers;
 pub(crate) mod help;
 pub(crate) mod histogram;
 pub(crate) mod history;
+pub(crate) mod input;
 pub(crate) mod insert;
+pub(crate) mod into;
+pub(crate) mod is_admin;
 pub(crate) mod is_empty;
+pub(crate) mod join;
+pub(crate) mod keypress;
 pub(crate) mod last;
 pub(crate) mod lines;
 pub(crate) mod ls;
 #[allow(unused)]
 pub(crate) mod map_max_by;
+pub(crate) mod matches;
 pub(crate) mod mkdir;
+pub(crate) mod mode;
+pub(crate) mod move_;
 pub(crate) mod mv;
 pub(crate) mod next;
+pub(crate) mod normalize;
 pub(crate) mod nth;
 pub(crate) mod open;
+pub(crate) mod par_each;
 pub(crate) mod parse;
+pub(crate) mod path;
 pub(crate) mod pick;
 pub(crate) mod pivot;
 pub(crate) mod plugin;
+pub(crate) mod poll;
+pub(crate) mod pow;
 pub(crate) mod prepend;
 pub(crate) mod prev;
+pub(crate) mod print;
 pub(crate) mod pwd;
 pub(crate) mod range;
 #[allow(unused)]
@@ -79,35 +119,51 @@ pub(crate) mod reject;
 pub(crate) mod rename;
 pub(crate) mod reverse;
 pub(crate) mod rm;
+pub(crate) mod roll;
+pub(crate) mod rotate;
+pub(crate) mod round;
 pub(crate) mod run_alias;
+pub(crate) mod run_custom;
 pub(crate) mod run_external;
 pub(crate) mod save;
+pub(crate) mod seq_date;
 pub(crate) mod shells;
 pub(crate) mod shuffle;
 pub(crate) mod size;
 pub(crate) mod skip;
 pub(crate) mod skip_while;
+pub(crate) mod sleep;
+pub(crate) mod sort;
 pub(crate) mod sort_by;
+pub(crate) mod source;
 pub(crate) mod split_by;
 pub(crate) mod split_column;
 pub(crate) mod split_row;
+pub(crate) mod stddev;
 pub(crate) mod sum;
 #[allow(unused)]
 pub(crate) mod t_sort_by;
 pub(crate) mod table;
 pub(crate) mod tags;
+pub(crate) mod take;
+pub(crate) mod term_size;
+pub(crate) mod timeout;
 pub(crate) mod to_bson;
 pub(crate) mod to_csv;
 pub(crate) mod to_html;
 pub(crate) mod to_json;
 pub(crate) mod to_md;
+pub(crate) mod to_nuon;
 pub(crate) mod to_sqlite;
+pub(crate) mod to_text;
 pub(crate) mod to_toml;
 pub(crate) mod to_tsv;
 pub(crate) mod to_url;
 pub(crate) mod to_yaml;
 pub(crate) mod trim;
 pub(crate) mod uniq;
+pub(crate) mod unwrap;
+pub(crate) mod upsert;
 pub(crate) mod version;
 pub(crate) mod what;
 pub(crate) mod where_;
@@ -115,35 +171,60 @@ pub(crate) mod which_;
 pub(crate) mod wrap;
 
 pub(crate) use autoview::Autoview;
+pub(crate) use base32::Base32;
+pub(crate) use benchmark::Benchmark;
+pub(crate) use bytes_::Bytes;
 pub(crate) use cd::Cd;
 pub(crate) use command::{whole_stream_command, Command, UnevaluatedCallInfo, WholeStreamCommand};
 
+pub(crate) use abs::Abs;
+pub(crate) use aggregate::Aggregate;
 pub(crate) use alias::Alias;
+pub(crate) use all::All;
+pub(crate) use any::Any;
 pub(crate) use append::Append;
+pub(crate) use as_::As;
 pub(crate) use calc::Calc;
+pub(crate) use clean_numbers::CleanNumbers;
+pub(crate) use collect::Collect;
 pub(crate) use compact::Compact;
+pub(crate) use complete::Complete;
 pub(crate) use config::Config;
 pub(crate) use count::Count;
 pub(crate) use cp::Cpy;
+pub(crate) use cycle::Cycle;
 pub(crate) use date::Date;
+pub(crate) use date_format::DateFormat;
+pub(crate) use date_to_timezone::DateToTimezone;
 pub(crate) use debug::Debug;
+pub(crate) use def::Def;
 pub(crate) use default::Default;
+pub(crate) use detect_columns::DetectColumns;
 pub(crate) use drop::Drop;
 pub(crate) use du::Du;
 pub(crate) use each::Each;
 pub(crate) use echo::Echo;
 pub(crate) use edit::Edit;
-pub(crate) use is_empty::IsEmpty;
+pub(crate) use is_empty::{IsEmpty, IsEmptyStream};
+pub(crate) use join::Join;
+pub(crate) use keypress::Keypress;
 pub(crate) mod kill;
 pub(crate) use kill::Kill;
 pub(crate) mod clear;
 pub(crate) use clear::Clear;
 pub(crate) mod touch;
 pub(crate) use enter::Enter;
+pub(crate) use enumerate::Enumerate;
+pub(crate) use eol::Eol;
 #[allow(unused_imports)]
 pub(crate) use evaluate_by::EvaluateBy;
+pub(crate) use exec::Exec;
 pub(crate) use exit::Exit;
+pub(crate) use filesize::Filesize;
+pub(crate) use fill::Fill;
+pub(crate) use find::Find;
 pub(crate) use first::First;
+pub(crate) use flatten::Flatten;
 pub(crate) use format::Format;
 pub(crate) use from_bson::FromBSON;
 pub(crate) use from_csv::FromCSV;
@@ -151,7 +232,9 @@ pub(crate) use from_eml::FromEML;
 pub(crate) use from_ics::FromIcs;
 pub(crate) use from_ini::FromINI;
 pub(crate) use from_json::FromJSON;
+pub(crate) use from_nuon::FromNUON;
 pub(crate) use from_ods::FromODS;
+pub(crate) use from_pairs::FromPairs;
 pub(crate) use from_sqlite::FromDB;
 pub(crate) use from_sqlite::FromSQLite;
 pub(crate) use from_ssv::FromSSV;
@@ -164,27 +247,40 @@ pub(crate) use from_xml::FromXML;
 pub(crate) use from_yaml::FromYAML;
 pub(crate) use from_yaml::FromYML;
 pub(crate) use get::Get;
+pub(crate) use glob::Glob;
 pub(crate) use group_by::GroupBy;
 pub(crate) use headers::Headers;
 pub(crate) use help::Help;
 pub(crate) use histogram::Histogram;
 pub(crate) use history::History;
+pub(crate) use input::Input;
 pub(crate) use insert::Insert;
+pub(crate) use into::IntoType;
+pub(crate) use is_admin::IsAdmin;
 pub(crate) use last::Last;
 pub(crate) use lines::Lines;
 pub(crate) use ls::Ls;
 #[allow(unused_imports)]
 pub(crate) use map_max_by::MapMaxBy;
+pub(crate) use matches::Matches;
 pub(crate) use mkdir::Mkdir;
+pub(crate) use mode::Mode;
+pub(crate) use move_::MoveColumn;
 pub(crate) use mv::Move;
 pub(crate) use next::Next;
+pub(crate) use normalize::Normalize;
 pub(crate) use nth::Nth;
 pub(crate) use open::Open;
+pub(crate) use par_each::ParEach;
 pub(crate) use parse::Parse;
+pub(crate) use path::PathCommand;
 pub(crate) use pick::Pick;
 pub(crate) use pivot::Pivot;
+pub(crate) use poll::Poll;
+pub(crate) use pow::{Pow, Sqrt};
 pub(crate) use prepend::Prepend;
 pub(crate) use prev::Previous;
+pub(crate) use print::Print;
 pub(crate) use pwd::Pwd;
 pub(crate) use range::Range;
 #[allow(unused_imports)]
@@ -193,29 +289,42 @@ pub(crate) use reject::Reject;
 pub(crate) use rename::Rename;
 pub(crate) use reverse::Reverse;
 pub(crate) use rm::Remove;
+pub(crate) use roll::Roll;
+pub(crate) use rotate::Rotate;
+pub(crate) use round::Round;
 pub(crate) use run_external::RunExternalCommand;
 pub(crate) use save::Save;
+pub(crate) use seq_date::SeqDate;
 pub(crate) use shells::Shells;
 pub(crate) use shuffle::Shuffle;
 pub(crate) use size::Size;
 pub(crate) use skip::Skip;
 pub(crate) use skip_while::SkipWhile;
+pub(crate) use sleep::Sleep;
+pub(crate) use sort::Sort;
 pub(crate) use sort_by::SortBy;
+pub(crate) use source::Source;
 pub(crate) use split_by::SplitBy;
 pub(crate) use split_column::SplitColumn;
 pub(crate) use split_row::SplitRow;
+pub(crate) use stddev::{Stddev, Variance};
 pub(crate) use sum::Sum;
 #[allow(unused_imports)]
 pub(crate) use t_sort_by::TSortBy;
 pub(crate) use table::Table;
 pub(crate) use tags::Tags;
+pub(crate) use take::Take;
+pub(crate) use term_size::TermSize;
+pub(crate) use timeout::Timeout;
 pub(crate) use to_bson::ToBSON;
 pub(crate) use to_csv::ToCSV;
 pub(crate) use to_html::ToHTML;
 pub(crate) use to_json::ToJSON;
 pub(crate) use to_md::ToMarkdown;
+pub(crate) use to_nuon::ToNUON;
 pub(crate) use to_sqlite::ToDB;
 pub(crate) use to_sqlite::ToSQLite;
+pub(crate) use to_text::ToText;
 pub(crate) use to_toml::ToTOML;
 pub(crate) use to_tsv::ToTSV;
 pub(crate) use to_url::ToURL;
@@ -223,6 +332,8 @@ pub(crate) use to_yaml::ToYAML;
 pub(crate) use touch::Touch;
 pub(crate) use trim::Trim;
 pub(crate) use uniq::Uniq;
+pub(crate) use unwrap::Unwrap;
+pub(crate) use upsert::Upsert;
 pub(crate) use version::Version;
 pub(crate) use what::What;
 pub(crate) use where_::Where;
@@ -0,0 +1,91 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_value_ext::ValueExt;
+use num_traits::Signed;
+
+pub struct Abs;
+
+#[derive(Deserialize)]
+pub struct AbsArgs {
+    rest: Vec<ColumnPath>,
+}
+
+impl WholeStreamCommand for Abs {
+    fn name(&self) -> &str {
+        "abs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("abs").rest(
+            SyntaxShape::ColumnPath,
+            "optionally, the columns to take the absolute value of (defaults to the whole value)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Take the absolute value of numbers (or columns)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, abs)?.run()
+    }
+}
+
+fn abs(
+    AbsArgs { rest: columns }: AbsArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .map(move |value| {
+            if columns.is_empty() {
+                return ReturnSuccess::value(abs_value(&value)?);
+            }
+
+            let mut result = value;
+
+            for path in &columns {
+                let cell =
+                    result.get_data_by_column_path(path, Box::new(move |(_, _, error)| error))?;
+                let cell = abs_value(&cell)?;
+
+                result = match result.replace_data_at_column_path(path, cell) {
+                    Some(replaced) => replaced,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Abs could not find the column",
+                            "column name",
+                            &result.tag,
+                        ))
+                    }
+                };
+            }
+
+            ReturnSuccess::value(result)
+        })
+        .to_output_stream())
+}
+
+fn abs_value(value: &Value) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            Ok(UntaggedValue::int(i.abs()).into_value(&value.tag))
+        }
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            Ok(UntaggedValue::decimal(d.abs()).into_value(&value.tag))
+        }
+        _ => Err(ShellError::labeled_error(
+            format!("Can't take the absolute value of {}", value.type_name()),
+            "expected a number",
+            &value.tag,
+        )),
+    }
+}
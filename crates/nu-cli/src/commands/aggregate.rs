@@ -0,0 +1,148 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Scope, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+
+pub struct Aggregate;
+
+#[derive(Deserialize)]
+pub struct AggregateArgs {
+    block: Block,
+    #[serde(rename(deserialize = "as"))]
+    column: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Aggregate {
+    fn name(&self) -> &str {
+        "aggregate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("aggregate")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the reduction to run over each group's items, eg) { get price | sum }",
+            )
+            .named(
+                "as",
+                SyntaxShape::String,
+                "the name of the column holding the result (defaults to 'value')",
+                Some('a'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Reduce each group from `group-by --to-table` to a summary row, running the block over its items."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, aggregate)?.run())
+    }
+}
+
+fn aggregate(
+    AggregateArgs { block, column }: AggregateArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let registry = context.registry.clone();
+    let column = column
+        .map(|column| column.item)
+        .unwrap_or_else(|| "value".to_string());
+    let mut input = context.input;
+
+    let stream = async_stream! {
+        let mut context = Context::from_raw(&raw_args, &registry);
+        let scope = raw_args.call_info.scope.clone();
+
+        while let Some(group) = input.next().await {
+            let (key, items) = match group_parts(&group) {
+                Ok(parts) => parts,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            match reduce(&block, &mut context, &scope, items, &group.tag).await {
+                Ok(result) => {
+                    let mut summary = TaggedDictBuilder::new(&group.tag);
+                    summary.insert_value("group", key);
+                    summary.insert_value(&column, result);
+                    yield ReturnSuccess::value(summary.into_value());
+                }
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// The group key and items of a `{group, items}` row, as written by `group-by --to-table`.
+fn group_parts(group: &Value) -> Result<(Value, Vec<Value>), ShellError> {
+    let row = match &group.value {
+        UntaggedValue::Row(row) => row,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected groups from group-by --to-table",
+                "requires {group, items} rows",
+                &group.tag,
+            ))
+        }
+    };
+
+    let key = row.get_data_by_key("group".spanned_unknown());
+    let items = row.get_data_by_key("items".spanned_unknown());
+
+    match (key, items) {
+        (Some(key), Some(items)) => match items.value {
+            UntaggedValue::Table(items) => Ok((key, items)),
+            _ => Ok((key, vec![items])),
+        },
+        _ => Err(ShellError::labeled_error(
+            "Expected groups from group-by --to-table",
+            "row is missing a group or items column",
+            &group.tag,
+        )),
+    }
+}
+
+/// Runs `block` with the group's items as its input (and as `$it`), giving back what it outputs:
+/// the single value, a table of several, or nothing.
+async fn reduce(
+    block: &Block,
+    context: &mut Context,
+    scope: &Scope,
+    items: Vec<Value>,
+    tag: &Tag,
+) -> Result<Value, ShellError> {
+    let table = UntaggedValue::table(&items).into_value(tag);
+    let input_stream = InputStream::from(items);
+
+    let stream = run_block(block, context, input_stream, &scope.clone().set_it(table)).await?;
+    let mut results: Vec<Value> = stream.collect().await;
+
+    if let Some(error) = context.get_errors().first() {
+        return Err(error.clone());
+    }
+
+    Ok(match results.len() {
+        0 => UntaggedValue::nothing().into_value(tag),
+        1 => results.remove(0),
+        _ => UntaggedValue::table(&results).into_value(tag),
+    })
+}
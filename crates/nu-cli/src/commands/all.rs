@@ -0,0 +1,108 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::evaluate::evaluate_baseline_expr;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, hir::ClassifiedCommand, ReturnSuccess, Signature, SyntaxShape, UntaggedValue,
+};
+
+pub struct All;
+
+#[derive(Deserialize)]
+pub struct AllArgs {
+    block: Block,
+}
+
+impl WholeStreamCommand for All {
+    fn name(&self) -> &str {
+        "all?"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("all?").required(
+            "condition",
+            SyntaxShape::Math,
+            "the condition that every row must match",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Checks whether every row matches the condition (true when there are no rows)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, all)?.run())
+    }
+}
+
+fn all(
+    AllArgs { block }: AllArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    matches_until(block, false, context, raw_args)
+}
+
+/// Checks the rows against the condition in `block` until one of them gives `stop_at`, and
+/// answers `stop_at` if one did or its opposite if none did. The rows after it are never looked
+/// at, so `all?` stops at the first miss and `any?` at the first match.
+pub(crate) fn matches_until(
+    block: Block,
+    stop_at: bool,
+    RunnableContext {
+        name: tag,
+        registry,
+        input,
+        ..
+    }: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let condition = {
+        if block.block.len() != 1 {
+            return Err(ShellError::labeled_error(
+                "Expected a condition",
+                "expected a condition",
+                tag,
+            ));
+        }
+        match block.block[0].list.get(0) {
+            Some(ClassifiedCommand::Expr(expr)) => expr.clone(),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a condition",
+                    "expected a condition",
+                    tag,
+                ))
+            }
+        }
+    };
+
+    let mut input = input;
+    let scope = raw_args.call_info.scope;
+    let stream = async_stream! {
+        while let Some(input) = input.next().await {
+            let condition = evaluate_baseline_expr(&condition, &registry, &scope.clone().set_it(input.clone()))?;
+
+            match condition.as_bool() {
+                Ok(b) if b == stop_at => {
+                    yield ReturnSuccess::value(UntaggedValue::boolean(stop_at).into_value(&tag));
+                    return;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            }
+        }
+
+        yield ReturnSuccess::value(UntaggedValue::boolean(!stop_at).into_value(&tag));
+    };
+
+    Ok(stream.to_output_stream())
+}
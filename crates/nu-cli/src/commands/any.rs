@@ -0,0 +1,47 @@
+use crate::commands::all::matches_until;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, Signature, SyntaxShape};
+
+pub struct Any;
+
+#[derive(Deserialize)]
+pub struct AnyArgs {
+    block: Block,
+}
+
+impl WholeStreamCommand for Any {
+    fn name(&self) -> &str {
+        "any?"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("any?").required(
+            "condition",
+            SyntaxShape::Math,
+            "the condition that some row must match",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Checks whether any row matches the condition (false when there are no rows)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, any)?.run())
+    }
+}
+
+fn any(
+    AnyArgs { block }: AnyArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    matches_until(block, true, context, raw_args)
+}
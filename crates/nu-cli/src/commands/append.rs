@@ -7,6 +7,7 @@ use nu_protocol::{Signature, SyntaxShape, Value};
 #[derive(Deserialize)]
 struct AppendArgs {
     row: Value,
+    rest: Vec<Value>,
 }
 
 pub struct Append;
@@ -17,15 +18,20 @@ impl WholeStreamCommand for Append {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("append").required(
-            "row value",
-            SyntaxShape::Any,
-            "the value of the row to append to the table",
-        )
+        Signature::build("append")
+            .required(
+                "row value",
+                SyntaxShape::Any,
+                "the value of the row to append to the table",
+            )
+            .rest(
+                SyntaxShape::Any,
+                "more rows to append, kept in the order given",
+            )
     }
 
     fn usage(&self) -> &str {
-        "Append the given row to the table"
+        "Append the given rows to the table"
     }
 
     fn run(
@@ -38,11 +44,12 @@ impl WholeStreamCommand for Append {
 }
 
 fn append(
-    AppendArgs { row }: AppendArgs,
+    AppendArgs { row, rest }: AppendArgs,
     RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let mut after: VecDeque<Value> = VecDeque::new();
     after.push_back(row);
+    after.extend(rest);
     let after = futures::stream::iter(after);
 
     Ok(OutputStream::from_input(input.chain(after)))
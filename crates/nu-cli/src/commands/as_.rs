@@ -0,0 +1,43 @@
+use crate::commands::wrap::{wrap, WrapArgs};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct As;
+
+#[derive(Deserialize)]
+struct AsArgs {
+    name: Tagged<String>,
+}
+
+impl WholeStreamCommand for As {
+    fn name(&self) -> &str {
+        "as"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("as").required(
+            "name",
+            SyntaxShape::String,
+            "the name of the column to put the values under",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Puts the values into a table with a single column of the given name (the same as `wrap <name>`)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, as_)?.run()
+    }
+}
+
+fn as_(AsArgs { name }: AsArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    wrap(WrapArgs { column: Some(name) }, context)
+}
@@ -0,0 +1,166 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, ReturnValue, Signature, UntaggedValue, Value};
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+pub struct Base32;
+
+#[derive(Deserialize)]
+pub struct Base32Args {
+    decode: bool,
+}
+
+impl WholeStreamCommand for Base32 {
+    fn name(&self) -> &str {
+        "base32"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("base32").switch(
+            "decode",
+            "decode the base32 text instead of encoding",
+            Some('d'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Encode strings and binary data as base32 (RFC 4648), or decode them."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, base32)?.run()
+    }
+}
+
+fn encode(input: &[u8]) -> String {
+    let mut output = String::new();
+
+    for chunk in input.chunks(5) {
+        let mut block = [0u8; 5];
+        block[..chunk.len()].copy_from_slice(chunk);
+
+        let bits = block
+            .iter()
+            .fold(0u64, |bits, byte| (bits << 8) | u64::from(*byte));
+
+        // every 5 input bytes become 8 characters; a short chunk only
+        // needs enough characters to hold its bits, the rest is padding
+        let significant = (chunk.len() * 8 + 4) / 5;
+
+        for idx in 0..8 {
+            if idx < significant {
+                let position = (bits >> (35 - idx * 5)) & 0x1f;
+                output.push(ALPHABET[position as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
+
+fn decode(input: &str) -> Option<Vec<u8>> {
+    let input = input.trim();
+    let unpadded = input.trim_end_matches('=');
+
+    if input.len() % 8 != 0 && input.len() != unpadded.len() {
+        return None;
+    }
+
+    match unpadded.len() % 8 {
+        0 | 2 | 4 | 5 | 7 => {}
+        _ => return None,
+    }
+
+    let mut output = vec![];
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for c in unpadded.bytes() {
+        let value = ALPHABET.iter().position(|a| *a == c)? as u32;
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+fn base32_value(value: Value, decoding: bool) -> ReturnValue {
+    let input: Vec<u8> = match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => s.as_bytes().to_vec(),
+        UntaggedValue::Primitive(Primitive::Binary(b)) if !decoding => b.clone(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a string or binary data",
+                "can't convert this value",
+                &value.tag,
+            ))
+        }
+    };
+
+    if !decoding {
+        return ReturnSuccess::value(UntaggedValue::string(encode(&input)).into_value(value.tag));
+    }
+
+    let decoded = decode(&String::from_utf8_lossy(&input)).ok_or_else(|| {
+        ShellError::labeled_error("Could not decode base32", "invalid base32 text", &value.tag)
+    })?;
+
+    match String::from_utf8(decoded) {
+        Ok(s) => ReturnSuccess::value(UntaggedValue::string(s).into_value(value.tag)),
+        Err(e) => ReturnSuccess::value(UntaggedValue::binary(e.into_bytes()).into_value(value.tag)),
+    }
+}
+
+fn base32(
+    Base32Args { decode: decoding }: Base32Args,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .map(move |value| base32_value(value, decoding))
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+
+    #[test]
+    fn encodes_the_rfc4648_test_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+
+        for (plain, encoded) in vectors.iter() {
+            assert_eq!(encode(plain.as_bytes()), *encoded);
+            assert_eq!(decode(encoded), Some(plain.as_bytes().to_vec()));
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(decode("MZXW6YT!"), None);
+        assert_eq!(decode("MZX"), None);
+    }
+}
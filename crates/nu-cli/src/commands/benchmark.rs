@@ -0,0 +1,125 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use std::convert::TryFrom;
+use std::time::{Duration, Instant};
+
+pub struct Benchmark;
+
+#[derive(Deserialize)]
+pub struct BenchmarkArgs {
+    block: Block,
+    rounds: Option<Tagged<u64>>,
+}
+
+impl WholeStreamCommand for Benchmark {
+    fn name(&self) -> &str {
+        "benchmark"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("benchmark")
+            .required("block", SyntaxShape::Block, "the block to run and time")
+            .named(
+                "rounds",
+                SyntaxShape::Int,
+                "run the block this many times and average the duration",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block and report how long it took, along with its output."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, benchmark)?.run())
+    }
+}
+
+fn benchmark(
+    BenchmarkArgs { block, rounds }: BenchmarkArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let rounds = match rounds {
+        Some(rounds) if rounds.item == 0 => {
+            return Err(ShellError::labeled_error(
+                "Benchmark needs at least one round",
+                "expected a positive number of rounds",
+                rounds.tag,
+            ))
+        }
+        // The total time is divided by the rounds, which a Duration can only do by a u32
+        Some(rounds) => u32::try_from(rounds.item).map_err(|_| {
+            ShellError::labeled_error(
+                "Too many rounds to benchmark",
+                format!("expected at most {} rounds", u32::MAX),
+                rounds.tag,
+            )
+        })?,
+        None => 1,
+    };
+
+    let scope = raw_args.call_info.scope.clone();
+    let registry = context.registry.clone();
+    let name = context.name;
+
+    let stream = async_stream! {
+        let mut elapsed = Duration::from_secs(0);
+        let mut output: Vec<Value> = vec![];
+
+        for _ in 0..rounds {
+            let mut context = Context::from_raw(&raw_args, &registry);
+            let start = Instant::now();
+
+            let result = run_block(&block, &mut context, InputStream::empty(), &scope).await;
+
+            output = match result {
+                Ok(mut stream) => stream.drain_vec().await,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+
+            elapsed += start.elapsed();
+
+            if let Some(error) = context.get_errors().first() {
+                yield Err(error.clone());
+                return;
+            }
+        }
+
+        let average = elapsed / rounds;
+
+        let output = match output.len() {
+            0 => UntaggedValue::nothing().into_value(&name),
+            1 => output.remove(0),
+            _ => UntaggedValue::Table(output).into_value(&name),
+        };
+
+        // Durations only hold whole seconds, so a block quicker than that reports 0s
+        let mut dict = TaggedDictBuilder::new(&name);
+        dict.insert_untagged(
+            "duration",
+            UntaggedValue::Primitive(Primitive::Duration(average.as_secs() as i64)),
+        );
+        dict.insert_value("output", output);
+
+        yield ReturnSuccess::value(dict.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,124 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct Bytes;
+
+#[derive(Deserialize)]
+pub struct BytesArgs {
+    length: bool,
+    at: Option<Tagged<String>>,
+    reverse: bool,
+}
+
+impl WholeStreamCommand for Bytes {
+    fn name(&self) -> &str {
+        "bytes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes")
+            .switch("length", "the number of bytes", Some('l'))
+            .named(
+                "at",
+                SyntaxShape::String,
+                "the bytes between the given offsets, requires \"start,end\"",
+                Some('a'),
+            )
+            .switch("reverse", "reverse the order of the bytes", Some('r'))
+    }
+
+    fn usage(&self) -> &str {
+        "Work with binary data: get its length, slice it, or reverse it."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, bytes)?.run()
+    }
+}
+
+fn parse_offset(offset: &str, default: usize, tag: &Tag) -> Result<usize, ShellError> {
+    match offset.trim() {
+        "" => Ok(default),
+        offset => offset.parse().map_err(|_| {
+            ShellError::labeled_error("Could not parse byte offset", "expected \"start,end\"", tag)
+        }),
+    }
+}
+
+fn parse_range(at: &Tagged<String>) -> Result<(usize, usize), ShellError> {
+    let offsets: Vec<&str> = at.item.split(',').collect();
+
+    match offsets.as_slice() {
+        [start, end] => Ok((
+            parse_offset(start, 0, &at.tag)?,
+            parse_offset(end, usize::max_value(), &at.tag)?,
+        )),
+        _ => Err(ShellError::labeled_error(
+            "Could not parse byte offsets",
+            "expected \"start,end\"",
+            &at.tag,
+        )),
+    }
+}
+
+fn bytes_value(
+    value: Value,
+    length: bool,
+    range: Option<(usize, usize)>,
+    reverse: bool,
+) -> ReturnValue {
+    let mut binary = match value.value {
+        UntaggedValue::Primitive(Primitive::Binary(binary)) => binary,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected binary data",
+                "expected binary data",
+                &value.tag,
+            ))
+        }
+    };
+
+    if let Some((start, end)) = range {
+        let end = end.min(binary.len());
+        let start = start.min(end);
+
+        binary = binary[start..end].to_vec();
+    }
+
+    if reverse {
+        binary.reverse();
+    }
+
+    if length {
+        return ReturnSuccess::value(UntaggedValue::int(binary.len()).into_value(value.tag));
+    }
+
+    ReturnSuccess::value(UntaggedValue::binary(binary).into_value(value.tag))
+}
+
+fn bytes(
+    BytesArgs {
+        length,
+        at,
+        reverse,
+    }: BytesArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let range = match at {
+        Some(at) => Some(parse_range(&at)?),
+        None => None,
+    };
+
+    Ok(input
+        .map(move |value| bytes_value(value, length, range, reverse))
+        .to_output_stream())
+}
@@ -5,7 +5,7 @@ use log::{log_enabled, trace};
 
 use nu_errors::ShellError;
 use nu_protocol::hir::SpannedExpression;
-use nu_protocol::Scope;
+use nu_protocol::{Scope, UntaggedValue, Value};
 
 pub(crate) fn run_expression_block(
     expr: SpannedExpression,
@@ -18,12 +18,27 @@ pub(crate) fn run_expression_block(
         trace!(target: "nu::run::expr", "{:?}", expr);
     }
 
+    // Nothing is piped into the head of a pipeline, and a list there streams its items the
+    // way `echo` does, so `= [1 2 3]` gives three rows and `collect` turns them back into one list
+    let at_head = input.is_empty();
+
     let scope = scope.clone();
     let registry = context.registry().clone();
-    let stream = input.map(move |row| {
-        let scope = scope.clone().set_it(row);
-        evaluate_baseline_expr(&expr, &registry, &scope)
-    });
+    let stream = input
+        .map(move |row| {
+            let scope = scope.clone().set_it(row);
+
+            match evaluate_baseline_expr(&expr, &registry, &scope) {
+                Ok(Value {
+                    value: UntaggedValue::Table(items),
+                    ..
+                }) if at_head => {
+                    futures::stream::iter(items.into_iter().map(Ok).collect::<Vec<_>>())
+                }
+                result => futures::stream::iter(vec![result]),
+            }
+        })
+        .flatten();
 
     Ok(stream.to_input_stream())
 }
@@ -1,10 +1,11 @@
 use crate::evaluate::evaluate_baseline_expr;
 use crate::futures::ThreadedReceiver;
 use crate::prelude::*;
+use crate::utils::closest_name;
 
 use std::io::Write;
 use std::ops::Deref;
-use std::process::{Command, Stdio};
+use std::process::{Command, Output, Stdio};
 use std::sync::mpsc;
 
 use bytes::{BufMut, Bytes, BytesMut};
@@ -106,11 +107,7 @@ pub(crate) async fn run_external_command(
     trace!(target: "nu::run::external", "-> {}", command.name);
 
     if !did_find_command(&command.name).await {
-        return Err(ShellError::labeled_error(
-            "Command not found",
-            "command not found",
-            &command.name_tag,
-        ));
+        return Err(command_not_found(&command, &context.registry));
     }
 
     if command.has_it_argument() {
@@ -205,13 +202,61 @@ fn run_with_stdin(
 
     let input = trace_stream!(target: "nu::trace_stream::external::stdin", "input" = input);
 
+    let process_args = evaluate_process_args(&command, context, scope)?;
+
+    spawn(&command, &path, &process_args[..], input, is_last)
+}
+
+/// Runs the external command to completion, capturing its stdout, stderr and
+/// exit status instead of streaming them.
+pub(crate) async fn run_external_command_to_completion(
+    command: ExternalCommand,
+    context: &mut Context,
+    scope: &Scope,
+) -> Result<Output, ShellError> {
+    if !did_find_command(&command.name).await {
+        return Err(command_not_found(&command, &context.registry));
+    }
+
+    let path = context.shell_manager.path();
+    let process_args = evaluate_process_args(&command, context, scope)?;
+
+    let mut process = build_process(&command, &path, &process_args[..]);
+    process.stdin(Stdio::null());
+
+    let could_not_run = |reason: String| {
+        ShellError::labeled_error(
+            format!("Could not run external command ({})", reason),
+            "could not run",
+            &command.name_tag,
+        )
+    };
+
+    // Waiting on the process blocks, so it's done on its own thread rather than the executor's
+    let (sender, receiver) = futures::channel::oneshot::channel();
+
+    std::thread::spawn(move || {
+        let _ = sender.send(process.output());
+    });
+
+    receiver
+        .await
+        .map_err(|_| could_not_run("the process was lost".to_string()))?
+        .map_err(|e| could_not_run(e.to_string()))
+}
+
+fn evaluate_process_args(
+    command: &ExternalCommand,
+    context: &Context,
+    scope: &Scope,
+) -> Result<Vec<String>, ShellError> {
     let mut command_args = vec![];
     for arg in command.args.iter() {
         let value = evaluate_baseline_expr(arg, &context.registry, scope)?;
         command_args.push(value.as_string()?);
     }
 
-    let process_args = command_args
+    Ok(command_args
         .iter()
         .map(|arg| {
             let arg = expand_tilde(arg.deref(), dirs::home_dir);
@@ -233,20 +278,10 @@ fn run_with_stdin(
                 }
             }
         })
-        .collect::<Vec<String>>();
-
-    spawn(&command, &path, &process_args[..], input, is_last)
+        .collect::<Vec<String>>())
 }
 
-fn spawn(
-    command: &ExternalCommand,
-    path: &str,
-    args: &[String],
-    input: InputStream,
-    is_last: bool,
-) -> Result<InputStream, ShellError> {
-    let command = command.clone();
-
+fn build_process(command: &ExternalCommand, path: &str, args: &[String]) -> Command {
     let mut process = {
         #[cfg(windows)]
         {
@@ -273,6 +308,20 @@ fn spawn(
     process.current_dir(path);
     trace!(target: "nu::run::external", "cwd = {:?}", &path);
 
+    process
+}
+
+fn spawn(
+    command: &ExternalCommand,
+    path: &str,
+    args: &[String],
+    input: InputStream,
+    is_last: bool,
+) -> Result<InputStream, ShellError> {
+    let command = command.clone();
+
+    let mut process = build_process(&command, path, args);
+
     // We want stdout regardless of what
     // we are doing ($it case or pipe stdin)
     if !is_last {
@@ -464,6 +513,19 @@ fn spawn(
     }
 }
 
+fn command_not_found(command: &ExternalCommand, registry: &CommandRegistry) -> ShellError {
+    match closest_name(&command.name, &registry.names()) {
+        Some(suggestion) => ShellError::labeled_error(
+            "Command not found",
+            format!("did you mean `{}`?", suggestion),
+            &command.name_tag,
+        ),
+        None => {
+            ShellError::labeled_error("Command not found", "command not found", &command.name_tag)
+        }
+    }
+}
+
 async fn did_find_command(name: &str) -> bool {
     #[cfg(not(windows))]
     {
@@ -1,5 +1,6 @@
 use crate::commands::command::whole_stream_command;
 use crate::commands::run_alias::AliasCommand;
+use crate::commands::run_custom::CustomCommand;
 use crate::commands::UnevaluatedCallInfo;
 use crate::prelude::*;
 use log::{log_enabled, trace};
@@ -129,6 +130,14 @@ pub(crate) fn run_internal_command(
                             ))
                         ]);
                     }
+                    CommandAction::AddCommand(signature, block) => {
+                        context.add_commands(vec![
+                            whole_stream_command(CustomCommand::new(
+                                signature,
+                                block,
+                            ))
+                        ]);
+                    }
                     CommandAction::PreviousShell => {
                         context.shell_manager.prev();
                     }
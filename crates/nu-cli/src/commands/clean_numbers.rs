@@ -0,0 +1,118 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct CleanNumbers;
+
+#[derive(Deserialize)]
+pub struct CleanNumbersArgs {
+    rest: Vec<Tagged<String>>,
+    drop: bool,
+    flag: bool,
+}
+
+impl WholeStreamCommand for CleanNumbers {
+    fn name(&self) -> &str {
+        "clean-numbers"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("clean-numbers")
+            .switch(
+                "drop",
+                "drop rows without a number in the columns (the default)",
+                Some('d'),
+            )
+            .switch(
+                "flag",
+                "keep every row, adding an 'invalid' column that is true for rows without a number in the columns",
+                Some('f'),
+            )
+            .rest(SyntaxShape::String, "the columns that should hold numbers")
+    }
+
+    fn usage(&self) -> &str {
+        "Drop (or flag) rows whose columns hold something other than a number, like NaN, inf or text."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, clean_numbers)?.run()
+    }
+}
+
+fn clean_numbers(
+    CleanNumbersArgs {
+        rest: columns,
+        drop,
+        flag,
+    }: CleanNumbersArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if drop && flag {
+        return Err(ShellError::labeled_error(
+            "Clean-numbers can either drop or flag rows, not both",
+            "use either --drop or --flag",
+            name,
+        ));
+    }
+
+    if columns.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Clean-numbers requires the columns to check",
+            "needs one or more columns",
+            name,
+        ));
+    }
+
+    Ok(input
+        .filter_map(move |row| {
+            let result = match &row.value {
+                UntaggedValue::Row(dict) => {
+                    let valid = columns
+                        .iter()
+                        .all(|column| is_number(dict.get_data(&column.item).borrow()));
+
+                    if flag {
+                        let mut flagged = dict.clone();
+                        flagged.insert_data_at_key(
+                            "invalid",
+                            UntaggedValue::boolean(!valid).into_value(&row.tag),
+                        );
+                        Some(ReturnSuccess::value(
+                            UntaggedValue::Row(flagged).into_value(&row.tag),
+                        ))
+                    } else if valid {
+                        Some(ReturnSuccess::value(row))
+                    } else {
+                        None
+                    }
+                }
+                _ => Some(Err(ShellError::labeled_error(
+                    "Expected a table from pipeline",
+                    "requires rows with columns to check",
+                    &row.tag,
+                ))),
+            };
+
+            futures::future::ready(result)
+        })
+        .to_output_stream())
+}
+
+/// Whether the cell holds a number. Decimals are always finite, so NaN and infinities can only
+/// turn up as text (like `NaN` or `inf` read from a CSV file), which isn't a number.
+fn is_number(value: &Value) -> bool {
+    matches!(
+        &value.value,
+        UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Bytes(_))
+    )
+}
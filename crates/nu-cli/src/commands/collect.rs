@@ -0,0 +1,86 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use futures::stream::once;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Collect;
+
+#[derive(Deserialize)]
+pub struct CollectArgs {
+    block: Option<Block>,
+}
+
+impl WholeStreamCommand for Collect {
+    fn name(&self) -> &str {
+        "collect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("collect").optional(
+            "block",
+            SyntaxShape::Block,
+            "a block to run once over the collected list, given as $it",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Collect the whole stream into a single list value."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, collect)?.run())
+    }
+}
+
+fn collect(
+    CollectArgs { block }: CollectArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let registry = context.registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let name = context.name;
+    let input = context.input;
+
+    let stream = async_stream! {
+        let values: Vec<Value> = input.collect().await;
+        let list = UntaggedValue::table(&values).into_value(&name);
+
+        let block = match block {
+            Some(block) => block,
+            None => {
+                yield ReturnSuccess::value(list);
+                return;
+            }
+        };
+
+        let mut context = Context::from_raw(&raw_args, &registry);
+        let input = list.clone();
+        let input_stream = once(async { Ok(input) }).to_input_stream();
+
+        let result = run_block(&block, &mut context, input_stream, &scope.clone().set_it(list)).await;
+
+        match result {
+            Ok(mut stream) => {
+                if let Some(error) = context.get_errors().first() {
+                    yield Err(error.clone());
+                    return;
+                }
+
+                while let Some(result) = stream.next().await {
+                    yield ReturnSuccess::value(result);
+                }
+            }
+            Err(e) => yield Err(e),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
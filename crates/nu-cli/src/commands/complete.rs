@@ -0,0 +1,125 @@
+use crate::commands::classified::external::run_external_command_to_completion;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::hir::{
+    Block, ClassifiedCommand, Expression, ExternalArgs, ExternalCommand, Literal, SpannedExpression,
+};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+
+pub struct Complete;
+
+#[derive(Deserialize)]
+pub struct CompleteArgs {
+    block: Block,
+}
+
+impl WholeStreamCommand for Complete {
+    fn name(&self) -> &str {
+        "complete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("complete").required(
+            "block",
+            SyntaxShape::Block,
+            "the block with the external command to run",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Run an external command to completion, capturing its stdout, stderr and exit code."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, complete)?.run())
+    }
+}
+
+/// Pulls the external command out of a block holding exactly one call to it.
+fn external_command(block: &Block) -> Result<ExternalCommand, ShellError> {
+    let not_external = || {
+        ShellError::labeled_error(
+            "Complete expects a block with a single external command",
+            "expected a single external command",
+            block.span,
+        )
+    };
+
+    let call = match block.block.as_slice() {
+        [pipeline] => match pipeline.list.as_slice() {
+            [ClassifiedCommand::Internal(internal)] if internal.name == "run_external" => {
+                &internal.args
+            }
+            _ => return Err(not_external()),
+        },
+        _ => return Err(not_external()),
+    };
+
+    let mut positionals = call.positional.clone().unwrap_or_default().into_iter();
+
+    let (name, name_span) = match positionals.next() {
+        Some(SpannedExpression {
+            expr: Expression::Literal(Literal::String(name)),
+            span,
+        }) => (name, span),
+        _ => return Err(not_external()),
+    };
+
+    Ok(ExternalCommand {
+        name,
+        name_tag: Tag::unknown_anchor(name_span),
+        args: ExternalArgs {
+            list: positionals.collect(),
+            span: call.span,
+        },
+    })
+}
+
+fn complete(
+    CompleteArgs { block }: CompleteArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let command = external_command(&block)?;
+    let scope = raw_args.call_info.scope.clone();
+    let registry = context.registry.clone();
+    let name = context.name;
+
+    let stream = async_stream! {
+        let mut context = Context::from_raw(&raw_args, &registry);
+
+        let output = match run_external_command_to_completion(command, &mut context, &scope).await {
+            Ok(output) => output,
+            Err(e) => {
+                yield Err(e);
+                return;
+            }
+        };
+
+        let exit_code = match output.status.code() {
+            Some(code) => UntaggedValue::int(code),
+            None => UntaggedValue::nothing(),
+        };
+
+        let mut dict = TaggedDictBuilder::new(&name);
+        dict.insert_untagged(
+            "stdout",
+            UntaggedValue::string(String::from_utf8_lossy(&output.stdout)),
+        );
+        dict.insert_untagged(
+            "stderr",
+            UntaggedValue::string(String::from_utf8_lossy(&output.stderr)),
+        );
+        dict.insert_untagged("exit_code", exit_code);
+
+        yield ReturnSuccess::value(dict.into_value());
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,59 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, Value};
+use std::sync::atomic::Ordering;
+
+pub struct Cycle;
+
+#[derive(Deserialize)]
+pub struct CycleArgs {}
+
+impl WholeStreamCommand for Cycle {
+    fn name(&self) -> &str {
+        "cycle"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cycle")
+    }
+
+    fn usage(&self) -> &str {
+        "Repeat the rows over and over, until a later command (like first) stops asking or ctrl-c."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, cycle)?.run()
+    }
+}
+
+fn cycle(
+    _: CycleArgs,
+    RunnableContext { input, ctrl_c, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.collect().await;
+
+        // Nothing to repeat, so there'd be nothing to ever give
+        if values.is_empty() {
+            return;
+        }
+
+        // Rows are only made as they're asked for, so this ends when whatever reads it stops
+        loop {
+            for value in &values {
+                if ctrl_c.load(Ordering::SeqCst) {
+                    return;
+                }
+
+                yield ReturnSuccess::value(value.clone());
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
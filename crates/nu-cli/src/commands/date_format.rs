@@ -0,0 +1,93 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct DateFormat;
+
+#[derive(Deserialize)]
+pub struct DateFormatArgs {
+    format: Tagged<String>,
+}
+
+impl WholeStreamCommand for DateFormat {
+    fn name(&self) -> &str {
+        "date-format"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date-format").required(
+            "format",
+            SyntaxShape::String,
+            "the strftime pattern to format with, like \"%Y-%m-%d\"",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Format dates (or strings holding dates) as text with a strftime pattern."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, date_format)?.run()
+    }
+}
+
+fn date_format(
+    DateFormatArgs { format }: DateFormatArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if StrftimeItems::new(&format.item).any(|item| item == Item::Error) {
+        return Err(ShellError::labeled_error(
+            "Invalid date format",
+            "this pattern isn't a valid strftime format",
+            &format.tag,
+        ));
+    }
+
+    Ok(input
+        .map(move |value| {
+            let date = as_date(&value)?;
+            let formatted = date.format(&format.item).to_string();
+
+            ReturnSuccess::value(UntaggedValue::string(formatted).into_value(&value.tag))
+        })
+        .to_output_stream())
+}
+
+pub(crate) fn as_date(value: &Value) -> Result<DateTime<FixedOffset>, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => Ok(*date),
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => {
+            let s = s.trim();
+
+            if let Ok(day) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+                return Ok(DateTime::<Utc>::from_utc(day.and_hms(0, 0, 0), Utc).into());
+            }
+
+            DateTime::parse_from_rfc3339(s)
+                .map(|date| date.with_timezone(&Utc).into())
+                .map_err(|_| {
+                    ShellError::labeled_error(
+                        "Could not parse date",
+                        "expected a date like 2020-01-31 or 2020-01-31T12:00:00Z",
+                        &value.tag,
+                    )
+                })
+        }
+        _ => Err(ShellError::labeled_error(
+            format!("Can't format {} as a date", value.type_name()),
+            "expected a date",
+            &value.tag,
+        )),
+    }
+}
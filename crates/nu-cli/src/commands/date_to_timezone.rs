@@ -0,0 +1,83 @@
+use crate::commands::date_format::as_date;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::Offset;
+use chrono_tz::{Tz, TZ_VARIANTS};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct DateToTimezone;
+
+#[derive(Deserialize)]
+pub struct DateToTimezoneArgs {
+    timezone: Tagged<String>,
+}
+
+impl WholeStreamCommand for DateToTimezone {
+    fn name(&self) -> &str {
+        "date-to-timezone"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date-to-timezone").required(
+            "timezone",
+            SyntaxShape::String,
+            "the timezone to convert to, like America/New_York",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert dates to the given timezone, keeping the instant and taking on its offset."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, date_to_timezone)?.run()
+    }
+}
+
+fn date_to_timezone(
+    DateToTimezoneArgs { timezone }: DateToTimezoneArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let tz: Tz = timezone.item.parse().map_err(|_| {
+        ShellError::labeled_error(
+            "Unknown timezone",
+            unknown_timezone_label(&timezone.item),
+            &timezone.tag,
+        )
+    })?;
+
+    Ok(input
+        .map(move |value| {
+            let date = as_date(&value)?;
+
+            // The same instant, carrying the offset `tz` has at that instant
+            let local = date.with_timezone(&tz);
+            let local = local.with_timezone(&local.offset().fix());
+
+            ReturnSuccess::value(UntaggedValue::date(local).into_value(value.tag))
+        })
+        .to_output_stream())
+}
+
+fn unknown_timezone_label(name: &str) -> String {
+    let mut names: Vec<&str> = TZ_VARIANTS.iter().map(|tz| tz.name()).collect();
+    names.sort_by_key(|candidate| {
+        natural::distance::levenshtein_distance(&candidate.to_lowercase(), &name.to_lowercase())
+    });
+
+    format!(
+        "timezone `{}` not found; did you mean {}?",
+        name,
+        names
+            .iter()
+            .take(3)
+            .map(|name| format!("`{}`", name))
+            .join(", ")
+    )
+}
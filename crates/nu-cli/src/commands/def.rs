@@ -0,0 +1,72 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, CommandAction, ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct Def;
+
+#[derive(Deserialize)]
+pub struct DefArgs {
+    pub name: Tagged<String>,
+    pub params: Vec<Value>,
+    pub block: Block,
+}
+
+impl WholeStreamCommand for Def {
+    fn name(&self) -> &str {
+        "def"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("def")
+            .required("name", SyntaxShape::String, "the name of the command")
+            .required(
+                "params",
+                SyntaxShape::Table,
+                "the parameters of the command, eg) [name: string, times?: int, --loud]",
+            )
+            .required("block", SyntaxShape::Block, "the body of the command")
+    }
+
+    fn usage(&self) -> &str {
+        "Define a custom command, which runs the block with its arguments as variables."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, def)?.run()
+    }
+}
+
+pub fn def(
+    DefArgs {
+        name,
+        params,
+        block,
+    }: DefArgs,
+    _: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mut tokens = vec![];
+
+    for param in params.iter() {
+        let token = param.as_string().map_err(|_| {
+            ShellError::labeled_error("Expected a string", "expected a string", param.tag())
+        })?;
+
+        tokens.push(token.spanned(param.tag.span));
+    }
+
+    let (signature, err) = nu_parser::signature_from_tokens(&name.item, &tokens);
+    if let Some(err) = err {
+        return Err(err.into());
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::action(
+        CommandAction::AddCommand(signature, block),
+    )))
+}
@@ -0,0 +1,249 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct DetectColumns;
+
+#[derive(Deserialize)]
+pub struct DetectColumnsArgs {
+    skip: Option<Tagged<usize>>,
+}
+
+impl WholeStreamCommand for DetectColumns {
+    fn name(&self) -> &str {
+        "detect-columns"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("detect-columns").named(
+            "skip",
+            SyntaxShape::Int,
+            "number of lines to skip before the header line",
+            Some('s'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Infer columns from aligned text, using the first line as column names."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, detect_columns)?.run()
+    }
+}
+
+/// Finds the character ranges that hold a column by looking for positions
+/// that are blank on every line. A range whose header is blank belongs to
+/// data spilling out of its neighbour (eg. right-aligned numbers), so it is
+/// merged into the column before it.
+fn column_ranges(lines: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    let is_gap = |position: usize| {
+        lines.iter().all(|line| match line.get(position) {
+            Some(c) => c.is_whitespace(),
+            None => true,
+        })
+    };
+
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    let mut start = None;
+
+    for position in 0..=width {
+        match (start, position < width && !is_gap(position)) {
+            (None, true) => start = Some(position),
+            (Some(begin), false) => {
+                ranges.push((begin, position));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let header = match lines.first() {
+        Some(header) => header,
+        None => return vec![],
+    };
+
+    let has_header = |(begin, end): (usize, usize)| {
+        header
+            .iter()
+            .skip(begin)
+            .take(end - begin)
+            .any(|c| !c.is_whitespace())
+    };
+
+    let mut merged: Vec<(usize, usize)> = vec![];
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if !has_header(range) => last.1 = range.1,
+            Some(last) if !has_header(*last) => last.1 = range.1,
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+fn slice(line: &[char], begin: usize, end: Option<usize>) -> String {
+    let end = end.unwrap_or_else(|| line.len()).min(line.len());
+
+    if begin >= end {
+        return String::new();
+    }
+
+    line[begin..end].iter().collect::<String>().trim().to_string()
+}
+
+fn detect_columns_from_string(s: &str, skip: usize) -> Vec<Vec<(String, String)>> {
+    let lines: Vec<Vec<char>> = s
+        .lines()
+        .skip(skip)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let ranges = column_ranges(&lines);
+
+    if ranges.is_empty() {
+        return vec![];
+    }
+
+    let bounds: Vec<(usize, Option<usize>)> = ranges
+        .iter()
+        .enumerate()
+        .map(|(idx, (begin, end))| {
+            // the last column takes the rest of the line so that values
+            // containing spaces (eg. commands with arguments) stay whole
+            let begin = if idx == 0 { 0 } else { *begin };
+            if idx + 1 == ranges.len() {
+                (begin, None)
+            } else {
+                (begin, Some(*end))
+            }
+        })
+        .collect();
+
+    let headers: Vec<String> = bounds
+        .iter()
+        .map(|(begin, end)| slice(&lines[0], *begin, *end))
+        .collect();
+
+    lines
+        .iter()
+        .skip(1)
+        .map(|line| {
+            headers
+                .iter()
+                .zip(bounds.iter())
+                .map(|(header, (begin, end))| (header.clone(), slice(line, *begin, *end)))
+                .collect()
+        })
+        .collect()
+}
+
+fn detect_columns(
+    DetectColumnsArgs { skip }: DetectColumnsArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let skip = skip.map(|s| s.item).unwrap_or(0);
+
+    let stream = async_stream! {
+        let concat_string = input.collect_string(name.clone()).await?;
+        let rows = detect_columns_from_string(&concat_string.item, skip);
+
+        if rows.is_empty() && !concat_string.item.trim().is_empty() {
+            yield Err(ShellError::labeled_error_with_secondary(
+                "Could not detect columns",
+                "input has no aligned columns",
+                &name,
+                "value originates from here",
+                &concat_string.tag,
+            ));
+            return;
+        }
+
+        for row in rows {
+            let mut dict = TaggedDictBuilder::new(&name);
+
+            for (column, value) in row {
+                dict.insert_untagged(column, UntaggedValue::string(value));
+            }
+
+            yield ReturnSuccess::value(dict.into_value());
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(x: &str, y: &str) -> (String, String) {
+        (String::from(x), String::from(y))
+    }
+
+    #[test]
+    fn it_detects_columns_in_ps_style_output() {
+        let input = r#"
+  PID TTY          TIME CMD
+    1 ?        00:00:03 systemd
+  732 pts/0    00:00:00 bash --login
+10423 pts/0    00:00:00 ps
+"#;
+
+        let result = detect_columns_from_string(input, 0);
+
+        assert_eq!(
+            result,
+            vec![
+                vec![
+                    owned("PID", "1"),
+                    owned("TTY", "?"),
+                    owned("TIME", "00:00:03"),
+                    owned("CMD", "systemd"),
+                ],
+                vec![
+                    owned("PID", "732"),
+                    owned("TTY", "pts/0"),
+                    owned("TIME", "00:00:00"),
+                    owned("CMD", "bash --login"),
+                ],
+                vec![
+                    owned("PID", "10423"),
+                    owned("TTY", "pts/0"),
+                    owned("TIME", "00:00:00"),
+                    owned("CMD", "ps"),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn it_skips_leading_lines() {
+        let input = r#"total 8
+name    size
+a.txt   10
+b.txt   200
+"#;
+
+        let result = detect_columns_from_string(input, 1);
+
+        assert_eq!(
+            result,
+            vec![
+                vec![owned("name", "a.txt"), owned("size", "10")],
+                vec![owned("name", "b.txt"), owned("size", "200")],
+            ]
+        );
+    }
+}
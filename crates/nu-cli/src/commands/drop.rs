@@ -2,14 +2,15 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub struct Drop;
 
 #[derive(Deserialize)]
 pub struct DropArgs {
-    rows: Option<Tagged<u64>>,
+    rows: Option<Tagged<i64>>,
+    column: bool,
 }
 
 impl WholeStreamCommand for Drop {
@@ -18,15 +19,21 @@ impl WholeStreamCommand for Drop {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("drop").optional(
-            "rows",
-            SyntaxShape::Number,
-            "starting from the back, the number of rows to drop",
-        )
+        Signature::build("drop")
+            .optional(
+                "rows",
+                SyntaxShape::Number,
+                "starting from the back, the number of rows (or columns) to drop (negative counts from the front)",
+            )
+            .switch(
+                "column",
+                "drop the last columns of each row instead of the last rows",
+                Some('c'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Drop the last number of rows."
+        "Drop the last number of rows (or columns with --column)."
     }
 
     fn run(
@@ -38,23 +45,63 @@ impl WholeStreamCommand for Drop {
     }
 }
 
-fn drop(DropArgs { rows }: DropArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+fn drop(
+    DropArgs { rows, column }: DropArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let rows = rows.map(|quantity| quantity.item).unwrap_or(1);
+    let from_front = rows < 0;
+    let rows_to_drop = rows.checked_abs().unwrap_or(i64::max_value()) as usize;
+
+    if column {
+        return Ok(input
+            .map(move |item| ReturnSuccess::value(drop_columns(item, rows_to_drop, from_front)))
+            .to_output_stream());
+    }
+
+    if from_front {
+        return Ok(input
+            .skip(rows_to_drop)
+            .map(ReturnSuccess::value)
+            .to_output_stream());
+    }
+
+    Ok(all_but_last(input, rows_to_drop))
+}
+
+pub(crate) fn all_but_last(mut input: InputStream, count: usize) -> OutputStream {
     let stream = async_stream! {
-        let v: Vec<_> = context.input.into_vec().await;
-
-        let rows_to_drop = if let Some(quantity) = rows {
-            *quantity as usize
-        } else {
-            1
-        };
-
-        if rows_to_drop < v.len() {
-            let k = v.len() - rows_to_drop;
-            for x in v[0..k].iter() {
-                let y: Value = x.clone();
-                yield ReturnSuccess::value(y)
+        // Hold back only as many rows as we need to drop, so everything
+        // before them can keep flowing down the pipeline.
+        let mut delayed: VecDeque<Value> = VecDeque::with_capacity(count + 1);
+
+        while let Some(value) = input.next().await {
+            delayed.push_back(value);
+
+            if delayed.len() > count {
+                if let Some(oldest) = delayed.pop_front() {
+                    yield ReturnSuccess::value(oldest);
+                }
             }
         }
     };
-    Ok(stream.to_output_stream())
+
+    stream.to_output_stream()
+}
+
+fn drop_columns(item: Value, count: usize, from_front: bool) -> Value {
+    match item.value {
+        UntaggedValue::Row(mut dict) => {
+            let keep = dict.entries.len().saturating_sub(count);
+
+            dict.entries = if from_front {
+                dict.entries.into_iter().skip(count).collect()
+            } else {
+                dict.entries.into_iter().take(keep).collect()
+            };
+
+            UntaggedValue::Row(dict).into_value(item.tag)
+        }
+        _ => item,
+    }
 }
@@ -6,13 +6,18 @@ use crate::prelude::*;
 use futures::stream::once;
 
 use nu_errors::ShellError;
-use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape};
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
 
 pub struct Each;
 
 #[derive(Deserialize)]
 pub struct EachArgs {
     block: Block,
+    #[serde(rename = "keep-errors")]
+    keep_errors: bool,
+    numbered: bool,
 }
 
 impl WholeStreamCommand for Each {
@@ -21,11 +26,18 @@ impl WholeStreamCommand for Each {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("each").required(
-            "block",
-            SyntaxShape::Block,
-            "the block to run on each row",
-        )
+        Signature::build("each")
+            .required("block", SyntaxShape::Block, "the block to run on each row")
+            .switch(
+                "keep-errors",
+                "when the block fails on a row, give {error: ...} for it and keep going",
+                Some('k'),
+            )
+            .switch(
+                "numbered",
+                "give the block {index, item} rows as $it, counting the rows from 0",
+                Some('n'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -47,11 +59,23 @@ fn each(
     raw_args: RawCommandArgs,
 ) -> Result<OutputStream, ShellError> {
     let block = each_args.block;
+    let keep_errors = each_args.keep_errors;
+    let numbered = each_args.numbered;
     let scope = raw_args.call_info.scope.clone();
     let registry = context.registry.clone();
     let mut input_stream = context.input;
     let stream = async_stream! {
+        let mut index = 0;
+
         while let Some(input) = input_stream.next().await {
+            let tag = input.tag.clone();
+            let input = if numbered {
+                numbered_row(index, input)
+            } else {
+                input
+            };
+            index += 1;
+
             let mut context = Context::from_raw(&raw_args, &registry);
             let input_clone = input.clone();
             let input_stream = once(async { Ok(input) }).to_input_stream();
@@ -64,15 +88,37 @@ fn each(
             ).await;
 
             match result {
-                Ok(mut stream) => {
-                    let errors = context.get_errors();
-                    if let Some(error) = errors.first() {
-                        yield Err(error.clone());
+                Ok(mut stream) if keep_errors => {
+                    let mut failed = context.get_errors().first().cloned();
+
+                    if failed.is_none() {
+                        while let Some(result) = stream.next().await {
+                            match result {
+                                Value { value: UntaggedValue::Error(e), .. } => {
+                                    failed = Some(e);
+                                    break;
+                                }
+                                result => yield Ok(ReturnSuccess::Value(result)),
+                            }
+                        }
                     }
 
+                    // The block's commands only report some errors once their output is read
+                    if let Some(e) = failed.or_else(|| context.get_errors().first().cloned()) {
+                        yield ReturnSuccess::value(kept_error(e, &tag));
+                    }
+                }
+                Ok(mut stream) => {
                     while let Some(result) = stream.next().await {
                         yield Ok(ReturnSuccess::Value(result));
                     }
+
+                    if let Some(error) = context.get_errors().first() {
+                        yield Err(error.clone());
+                    }
+                }
+                Err(e) if keep_errors => {
+                    yield ReturnSuccess::value(kept_error(e, &tag));
                 }
                 Err(e) => {
                     yield Err(e);
@@ -83,3 +129,19 @@ fn each(
 
     Ok(stream.to_output_stream())
 }
+
+/// The `{index, item}` row `--numbered` gives the block in place of `item`.
+fn numbered_row(index: u64, item: Value) -> Value {
+    let mut row = TaggedDictBuilder::new(&item.tag);
+    row.insert_untagged("index", UntaggedValue::int(index));
+    row.insert_value("item", item);
+    row.into_value()
+}
+
+/// The row given in place of a failed row with `--keep-errors`. A bare error value would still
+/// stop the pipeline, so it's wrapped in an `error` column for later commands to filter on.
+pub(crate) fn kept_error(error: ShellError, tag: &Tag) -> Value {
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("error", UntaggedValue::Error(error));
+    row.into_value()
+}
@@ -1,7 +1,9 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    Primitive, Range, RangeInclusion, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
 
 pub struct Echo;
 
@@ -51,6 +53,14 @@ fn echo(args: EchoArgs, _: RunnableContext) -> Result<OutputStream, ShellError>
                         output.push(Ok(ReturnSuccess::Value(value.clone())));
                     }
                 }
+                Value {
+                    value: UntaggedValue::Primitive(Primitive::Range(range)),
+                    tag,
+                } => {
+                    for value in expand_range(&range, &tag)? {
+                        output.push(Ok(ReturnSuccess::Value(value)));
+                    }
+                }
                 _ => {
                     output.push(Ok(ReturnSuccess::Value(i.clone())));
                 }
@@ -63,3 +73,23 @@ fn echo(args: EchoArgs, _: RunnableContext) -> Result<OutputStream, ShellError>
 
     Ok(stream.to_output_stream())
 }
+
+/// Turns a range of integers into one row per number in it
+fn expand_range(range: &Range, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+    let (from, from_inclusion) = &range.from;
+    let (to, to_inclusion) = &range.to;
+
+    let mut from = from.item.as_u64(from.span)?;
+    let mut to = to.item.as_u64(to.span)?;
+
+    if let RangeInclusion::Exclusive = from_inclusion {
+        from += 1;
+    }
+    if let RangeInclusion::Inclusive = to_inclusion {
+        to += 1;
+    }
+
+    Ok((from..to)
+        .map(|n| UntaggedValue::int(n).into_value(tag))
+        .collect())
+}
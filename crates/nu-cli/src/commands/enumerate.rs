@@ -0,0 +1,77 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ReturnSuccess, ReturnValue, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct Enumerate;
+
+#[derive(Deserialize)]
+pub struct EnumerateArgs {
+    start: Option<Tagged<i64>>,
+}
+
+impl WholeStreamCommand for Enumerate {
+    fn name(&self) -> &str {
+        "enumerate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("enumerate").named(
+            "start",
+            SyntaxShape::Int,
+            "the index of the first value (defaults to 0)",
+            Some('s'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Number each value: rows get an index column, other values become {index, item} rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, enumerate)?.run()
+    }
+}
+
+fn enumerate(
+    EnumerateArgs { start }: EnumerateArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let start = start.map(|start| start.item).unwrap_or(0);
+
+    Ok(input
+        .enumerate()
+        .map(move |(offset, value)| numbered(value, start + offset as i64))
+        .to_output_stream())
+}
+
+fn numbered(value: Value, index: i64) -> ReturnValue {
+    let mut row = TaggedDictBuilder::new(&value.tag);
+    row.insert_untagged("index", UntaggedValue::int(index));
+
+    match value.value {
+        UntaggedValue::Row(dict) => {
+            if dict.entries.contains_key("index") {
+                return Err(ShellError::labeled_error(
+                    "Row already has an index column",
+                    "this row's index column would be overwritten",
+                    &value.tag,
+                ));
+            }
+
+            for (key, value) in dict.entries {
+                row.insert_value(key, value);
+            }
+        }
+        _ => row.insert_value("item", value),
+    }
+
+    ReturnSuccess::value(row.into_value())
+}
@@ -0,0 +1,148 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct Eol;
+
+#[derive(Deserialize)]
+pub struct EolArgs {
+    to: Option<Tagged<String>>,
+    detect: bool,
+}
+
+#[derive(Clone, Copy)]
+enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+impl WholeStreamCommand for Eol {
+    fn name(&self) -> &str {
+        "eol"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("eol")
+            .named(
+                "to",
+                SyntaxShape::String,
+                "the line ending to convert every line to, lf or crlf",
+                Some('t'),
+            )
+            .switch(
+                "detect",
+                "report the line ending most lines use (lf, crlf, or none) instead",
+                Some('d'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert the line endings of text to LF or CRLF, or detect which one it uses."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, eol)?.run()
+    }
+}
+
+fn eol(
+    EolArgs { to, detect }: EolArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let to = match (to, detect) {
+        (None, true) => None,
+        (Some(to), false) => Some(match to.item.to_lowercase().as_str() {
+            "lf" => LineEnding::Lf,
+            "crlf" => LineEnding::CrLf,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown line ending",
+                    "expected lf or crlf",
+                    &to.tag,
+                ))
+            }
+        }),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected either --to or --detect",
+                "requires --to lf, --to crlf, or --detect",
+                name,
+            ))
+        }
+    };
+
+    Ok(input
+        .map(move |value| {
+            let text = match &value.value {
+                UntaggedValue::Primitive(Primitive::String(s))
+                | UntaggedValue::Primitive(Primitive::Line(s)) => s,
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Expected text",
+                        "requires string input",
+                        &value.tag,
+                    ))
+                }
+            };
+
+            // The detected ending is new, so it isn't tagged as coming from the text's file
+            let result = match to {
+                Some(ending) => UntaggedValue::string(convert(text, ending)).into_value(&value.tag),
+                None => UntaggedValue::string(detect_ending(text)).into_value(&name),
+            };
+
+            ReturnSuccess::value(result)
+        })
+        .to_output_stream())
+}
+
+/// Every line ending in `text` as `ending`, whichever one each line had before.
+fn convert(text: &str, ending: LineEnding) -> String {
+    let lf = text.replace("\r\n", "\n");
+
+    match ending {
+        LineEnding::Lf => lf,
+        LineEnding::CrLf => lf.replace('\n', "\r\n"),
+    }
+}
+
+/// The line ending most lines of `text` end with (LF when as many use each), or `none` for a
+/// single line.
+fn detect_ending(text: &str) -> &'static str {
+    let crlf = text.matches("\r\n").count();
+    let lf = text.matches('\n').count() - crlf;
+
+    if crlf == 0 && lf == 0 {
+        "none"
+    } else if crlf > lf {
+        "crlf"
+    } else {
+        "lf"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, detect_ending, LineEnding};
+
+    #[test]
+    fn converts_mixed_endings_to_one_ending() {
+        let mixed = "one\r\ntwo\nthree\r\n";
+
+        assert_eq!(convert(mixed, LineEnding::Lf), "one\ntwo\nthree\n");
+        assert_eq!(convert(mixed, LineEnding::CrLf), "one\r\ntwo\r\nthree\r\n");
+    }
+
+    #[test]
+    fn detects_the_ending_most_lines_use() {
+        assert_eq!(detect_ending("one\r\ntwo\nthree\r\n"), "crlf");
+        assert_eq!(detect_ending("one\r\ntwo\nthree\n"), "lf");
+        assert_eq!(detect_ending("one"), "none");
+    }
+}
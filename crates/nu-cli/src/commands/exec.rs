@@ -0,0 +1,80 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::process::Command;
+
+pub struct Exec;
+
+#[derive(Deserialize)]
+pub struct ExecArgs {
+    pub command: Tagged<String>,
+    pub rest: Vec<Tagged<String>>,
+}
+
+impl WholeStreamCommand for Exec {
+    fn name(&self) -> &str {
+        "exec"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("exec")
+            .required("command", SyntaxShape::String, "the command to run")
+            .rest(SyntaxShape::String, "the arguments to the command")
+    }
+
+    fn usage(&self) -> &str {
+        "Replace the shell with the given external command."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, exec)?.run()
+    }
+}
+
+fn build_command(command: &Tagged<String>, rest: &[Tagged<String>]) -> Command {
+    let mut process = Command::new(&command.item);
+    process.args(rest.iter().map(|arg| &arg.item));
+    process
+}
+
+/// Replaces the current process image with the command, which only returns
+/// if the command could not be started
+#[cfg(unix)]
+fn exec(
+    ExecArgs { command, rest }: ExecArgs,
+    _: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    use std::os::unix::process::CommandExt;
+
+    let error = build_command(&command, &rest).exec();
+
+    Err(ShellError::labeled_error(
+        format!("Could not exec {}", command.item),
+        error.to_string(),
+        &command.tag,
+    ))
+}
+
+/// There is no way to replace the current process on Windows, so the command
+/// is run to completion and the shell exits with its exit code
+#[cfg(not(unix))]
+fn exec(
+    ExecArgs { command, rest }: ExecArgs,
+    _: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let status = build_command(&command, &rest).status().map_err(|error| {
+        ShellError::labeled_error(
+            format!("Could not exec {}", command.item),
+            error.to_string(),
+            &command.tag,
+        )
+    })?;
+
+    std::process::exit(status.code().unwrap_or(0))
+}
@@ -0,0 +1,151 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use byte_unit::{Byte, ByteUnit};
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_value_ext::ValueExt;
+
+pub struct Filesize;
+
+#[derive(Deserialize)]
+pub struct FilesizeArgs {
+    rest: Vec<ColumnPath>,
+    binary: bool,
+    decimal: bool,
+}
+
+impl WholeStreamCommand for Filesize {
+    fn name(&self) -> &str {
+        "filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("filesize")
+            .switch("binary", "use powers of 1024 (KiB, MiB, ...)", Some('b'))
+            .switch(
+                "decimal",
+                "use powers of 1000 (KB, MB, ...), the default",
+                Some('d'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally, the columns to format (defaults to the whole value)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Format byte counts (or columns of them) as human-readable file sizes."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, filesize)?.run()
+    }
+}
+
+fn filesize(
+    FilesizeArgs {
+        rest: columns,
+        binary,
+        decimal,
+    }: FilesizeArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if binary && decimal {
+        return Err(ShellError::labeled_error(
+            "Filesize can use either a binary or a decimal base, not both",
+            "use either --binary or --decimal",
+            name,
+        ));
+    }
+
+    Ok(input
+        .map(move |value| {
+            if columns.is_empty() {
+                return ReturnSuccess::value(format_value(&value, binary)?);
+            }
+
+            let mut formatted = value;
+
+            for path in &columns {
+                let cell = formatted
+                    .get_data_by_column_path(path, Box::new(move |(_, _, error)| error))?;
+                let cell = format_value(&cell, binary)?;
+
+                formatted = match formatted.replace_data_at_column_path(path, cell) {
+                    Some(replaced) => replaced,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Filesize could not find the column to format",
+                            "column name",
+                            &formatted.tag,
+                        ))
+                    }
+                };
+            }
+
+            ReturnSuccess::value(formatted)
+        })
+        .to_output_stream())
+}
+
+fn format_value(value: &Value, binary: bool) -> Result<Value, ShellError> {
+    let bytes = match &value.value {
+        UntaggedValue::Primitive(Primitive::Bytes(b)) => Some(*b),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_u64(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Can't format {} as a file size", value.type_name()),
+                "expected a number of bytes",
+                &value.tag,
+            ))
+        }
+    };
+
+    match bytes {
+        Some(bytes) => {
+            Ok(UntaggedValue::string(format_bytes(bytes, binary)).into_value(&value.tag))
+        }
+        None => Err(ShellError::labeled_error(
+            "Can't format a negative or oversized number as a file size",
+            "expected a number of bytes",
+            &value.tag,
+        )),
+    }
+}
+
+fn format_bytes(bytes: u64, binary: bool) -> String {
+    let byte = Byte::from_bytes(bytes as u128).get_appropriate_unit(binary);
+
+    match byte.get_unit() {
+        ByteUnit::B => format!("{} B", byte.get_value()),
+        _ => byte.format(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_bytes;
+
+    #[test]
+    fn formats_with_a_decimal_base() {
+        assert_eq!(format_bytes(1_500_000, false), "1.5 MB");
+    }
+
+    #[test]
+    fn formats_with_a_binary_base() {
+        assert_eq!(format_bytes(1_572_864, true), "1.5 MiB");
+    }
+
+    #[test]
+    fn keeps_small_counts_in_bytes() {
+        assert_eq!(format_bytes(512, false), "512 B");
+        assert_eq!(format_bytes(512, true), "512 B");
+    }
+}
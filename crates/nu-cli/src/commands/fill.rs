@@ -0,0 +1,135 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::as_string;
+use unicode_width::UnicodeWidthStr;
+
+pub struct Fill;
+
+#[derive(Deserialize)]
+pub struct FillArgs {
+    width: Tagged<u64>,
+    character: Option<Tagged<String>>,
+    alignment: Option<Tagged<String>>,
+}
+
+enum Alignment {
+    Left,
+    Right,
+}
+
+impl WholeStreamCommand for Fill {
+    fn name(&self) -> &str {
+        "fill"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("fill")
+            .required("width", SyntaxShape::Int, "the width to pad values to")
+            .named(
+                "character",
+                SyntaxShape::String,
+                "the character to pad with (defaults to a space)",
+                Some('c'),
+            )
+            .named(
+                "alignment",
+                SyntaxShape::String,
+                "which side the value is aligned to: left (default) or right",
+                Some('a'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Pad strings and numbers to a given width."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, fill)?.run()
+    }
+}
+
+fn fill(
+    FillArgs {
+        width,
+        character,
+        alignment,
+    }: FillArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let character = match character {
+        None => ' ',
+        Some(character) => {
+            let mut chars = character.item.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Fill character must be a single character",
+                        "expected a single character",
+                        character.tag,
+                    ))
+                }
+            }
+        }
+    };
+
+    let alignment = match alignment {
+        None => Alignment::Left,
+        Some(alignment) => match alignment.item.as_str() {
+            "left" | "l" => Alignment::Left,
+            "right" | "r" => Alignment::Right,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown alignment",
+                    "expected left or right",
+                    alignment.tag,
+                ))
+            }
+        },
+    };
+
+    let width = width.item as usize;
+
+    Ok(input
+        .map(move |value| fill_value(&value, width, character, &alignment))
+        .to_output_stream())
+}
+
+fn fill_value(value: &Value, width: usize, character: char, alignment: &Alignment) -> ReturnValue {
+    let s = match &value.value {
+        UntaggedValue::Primitive(_) => as_string(value)?,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a string or number",
+                "can't fill this value",
+                &value.tag,
+            ))
+        }
+    };
+
+    // Wide characters, like most CJK and emoji, take up two columns
+    let len = UnicodeWidthStr::width(s.as_str());
+
+    if len >= width {
+        return ReturnSuccess::value(UntaggedValue::string(s).into_value(&value.tag));
+    }
+
+    let padding = std::iter::repeat(character)
+        .take(width - len)
+        .collect::<String>();
+
+    let filled = match alignment {
+        Alignment::Left => format!("{}{}", s, padding),
+        Alignment::Right => format!("{}{}", padding, s),
+    };
+
+    ReturnSuccess::value(UntaggedValue::string(filled).into_value(&value.tag))
+}
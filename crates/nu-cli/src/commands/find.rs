@@ -0,0 +1,126 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Find;
+
+#[derive(Deserialize)]
+pub struct FindArgs {
+    rest: Vec<Tagged<String>>,
+    #[serde(rename(deserialize = "case-sensitive"))]
+    case_sensitive: bool,
+    #[serde(rename(deserialize = "line-numbers"))]
+    line_numbers: bool,
+    invert: bool,
+}
+
+impl WholeStreamCommand for Find {
+    fn name(&self) -> &str {
+        "find"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("find")
+            .rest(SyntaxShape::String, "the terms to search for")
+            .switch(
+                "case-sensitive",
+                "match the terms case-sensitively",
+                Some('c'),
+            )
+            .switch(
+                "line-numbers",
+                "emit matching lines as records of their line number and text",
+                Some('n'),
+            )
+            .switch("invert", "keep the values that don't match", Some('v'))
+    }
+
+    fn usage(&self) -> &str {
+        "Keep the rows (or values) containing any of the given terms."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, find)?.run()
+    }
+}
+
+fn contains_term(value: &Value, terms: &[String], case_sensitive: bool) -> bool {
+    let s = match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => s.clone(),
+        // Numbers are searched as they're written, eg) `find 42` keeps 1425
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_string(),
+        UntaggedValue::Row(dict) => {
+            return dict
+                .entries
+                .values()
+                .any(|field| contains_term(field, terms, case_sensitive))
+        }
+        _ => return false,
+    };
+
+    let s = if case_sensitive { s } else { s.to_lowercase() };
+
+    terms.iter().any(|term| s.contains(term.as_str()))
+}
+
+fn numbered_line(line_number: usize, value: Value) -> Value {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => {
+            let mut dict = TaggedDictBuilder::new(&value.tag);
+            dict.insert_untagged("line_number", UntaggedValue::int(line_number));
+            dict.insert_untagged("text", UntaggedValue::string(s));
+            dict.into_value()
+        }
+        _ => value,
+    }
+}
+
+fn find(
+    FindArgs {
+        rest,
+        case_sensitive,
+        line_numbers,
+        invert,
+    }: FindArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if rest.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Find requires at least one term",
+            "needs a term to search for",
+            name,
+        ));
+    }
+
+    let terms: Vec<String> = rest
+        .into_iter()
+        .map(|term| {
+            if case_sensitive {
+                term.item
+            } else {
+                term.item.to_lowercase()
+            }
+        })
+        .collect();
+
+    let objects = input.enumerate().filter_map(move |(idx, value)| {
+        let keep = contains_term(&value, &terms, case_sensitive) != invert;
+
+        futures::future::ready(match (keep, line_numbers) {
+            (false, _) => None,
+            (true, false) => Some(value),
+            (true, true) => Some(numbered_line(idx + 1, value)),
+        })
+    });
+
+    Ok(objects.from_input_stream())
+}
@@ -0,0 +1,113 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, UntaggedValue, Value};
+
+pub struct Flatten;
+
+#[derive(Deserialize)]
+pub struct FlattenArgs {
+    all: bool,
+}
+
+impl WholeStreamCommand for Flatten {
+    fn name(&self) -> &str {
+        "flatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("flatten").switch(
+            "all",
+            "flatten nested rows recursively instead of one level",
+            Some('a'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Flatten tables into their rows, and nested row columns into dotted columns."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, flatten)?.run()
+    }
+}
+
+fn flatten_row(
+    prefix: Option<&str>,
+    row: &IndexMap<String, Value>,
+    all: bool,
+    out: &mut IndexMap<String, Value>,
+) {
+    for (key, value) in row {
+        let key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.clone(),
+        };
+
+        match &value.value {
+            UntaggedValue::Row(nested) if prefix.is_none() || all => {
+                flatten_row(Some(&key), &nested.entries, all, out)
+            }
+            _ => {
+                out.insert(key, value.clone());
+            }
+        }
+    }
+}
+
+fn flatten_value(value: Value, all: bool) -> Vec<ReturnValue> {
+    match value.value {
+        UntaggedValue::Table(rows) => rows.into_iter().map(ReturnSuccess::value).collect(),
+        UntaggedValue::Row(row) => {
+            let mut out = IndexMap::new();
+            flatten_row(None, &row.entries, all, &mut out);
+
+            vec![ReturnSuccess::value(
+                UntaggedValue::row(out).into_value(value.tag),
+            )]
+        }
+        other => vec![ReturnSuccess::value(other.into_value(value.tag))],
+    }
+}
+
+fn flatten(
+    FlattenArgs { all }: FlattenArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .map(move |value| futures::stream::iter(flatten_value(value, all)))
+        .flatten()
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::flatten_value;
+    use nu_protocol::{ReturnSuccess, UntaggedValue};
+    use nu_source::{Span, Tag};
+
+    #[test]
+    fn flattened_rows_keep_their_spans_within_the_list() {
+        // As if from the literal `[1 two]`, where `1` is at 1..2 and `two` at 3..6
+        let list = UntaggedValue::table(&[
+            UntaggedValue::int(1).into_value(Tag::unknown_anchor(Span::new(1, 2))),
+            UntaggedValue::string("two").into_value(Tag::unknown_anchor(Span::new(3, 6))),
+        ])
+        .into_value(Tag::unknown_anchor(Span::new(0, 7)));
+
+        let spans: Vec<Span> = flatten_value(list, false)
+            .into_iter()
+            .map(|returned| match returned {
+                Ok(ReturnSuccess::Value(value)) => value.tag.span,
+                _ => panic!("expected a value"),
+            })
+            .collect();
+
+        assert_eq!(spans, vec![Span::new(1, 2), Span::new(3, 6)]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::commands::from_delimited_data::from_delimited_data;
+use crate::commands::from_delimited_data::{from_delimited_data, CellTypes};
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
@@ -9,6 +9,8 @@ pub struct FromCSV;
 #[derive(Deserialize)]
 pub struct FromCSVArgs {
     headerless: bool,
+    trim: bool,
+    infer: bool,
     separator: Option<Value>,
 }
 
@@ -30,6 +32,16 @@ impl WholeStreamCommand for FromCSV {
                 "don't treat the first row as column names",
                 None,
             )
+            .switch(
+                "trim",
+                "strip whitespace around cells (quoted cells keep theirs)",
+                Some('t'),
+            )
+            .switch(
+                "infer",
+                "turn cells that are clearly ints, floats or booleans into those types, and empty cells into nothing",
+                Some('i'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -48,6 +60,8 @@ impl WholeStreamCommand for FromCSV {
 fn from_csv(
     FromCSVArgs {
         headerless,
+        trim,
+        infer,
         separator,
     }: FromCSVArgs,
     runnable_context: RunnableContext,
@@ -75,5 +89,11 @@ fn from_csv(
         _ => ',',
     };
 
-    from_delimited_data(headerless, sep, "CSV", runnable_context)
+    let types = if infer {
+        CellTypes::Inferred
+    } else {
+        CellTypes::Text
+    };
+
+    from_delimited_data(headerless, trim, types, sep, "CSV", runnable_context)
 }
@@ -1,86 +1,378 @@
 use crate::prelude::*;
 use csv::{ErrorKind, ReaderBuilder};
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
 
-fn from_delimited_string_to_value(
-    s: String,
-    headerless: bool,
-    separator: char,
-    tag: impl Into<Tag>,
-) -> Result<Value, csv::Error> {
-    let mut reader = ReaderBuilder::new()
-        .has_headers(!headerless)
-        .delimiter(separator as u8)
-        .from_reader(s.as_bytes());
-    let tag = tag.into();
-
-    let headers = if headerless {
-        (1..=reader.headers()?.len())
-            .map(|i| format!("Column{}", i))
-            .collect::<Vec<String>>()
-    } else {
-        reader.headers()?.iter().map(String::from).collect()
-    };
+/// What the text of each cell is turned into
+#[derive(Debug, Clone, Copy)]
+pub enum CellTypes {
+    /// Every cell stays a string
+    Text,
+    /// Cells that parse as numbers become ints and decimals
+    Numbers,
+    /// Only cells that are unmistakably ints, floats or booleans are converted
+    Inferred,
+}
 
-    let mut rows = vec![];
-    for row in reader.records() {
-        let mut tagged_row = TaggedDictBuilder::new(&tag);
-        for (value, header) in row?.iter().zip(headers.iter()) {
+fn cell_value(value: &str, types: CellTypes, tag: &Tag) -> Value {
+    match types {
+        CellTypes::Text => UntaggedValue::string(value).into_value(tag),
+        CellTypes::Numbers => {
             if let Ok(i) = value.parse::<i64>() {
-                tagged_row.insert_value(header, UntaggedValue::int(i).into_value(&tag))
-            } else if let Ok(f) = value.parse::<f64>() {
-                tagged_row.insert_value(header, UntaggedValue::decimal(f).into_value(&tag))
+                UntaggedValue::int(i).into_value(tag)
+            } else if let Some(f) = value.parse::<f64>().ok().filter(|f| f.is_finite()) {
+                // decimals can't hold NaN or infinities, so those stay strings
+                UntaggedValue::decimal(f).into_value(tag)
             } else {
-                tagged_row.insert_value(header, UntaggedValue::string(value).into_value(&tag))
+                UntaggedValue::string(value).into_value(tag)
+            }
+        }
+        CellTypes::Inferred => inferred_cell_value(value, tag),
+    }
+}
+
+/// Converts cells that are unmistakably ints, floats or booleans, and empty cells to nothing.
+/// Anything that could be read more than one way, like `007`, `1e5` or `nan`, stays a string.
+fn inferred_cell_value(value: &str, tag: &Tag) -> Value {
+    let digits = value.trim_start_matches(|c| c == '-' || c == '+');
+
+    let looks_numeric = !digits.is_empty()
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && digits.chars().next().map(|c| c.is_ascii_digit()) == Some(true)
+        && !(digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0."));
+
+    if value.is_empty() {
+        UntaggedValue::nothing().into_value(tag)
+    } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+        UntaggedValue::boolean(value.eq_ignore_ascii_case("true")).into_value(tag)
+    } else if let (true, Ok(i)) = (looks_numeric, value.parse::<i64>()) {
+        UntaggedValue::int(i).into_value(tag)
+    } else if let (true, Ok(f)) = (looks_numeric, value.parse::<f64>()) {
+        UntaggedValue::decimal(f).into_value(tag)
+    } else {
+        UntaggedValue::string(value).into_value(tag)
+    }
+}
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Parses delimited text a chunk at a time. Only the records completed so far (up to the
+/// last line break outside of quotes) are parsed, and the rest waits for the next chunk, so
+/// memory stays bounded by the chunk size rather than growing with the whole input.
+pub(crate) struct DelimitedParser {
+    headerless: bool,
+    separator: char,
+    trim: bool,
+    types: CellTypes,
+    headers: Option<Vec<String>>,
+    pending: String,
+    lines_parsed: u64,
+    started: bool,
+}
+
+impl DelimitedParser {
+    pub(crate) fn new(
+        headerless: bool,
+        separator: char,
+        trim: bool,
+        types: CellTypes,
+    ) -> DelimitedParser {
+        DelimitedParser {
+            headerless,
+            separator,
+            trim,
+            types,
+            headers: None,
+            pending: String::new(),
+            lines_parsed: 0,
+            started: false,
+        }
+    }
+
+    /// Adds a chunk of text, giving back the rows it completed. Errors carry a description
+    /// of what went wrong, when there is one more helpful than "could not parse".
+    pub(crate) fn push(&mut self, chunk: &str, tag: &Tag) -> Result<Vec<Value>, Option<String>> {
+        // Files saved by Windows tools often start with a byte order mark, which would
+        // otherwise end up as part of the first header
+        let chunk = if self.started {
+            chunk
+        } else {
+            self.started = !chunk.is_empty();
+            chunk.trim_start_matches(BYTE_ORDER_MARK)
+        };
+
+        self.pending.push_str(chunk);
+
+        match complete_records_end(&self.pending, self.separator) {
+            Some(end) => {
+                let rest = self.pending.split_off(end);
+                let complete = std::mem::replace(&mut self.pending, rest);
+                self.parse(&complete, tag)
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Parses whatever is left once the input has run out.
+    pub(crate) fn finish(&mut self, tag: &Tag) -> Result<Vec<Value>, Option<String>> {
+        let rest = std::mem::take(&mut self.pending);
+        self.parse(&rest, tag)
+    }
+
+    fn parse(&mut self, text: &str, tag: &Tag) -> Result<Vec<Value>, Option<String>> {
+        let trimmed;
+        let text = if self.trim {
+            trimmed = trim_unquoted_cells(text, self.separator);
+            &trimmed
+        } else {
+            text
+        };
+
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.separator as u8)
+            .from_reader(text.as_bytes());
+
+        let mut rows = vec![];
+
+        for record in reader.records() {
+            let record = record.map_err(pretty_csv_error)?;
+
+            if self.headers.is_none() && !self.headerless {
+                self.headers = Some(record.iter().map(String::from).collect());
+                continue;
+            }
+
+            let headers = self.headers.get_or_insert_with(|| {
+                (1..=record.len()).map(|i| format!("Column{}", i)).collect()
+            });
+
+            if record.len() != headers.len() {
+                let line = self.lines_parsed + record.position().map(|p| p.line()).unwrap_or(0);
+
+                return Err(Some(format!(
+                    "Line {}: expected {} fields, found {}",
+                    line,
+                    headers.len(),
+                    record.len()
+                )));
+            }
+
+            let mut tagged_row = TaggedDictBuilder::new(tag);
+            for (value, header) in record.iter().zip(headers.iter()) {
+                tagged_row.insert_value(header, cell_value(value, self.types, tag));
             }
+            rows.push(tagged_row.into_value());
+        }
+
+        self.lines_parsed += text.matches('\n').count() as u64;
+
+        Ok(rows)
+    }
+}
+
+/// Where the records that are complete so far end: just after the last line break that isn't
+/// inside a quoted cell.
+fn complete_records_end(text: &str, separator: char) -> Option<usize> {
+    let mut quotes = Quotes::default();
+    let mut end = None;
+
+    for (idx, c) in text.char_indices() {
+        if quotes.read(c, separator) && c == '\n' {
+            end = Some(idx + 1);
         }
-        rows.push(tagged_row.into_value());
     }
 
-    Ok(UntaggedValue::Table(rows).into_value(&tag))
+    end
+}
+
+/// Follows which characters of delimited text sit inside a quoted cell. As in csv itself, a `"`
+/// only opens a quote at the start of a cell, so the one in an unquoted `5" disk` is just text.
+#[derive(Default)]
+struct Quotes {
+    in_quotes: bool,
+    quoted_cell: bool,
+    cell_started: bool,
+}
+
+impl Quotes {
+    /// Reads the next character, telling whether it is outside of any quotes.
+    fn read(&mut self, c: char, separator: char) -> bool {
+        if c == '"' && (self.in_quotes || self.quoted_cell || !self.cell_started) {
+            self.in_quotes = !self.in_quotes;
+            self.quoted_cell = true;
+            self.cell_started = true;
+            return false;
+        }
+
+        if self.in_quotes {
+            return false;
+        }
+
+        if c == separator || c == '\n' || c == '\r' {
+            self.quoted_cell = false;
+            self.cell_started = false;
+        } else if c != ' ' && c != '\t' {
+            self.cell_started = true;
+        }
+
+        true
+    }
+}
+
+/// Strips the whitespace around each cell, leaving whitespace inside quotes alone so that
+/// `  "  padded "  ` still reads as `  padded `.
+fn trim_unquoted_cells(text: &str, separator: char) -> String {
+    let mut trimmed = String::with_capacity(text.len());
+    let mut cell = String::new();
+    let mut quotes = Quotes::default();
+
+    let is_padding = |c: char| c != separator && (c == ' ' || c == '\t');
+
+    for c in text.chars() {
+        if quotes.read(c, separator) && (c == separator || c == '\n' || c == '\r') {
+            trimmed.push_str(cell.trim_matches(is_padding));
+            trimmed.push(c);
+            cell.clear();
+        } else {
+            cell.push(c);
+        }
+    }
+
+    trimmed.push_str(cell.trim_matches(is_padding));
+    trimmed
 }
 
 pub fn from_delimited_data(
     headerless: bool,
+    trim: bool,
+    types: CellTypes,
     sep: char,
     format_name: &'static str,
-    RunnableContext { input, name, .. }: RunnableContext,
+    RunnableContext {
+        mut input, name, ..
+    }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let name_tag = name;
 
     let stream = async_stream! {
-        let concat_string = input.collect_string(name_tag.clone()).await?;
+        let mut parser = DelimitedParser::new(headerless, sep, trim, types);
+        let mut value_tag = name_tag.clone();
+        let mut undecoded = vec![];
 
-        match from_delimited_string_to_value(concat_string.item, headerless, sep, name_tag.clone()) {
-            Ok(x) => match x {
-                Value { value: UntaggedValue::Table(list), .. } => {
-                    for l in list {
-                        yield ReturnSuccess::value(l);
+        loop {
+            let parsed = match input.next().await {
+                Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::String(s)),
+                    tag,
+                })
+                | Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::Line(s)),
+                    tag,
+                }) => {
+                    value_tag = tag;
+                    parser.push(&s, &name_tag)
+                }
+                Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::Binary(b)),
+                    tag,
+                }) => {
+                    value_tag = tag;
+
+                    match decode_utf8(&mut undecoded, &b) {
+                        Some(s) => parser.push(&s, &name_tag),
+                        None => {
+                            yield Err(expected_string(&name_tag, &value_tag));
+                            return;
+                        }
                     }
                 }
-                x => yield ReturnSuccess::value(x),
-            },
-            Err(err) => {
-                let line_one = match pretty_csv_error(err) {
-                    Some(pretty) => format!("Could not parse as {} ({})", format_name,pretty),
-                    None => format!("Could not parse as {}", format_name),
-                };
-                let line_two = format!("input cannot be parsed as {}", format_name);
-                yield Err(ShellError::labeled_error_with_secondary(
-                    line_one,
-                    line_two,
-                    name_tag.clone(),
-                    "value originates from here",
-                    concat_string.tag,
-                ))
-            } ,
+                Some(Value { tag, .. }) => {
+                    yield Err(expected_string(&name_tag, &tag));
+                    return;
+                }
+                None => break,
+            };
+
+            match parsed {
+                Ok(rows) => {
+                    for row in rows {
+                        yield ReturnSuccess::value(row);
+                    }
+                }
+                Err(detail) => {
+                    yield Err(parse_error(detail, format_name, &name_tag, &value_tag));
+                    return;
+                }
+            }
+        }
+
+        if !undecoded.is_empty() {
+            yield Err(expected_string(&name_tag, &value_tag));
+            return;
+        }
+
+        match parser.finish(&name_tag) {
+            Ok(rows) => {
+                for row in rows {
+                    yield ReturnSuccess::value(row);
+                }
+            }
+            Err(detail) => yield Err(parse_error(detail, format_name, &name_tag, &value_tag)),
         }
     };
 
     Ok(stream.to_output_stream())
 }
 
+/// Appends `chunk` to the bytes left over from the last one, giving back the text they decode
+/// to. A character split across chunks is held back until the rest of it arrives. Gives back
+/// None when the bytes aren't UTF-8.
+fn decode_utf8(undecoded: &mut Vec<u8>, chunk: &[u8]) -> Option<String> {
+    undecoded.extend_from_slice(chunk);
+
+    let valid = match std::str::from_utf8(undecoded) {
+        Ok(_) => undecoded.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => return None,
+    };
+
+    let rest = undecoded.split_off(valid);
+    String::from_utf8(std::mem::replace(undecoded, rest)).ok()
+}
+
+fn expected_string(name_tag: &Tag, value_tag: &Tag) -> ShellError {
+    ShellError::labeled_error_with_secondary(
+        "Expected a string from pipeline",
+        "requires string input",
+        name_tag.clone(),
+        "value originates from here",
+        value_tag.clone(),
+    )
+}
+
+fn parse_error(
+    detail: Option<String>,
+    format_name: &str,
+    name_tag: &Tag,
+    value_tag: &Tag,
+) -> ShellError {
+    let line_one = match detail {
+        Some(pretty) => format!("Could not parse as {} ({})", format_name, pretty),
+        None => format!("Could not parse as {}", format_name),
+    };
+    let line_two = format!("input cannot be parsed as {}", format_name);
+
+    ShellError::labeled_error_with_secondary(
+        line_one,
+        line_two,
+        name_tag.clone(),
+        "value originates from here",
+        value_tag.clone(),
+    )
+}
+
 fn pretty_csv_error(err: csv::Error) -> Option<String> {
     match err.kind() {
         ErrorKind::UnequalLengths {
@@ -103,3 +395,124 @@ fn pretty_csv_error(err: csv::Error) -> Option<String> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        complete_records_end, decode_utf8, inferred_cell_value, trim_unquoted_cells, CellTypes,
+        DelimitedParser,
+    };
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+
+    #[test]
+    fn parses_rows_split_across_chunks() {
+        let mut parser = DelimitedParser::new(false, ',', false, CellTypes::Text);
+        let tag = Tag::unknown();
+
+        let mut rows = parser.push("name,motto\nandres,\"hello", &tag).unwrap();
+        assert!(rows.is_empty());
+
+        rows.extend(parser.push(", world\"\njonathan,hi", &tag).unwrap());
+        assert_eq!(rows.len(), 1);
+
+        rows.extend(parser.finish(&tag).unwrap());
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn buffers_no_more_than_the_unfinished_record() {
+        let mut parser = DelimitedParser::new(false, ',', false, CellTypes::Text);
+        let tag = Tag::unknown();
+        let mut count = 0;
+
+        count += parser.push("index,square\n", &tag).unwrap().len();
+
+        // A generated input, far larger than anything kept around between chunks
+        for i in 0..10_000 {
+            count += parser
+                .push(&format!("{},{}\n{}", i, i * i, i), &tag)
+                .unwrap()
+                .len();
+            count += parser.push(",0\n", &tag).unwrap().len();
+
+            assert!(parser.pending.len() < 32);
+        }
+
+        count += parser.finish(&tag).unwrap().len();
+
+        assert_eq!(count, 20_000);
+    }
+
+    #[test]
+    fn strips_a_leading_byte_order_mark() {
+        let mut parser = DelimitedParser::new(false, ',', false, CellTypes::Text);
+        let tag = Tag::unknown();
+
+        parser.push("", &tag).unwrap();
+        let rows = parser
+            .push("\u{feff}name,motto\nandres,hi\n", &tag)
+            .unwrap();
+
+        assert_eq!(
+            parser.headers,
+            Some(vec!["name".to_string(), "motto".to_string()])
+        );
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[test]
+    fn decodes_characters_split_across_binary_chunks() {
+        let mut undecoded = vec![];
+        let bytes = "Andrés".as_bytes();
+
+        assert_eq!(
+            decode_utf8(&mut undecoded, &bytes[..5]),
+            Some("Andr".to_string())
+        );
+        assert_eq!(
+            decode_utf8(&mut undecoded, &bytes[5..]),
+            Some("és".to_string())
+        );
+        assert!(undecoded.is_empty());
+
+        assert_eq!(decode_utf8(&mut undecoded, &[0xff, b'a']), None);
+    }
+
+    #[test]
+    fn trims_unquoted_cells_only() {
+        assert_eq!(
+            trim_unquoted_cells("  a ,\"  b \"  ,c\n d,e \n", ','),
+            "a,\"  b \",c\nd,e\n"
+        );
+        assert_eq!(trim_unquoted_cells(" a \t b ", '\t'), "a\tb");
+    }
+
+    #[test]
+    fn reads_a_quote_inside_an_unquoted_cell_as_text() {
+        assert_eq!(
+            trim_unquoted_cells(" 5\" disk , b \n c,\" d \"\n", ','),
+            "5\" disk,b\nc,\" d \"\n"
+        );
+        assert_eq!(complete_records_end("5\" disk,b\nc,d", ','), Some(10));
+    }
+
+    #[test]
+    fn infers_only_unambiguous_cells() {
+        let tag = Tag::unknown();
+        let infer = |cell| inferred_cell_value(cell, &tag).value;
+
+        assert_eq!(infer("42"), UntaggedValue::int(42));
+        assert_eq!(infer("-1.5"), UntaggedValue::decimal(-1.5));
+        assert_eq!(infer("0.25"), UntaggedValue::decimal(0.25));
+        assert_eq!(infer("TRUE"), UntaggedValue::boolean(true));
+        assert_eq!(infer(""), UntaggedValue::nothing());
+
+        for ambiguous in &["007", "1e5", "nan", "inf", "1.2.3", "yes"] {
+            assert_eq!(
+                infer(*ambiguous),
+                UntaggedValue::Primitive(Primitive::String(ambiguous.to_string()))
+            );
+        }
+    }
+}
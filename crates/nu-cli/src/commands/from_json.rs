@@ -1,13 +1,17 @@
+use crate::commands::to_json::BINARY_KEY;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use chrono::{DateTime, Utc};
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{Span, Tagged};
 
 pub struct FromJSON;
 
 #[derive(Deserialize)]
 pub struct FromJSONArgs {
     objects: bool,
+    dates: bool,
 }
 
 impl WholeStreamCommand for FromJSON {
@@ -16,11 +20,13 @@ impl WholeStreamCommand for FromJSON {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from-json").switch(
-            "objects",
-            "treat each line as a separate value",
-            Some('o'),
-        )
+        Signature::build("from-json")
+            .switch("objects", "treat each line as a separate value", Some('o'))
+            .switch(
+                "dates",
+                "read strings holding ISO 8601 (RFC 3339) dates as dates",
+                Some('d'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -36,7 +42,11 @@ impl WholeStreamCommand for FromJSON {
     }
 }
 
-fn convert_json_value_to_nu_value(v: &serde_hjson::Value, tag: impl Into<Tag>) -> Value {
+fn convert_json_value_to_nu_value(
+    v: &serde_hjson::Value,
+    dates: bool,
+    tag: impl Into<Tag>,
+) -> Value {
     let tag = tag.into();
 
     match v {
@@ -45,19 +55,24 @@ fn convert_json_value_to_nu_value(v: &serde_hjson::Value, tag: impl Into<Tag>) -
         serde_hjson::Value::F64(n) => UntaggedValue::decimal(*n).into_value(&tag),
         serde_hjson::Value::U64(n) => UntaggedValue::int(*n).into_value(&tag),
         serde_hjson::Value::I64(n) => UntaggedValue::int(*n).into_value(&tag),
-        serde_hjson::Value::String(s) => {
-            UntaggedValue::Primitive(Primitive::String(String::from(s))).into_value(&tag)
-        }
+        serde_hjson::Value::String(s) => match DateTime::parse_from_rfc3339(s) {
+            Ok(date) if dates => UntaggedValue::date(date.with_timezone(&Utc)).into_value(&tag),
+            _ => UntaggedValue::Primitive(Primitive::String(String::from(s))).into_value(&tag),
+        },
         serde_hjson::Value::Array(a) => UntaggedValue::Table(
             a.iter()
-                .map(|x| convert_json_value_to_nu_value(x, &tag))
+                .map(|x| convert_json_value_to_nu_value(x, dates, &tag))
                 .collect(),
         )
         .into_value(tag),
         serde_hjson::Value::Object(o) => {
+            if let Some(bytes) = binary_from_json(v) {
+                return UntaggedValue::Primitive(Primitive::Binary(bytes)).into_value(&tag);
+            }
+
             let mut collected = TaggedDictBuilder::new(&tag);
             for (k, v) in o.iter() {
-                collected.insert_value(k.clone(), convert_json_value_to_nu_value(v, &tag));
+                collected.insert_value(k.clone(), convert_json_value_to_nu_value(v, dates, &tag));
             }
 
             collected.into_value()
@@ -65,13 +80,112 @@ fn convert_json_value_to_nu_value(v: &serde_hjson::Value, tag: impl Into<Tag>) -
     }
 }
 
-pub fn from_json_string_to_value(s: String, tag: impl Into<Tag>) -> serde_hjson::Result<Value> {
+/// The bytes of an object written by to-json for a binary value: a `$binary` key alone, holding
+/// base64 text.
+fn binary_from_json(value: &serde_hjson::Value) -> Option<Vec<u8>> {
+    let object = value.as_object()?;
+
+    if object.len() != 1 {
+        return None;
+    }
+
+    match object.get(BINARY_KEY) {
+        Some(serde_hjson::Value::String(encoded)) => base64::decode(encoded).ok(),
+        _ => None,
+    }
+}
+
+pub fn from_json_string_to_value(
+    s: String,
+    dates: bool,
+    tag: impl Into<Tag>,
+) -> serde_hjson::Result<Value> {
     let v: serde_hjson::Value = serde_hjson::from_str(&s)?;
-    Ok(convert_json_value_to_nu_value(&v, tag))
+    Ok(convert_json_value_to_nu_value(&v, dates, tag))
+}
+
+/// The error for JSON that could not be parsed, naming the line and column serde_hjson stopped at.
+/// `first_line` is where the parsed text starts within `source` (for `--objects`, each line is
+/// parsed on its own), so the position is always reported against the whole input.
+fn parse_error(
+    error: serde_hjson::Error,
+    source: &Tagged<String>,
+    first_line: usize,
+    name_tag: &Tag,
+) -> ShellError {
+    let (code, line, column) = match error {
+        serde_hjson::Error::Syntax(code, line, column) => (code, first_line + line, column),
+        error => {
+            return ShellError::labeled_error_with_secondary(
+                format!("Could not parse as JSON ({})", error),
+                "input cannot be parsed as JSON",
+                name_tag,
+                "value originates from here",
+                &source.tag,
+            )
+        }
+    };
+
+    let message = format!(
+        "Could not parse as JSON ({} at line {} column {})",
+        error_code_message(&code),
+        line,
+        column
+    );
+
+    match error_span(source, line, column) {
+        Some(span) => ShellError::labeled_error_with_secondary(
+            message,
+            format!("syntax error at line {}, column {}", line, column),
+            span,
+            "input cannot be parsed as JSON",
+            name_tag,
+        ),
+        None => ShellError::labeled_error_with_secondary(
+            message,
+            format!(
+                "input cannot be parsed as JSON (line {}, column {})",
+                line, column
+            ),
+            name_tag,
+            "value originates from here",
+            &source.tag,
+        ),
+    }
+}
+
+/// The text of a syntax error's code. `ErrorCode` has no `Display`, and its `Debug` writes most
+/// messages as quoted strings, so the quotes are trimmed off.
+fn error_code_message(code: &serde_hjson::ErrorCode) -> String {
+    format!("{:?}", code).trim_matches('"').to_string()
+}
+
+/// The span of the character at `line` and `column` (both counted from 1) of `source`, when the
+/// source's span covers its text, as it does for a string written in the pipeline. Text read
+/// from a file carries the span of the file name instead, which can't be pointed into.
+fn error_span(source: &Tagged<String>, line: usize, column: usize) -> Option<Span> {
+    let text = &source.item;
+    let span = source.tag.span;
+
+    let quotes = match span.end().checked_sub(span.start())? {
+        length if length == text.len() => 0,
+        length if length == text.len() + 2 => 1,
+        _ => return None,
+    };
+
+    let line_start: usize = text
+        .split('\n')
+        .take(line.saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum();
+    let offset = (line_start + column.saturating_sub(1)).min(text.len().saturating_sub(1));
+    let start = span.start() + quotes + offset;
+
+    Some(Span::new(start, start + 1))
 }
 
 fn from_json(
-    FromJSONArgs { objects }: FromJSONArgs,
+    FromJSONArgs { objects, dates }: FromJSONArgs,
     RunnableContext { input, name, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let name_tag = name;
@@ -80,30 +194,20 @@ fn from_json(
         let concat_string = input.collect_string(name_tag.clone()).await?;
 
         if objects {
-            for json_str in concat_string.item.lines() {
+            for (index, json_str) in concat_string.item.lines().enumerate() {
                 if json_str.is_empty() {
                     continue;
                 }
 
-                match from_json_string_to_value(json_str.to_string(), &name_tag) {
+                match from_json_string_to_value(json_str.to_string(), dates, &name_tag) {
                     Ok(x) =>
                         yield ReturnSuccess::value(x),
-                    Err(e) => {
-                        let mut message = "Could not parse as JSON (".to_string();
-                        message.push_str(&e.to_string());
-                        message.push_str(")");
-
-                        yield Err(ShellError::labeled_error_with_secondary(
-                            message,
-                            "input cannot be parsed as JSON",
-                            &name_tag,
-                            "value originates from here",
-                            concat_string.tag.clone()))
-                    }
+                    Err(e) =>
+                        yield Err(parse_error(e, &concat_string, index, &name_tag)),
                 }
             }
         } else {
-            match from_json_string_to_value(concat_string.item, name_tag.clone()) {
+            match from_json_string_to_value(concat_string.item.clone(), dates, name_tag.clone()) {
                 Ok(x) =>
                     match x {
                         Value { value: UntaggedValue::Table(list), .. } => {
@@ -113,18 +217,8 @@ fn from_json(
                         }
                         x => yield ReturnSuccess::value(x),
                     }
-                Err(e) => {
-                    let mut message = "Could not parse as JSON (".to_string();
-                    message.push_str(&e.to_string());
-                    message.push_str(")");
-
-                    yield Err(ShellError::labeled_error_with_secondary(
-                        message,
-                        "input cannot be parsed as JSON",
-                        name_tag,
-                        "value originates from here",
-                        concat_string.tag))
-                }
+                Err(e) =>
+                    yield Err(parse_error(e, &concat_string, 0, &name_tag)),
             }
         }
     };
@@ -0,0 +1,119 @@
+use crate::commands::WholeStreamCommand;
+use crate::evaluate::evaluate_baseline_expr;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::hir::{Expression, SpannedExpression, Variable};
+use nu_protocol::{Dictionary, ReturnSuccess, Scope, Signature, UntaggedValue, Value};
+
+pub struct FromNUON;
+
+impl WholeStreamCommand for FromNUON {
+    fn name(&self) -> &str {
+        "from-nuon"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from-nuon")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .nuon, nu's own literal syntax, and create table."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        from_nuon(args, registry)
+    }
+}
+
+pub fn from_nuon_string_to_value(
+    s: &str,
+    registry: &CommandRegistry,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    let tag = tag.into();
+    let (expr, error) = nu_parser::parse_literal(s, registry);
+
+    if error.is_some() {
+        return Err(ShellError::labeled_error(
+            "Could not parse as nuon",
+            "input cannot be parsed as nuon",
+            &tag,
+        ));
+    }
+
+    ensure_literal(&expr, &tag)?;
+
+    let value = evaluate_baseline_expr(&expr, registry, &Scope::empty())?;
+
+    Ok(retag(value, &tag))
+}
+
+/// Nuon is data, so only literals are read. Anything that would be looked up or run when
+/// evaluated, like `$nu.env.HOME`, is rejected rather than read from the user's session.
+fn ensure_literal(expr: &SpannedExpression, tag: &Tag) -> Result<(), ShellError> {
+    match &expr.expr {
+        Expression::Literal(_) | Expression::Synthetic(_) | Expression::Boolean(_) => Ok(()),
+        Expression::Variable(Variable::Other(name, _))
+            if name == "$true" || name == "$false" || name == "$nothing" =>
+        {
+            Ok(())
+        }
+        // A variable on its own is parsed as a path without any members
+        Expression::Path(path) if path.tail.is_empty() => ensure_literal(&path.head, tag),
+        Expression::List(items) => items.iter().try_for_each(|item| ensure_literal(item, tag)),
+        Expression::Row(entries) => entries
+            .iter()
+            .try_for_each(|(_, value)| ensure_literal(value, tag)),
+        _ => Err(ShellError::labeled_error(
+            "Could not parse as nuon",
+            "nuon can only hold literals",
+            tag,
+        )),
+    }
+}
+
+/// The evaluated values carry spans into the nuon text, which we swap for the command's own
+fn retag(value: Value, tag: &Tag) -> Value {
+    match value.value {
+        UntaggedValue::Table(rows) => {
+            UntaggedValue::Table(rows.into_iter().map(|row| retag(row, tag)).collect())
+                .into_value(tag)
+        }
+        UntaggedValue::Row(row) => UntaggedValue::Row(Dictionary::from(
+            row.entries
+                .into_iter()
+                .map(|(key, value)| (key, retag(value, tag)))
+                .collect::<IndexMap<_, _>>(),
+        ))
+        .into_value(tag),
+        other => other.into_value(tag),
+    }
+}
+
+fn from_nuon(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let args = args.evaluate_once(&registry)?;
+    let name_tag = args.name_tag();
+    let input = args.input;
+
+    let stream = async_stream! {
+        let concat_string = input.collect_string(name_tag.clone()).await?;
+
+        match from_nuon_string_to_value(&concat_string.item, &registry, &name_tag) {
+            Ok(Value { value: UntaggedValue::Table(list), .. }) => {
+                for l in list {
+                    yield ReturnSuccess::value(l);
+                }
+            }
+            Ok(x) => yield ReturnSuccess::value(x),
+            Err(e) => yield Err(e),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,74 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
+use nu_value_ext::as_string;
+
+pub struct FromPairs;
+
+impl WholeStreamCommand for FromPairs {
+    fn name(&self) -> &str {
+        "from-pairs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from-pairs")
+    }
+
+    fn usage(&self) -> &str {
+        "Builds a single row out of [key value] pairs. When a key repeats, the last value wins."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        from_pairs(args, registry)
+    }
+}
+
+fn from_pairs(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once(registry)?;
+    let name = args.name_tag();
+    let mut input = args.input;
+
+    let stream = async_stream! {
+        let mut entries: IndexMap<String, Value> = IndexMap::new();
+
+        while let Some(pair) = input.next().await {
+            match key_and_value(&pair) {
+                Ok((key, value)) => {
+                    entries.insert(key, value);
+                }
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        yield ReturnSuccess::value(UntaggedValue::row(entries).into_value(&name));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn key_and_value(pair: &Value) -> Result<(String, Value), ShellError> {
+    match &pair.value {
+        UntaggedValue::Table(items) if items.len() == 2 => {
+            Ok((as_string(&items[0])?, items[1].clone()))
+        }
+        UntaggedValue::Table(items) => Err(ShellError::labeled_error(
+            "Expected a pair of key and value",
+            format!("expected 2 items, found {}", items.len()),
+            &pair.tag,
+        )),
+        _ => Err(ShellError::labeled_error(
+            "Expected a pair of key and value",
+            format!("expected a [key value] list, found {}", pair.type_name()),
+            &pair.tag,
+        )),
+    }
+}
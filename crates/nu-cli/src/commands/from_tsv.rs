@@ -1,4 +1,4 @@
-use crate::commands::from_delimited_data::from_delimited_data;
+use crate::commands::from_delimited_data::{from_delimited_data, CellTypes};
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
@@ -9,6 +9,7 @@ pub struct FromTSV;
 #[derive(Deserialize)]
 pub struct FromTSVArgs {
     headerless: bool,
+    trim: bool,
 }
 
 impl WholeStreamCommand for FromTSV {
@@ -17,11 +18,17 @@ impl WholeStreamCommand for FromTSV {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from-tsv").switch(
-            "headerless",
-            "don't treat the first row as column names",
-            None,
-        )
+        Signature::build("from-tsv")
+            .switch(
+                "headerless",
+                "don't treat the first row as column names",
+                None,
+            )
+            .switch(
+                "trim",
+                "strip whitespace around cells (quoted cells keep theirs)",
+                Some('t'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -38,8 +45,15 @@ impl WholeStreamCommand for FromTSV {
 }
 
 fn from_tsv(
-    FromTSVArgs { headerless }: FromTSVArgs,
+    FromTSVArgs { headerless, trim }: FromTSVArgs,
     runnable_context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    from_delimited_data(headerless, '\t', "TSV", runnable_context)
+    from_delimited_data(
+        headerless,
+        trim,
+        CellTypes::Numbers,
+        '\t',
+        "TSV",
+        runnable_context,
+    )
 }
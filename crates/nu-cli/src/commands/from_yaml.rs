@@ -1,3 +1,4 @@
+use crate::commands::to_json::BINARY_KEY;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
@@ -51,6 +52,19 @@ impl WholeStreamCommand for FromYML {
     }
 }
 
+/// The bytes of a mapping written by to-yaml for a binary value: a `$binary` key alone, holding
+/// base64 text.
+fn binary_from_yaml(mapping: &serde_yaml::Mapping) -> Option<Vec<u8>> {
+    if mapping.len() != 1 {
+        return None;
+    }
+
+    match mapping.get(&serde_yaml::Value::String(BINARY_KEY.to_string())) {
+        Some(serde_yaml::Value::String(encoded)) => base64::decode(encoded).ok(),
+        _ => None,
+    }
+}
+
 fn convert_yaml_value_to_nu_value(
     v: &serde_yaml::Value,
     tag: impl Into<Tag>,
@@ -88,6 +102,10 @@ fn convert_yaml_value_to_nu_value(
             UntaggedValue::Table(result?).into_value(tag)
         }
         serde_yaml::Value::Mapping(t) => {
+            if let Some(bytes) = binary_from_yaml(t) {
+                return Ok(UntaggedValue::Primitive(Primitive::Binary(bytes)).into_value(tag));
+            }
+
             let mut collected = TaggedDictBuilder::new(&tag);
 
             for (k, v) in t.iter() {
@@ -118,6 +136,37 @@ pub fn from_yaml_string_to_value(s: String, tag: impl Into<Tag>) -> Result<Value
     Ok(convert_yaml_value_to_nu_value(&v, tag)?)
 }
 
+/// The documents of a multi-document YAML stream, split at the `---` lines that start each one.
+/// Documents with nothing in them, like the one before a leading `---`, are left out.
+fn yaml_documents(text: &str) -> Vec<String> {
+    let mut documents = vec![String::new()];
+
+    for line in text.lines() {
+        let rest = if line.trim_end() == "---" {
+            Some("")
+        } else if line.starts_with("--- ") {
+            Some(&line[4..])
+        } else {
+            None
+        };
+
+        match rest {
+            Some(rest) => documents.push(format!("{}\n", rest)),
+            None => {
+                if let Some(document) = documents.last_mut() {
+                    document.push_str(line);
+                    document.push('\n');
+                }
+            }
+        }
+    }
+
+    documents
+        .into_iter()
+        .filter(|document| !document.trim().is_empty())
+        .collect()
+}
+
 fn from_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once(registry)?;
     let tag = args.name_tag();
@@ -125,6 +174,21 @@ fn from_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
 
     let stream = async_stream! {
         let concat_string = input.collect_string(tag.clone()).await?;
+        let documents = yaml_documents(&concat_string.item);
+
+        // Each document of a multi-document stream becomes a value of its own
+        if documents.len() > 1 {
+            for document in documents {
+                match from_yaml_string_to_value(document, tag.clone()) {
+                    Ok(x) => yield ReturnSuccess::value(x),
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                }
+            }
+            return;
+        }
 
         match from_yaml_string_to_value(concat_string.item, tag.clone()) {
             Ok(x) => match x {
@@ -1,20 +1,23 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use crate::utils::closest_name;
 use indexmap::set::IndexSet;
 use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::{
     did_you_mean, ColumnPath, PathMember, Primitive, ReturnSuccess, ReturnValue, Signature,
-    SyntaxShape, UnspannedPathMember, UntaggedValue, Value,
+    SyntaxShape, TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::span_for_spanned_list;
-use nu_value_ext::get_data_by_column_path;
+use nu_value_ext::{as_string, column_path_ignoring_case, get_data_by_column_path};
 
 pub struct Get;
 
 #[derive(Deserialize)]
 pub struct GetArgs {
     rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-case"))]
+    ignore_case: bool,
 }
 
 impl WholeStreamCommand for Get {
@@ -23,10 +26,16 @@ impl WholeStreamCommand for Get {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("get").rest(
-            SyntaxShape::ColumnPath,
-            "optionally return additional data by path",
-        )
+        Signature::build("get")
+            .switch(
+                "ignore-case",
+                "match column names regardless of case",
+                Some('i'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally return additional data by path (more than one path returns a row)",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -59,11 +68,6 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
                     } => {
                         let primary_label = format!("There isn't a column named '{}'", &column);
 
-                        let suggestions: IndexSet<_> = rows
-                            .iter()
-                            .filter_map(|r| did_you_mean(&r, &column_path_tried))
-                            .map(|s| s[0].1.to_owned())
-                            .collect();
                         let mut existing_columns: IndexSet<_> = IndexSet::default();
                         let mut names: Vec<String> = vec![];
 
@@ -89,15 +93,7 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
                                 "Unknown column",
                                 primary_label,
                                 column_path_tried.span,
-                                format!(
-                                    "Perhaps you meant '{}'? Columns available: {}",
-                                    suggestions
-                                        .iter()
-                                        .map(|x| x.to_owned())
-                                        .collect::<Vec<String>>()
-                                        .join(","),
-                                    names.join(",")
-                                ),
+                                missing_column_label(column, closest_name(column, &names), &names),
                                 column_path_tried.span.since(path_members_span),
                             );
                         };
@@ -129,17 +125,14 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
                         ..
                     } => {
                         let primary_label = format!("There isn't a column named '{}'", &column);
+                        let names = obj_source.data_descriptors();
 
-                        if let Some(suggestions) = did_you_mean(&obj_source, column_path_tried) {
+                        if !names.is_empty() {
                             return ShellError::labeled_error_with_secondary(
                                 "Unknown column",
                                 primary_label,
                                 column_path_tried.span,
-                                format!(
-                                    "Perhaps you meant '{}'? Columns available: {}",
-                                    suggestions[0].1,
-                                    &obj_source.data_descriptors().join(",")
-                                ),
+                                missing_column_label(column, closest_name(column, &names), &names),
                                 column_path_tried.span.since(path_members_span),
                             );
                         }
@@ -176,8 +169,103 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
     )
 }
 
+fn missing_column_label(column: &str, suggestion: Option<&str>, available: &[String]) -> String {
+    match suggestion {
+        Some(suggestion) => format!(
+            "column `{}` not found. Perhaps you meant '{}'? available: {}",
+            column,
+            suggestion,
+            available.join(", ")
+        ),
+        None => format!(
+            "column `{}` not found; available: {}",
+            column,
+            available.join(", ")
+        ),
+    }
+}
+
+fn get_column_paths_as_row(paths: &[ColumnPath], ignore_case: bool, obj: &Value) -> ReturnValue {
+    let mut row = TaggedDictBuilder::new(&obj.tag);
+
+    for path in paths {
+        let path = &resolve_case(path, ignore_case, obj);
+        let key = as_string(
+            &UntaggedValue::Primitive(Primitive::ColumnPath(path.clone())).into_untagged_value(),
+        )?;
+
+        row.insert_value(key, get_column_path(path, obj)?);
+    }
+
+    ReturnSuccess::value(row.into_value())
+}
+
+fn starts_with_index(path: &ColumnPath) -> bool {
+    matches!(
+        path.members().first(),
+        Some(PathMember {
+            unspanned: UnspannedPathMember::Int(_),
+            ..
+        })
+    )
+}
+
+/// The value `path` picks out of a stream of values, which is read no further than the row the
+/// path starts with.
+async fn get_streamed_column_path(
+    values: impl Stream<Item = Value> + Unpin,
+    path: &ColumnPath,
+    ignore_case: bool,
+) -> Result<Value, ShellError> {
+    let (first, index, rest) = match path.members().split_first() {
+        Some((
+            first @ PathMember {
+                unspanned: UnspannedPathMember::Int(index),
+                ..
+            },
+            rest,
+        )) => (first, index, rest),
+        _ => {
+            return Err(ShellError::unexpected(
+                "get expected a path starting with a row",
+            ))
+        }
+    };
+
+    let row = match index.to_usize() {
+        Some(index) => values.skip(index).take(1).next().await,
+        None => None,
+    };
+
+    match row {
+        Some(row) if rest.is_empty() => Ok(row),
+        Some(row) => {
+            let rest = ColumnPath::new(rest.to_vec());
+            get_column_path(&resolve_case(&rest, ignore_case, &row), &row)
+        }
+        None if first.optional => Ok(UntaggedValue::nothing().into_value(first.span)),
+        None => Err(ShellError::labeled_error(
+            "Row not found",
+            format!("There isn't a row indexed at {}", index),
+            first.span,
+        )),
+    }
+}
+
+/// With `--ignore-case`, the path spelled the way `obj` spells its columns.
+fn resolve_case(path: &ColumnPath, ignore_case: bool, obj: &Value) -> ColumnPath {
+    if ignore_case {
+        column_path_ignoring_case(obj, path)
+    } else {
+        path.clone()
+    }
+}
+
 pub fn get(
-    GetArgs { rest: mut fields }: GetArgs,
+    GetArgs {
+        rest: mut fields,
+        ignore_case,
+    }: GetArgs,
     RunnableContext { mut input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     if fields.is_empty() {
@@ -192,6 +280,51 @@ pub fn get(
 
         let stream: BoxStream<'static, ReturnValue> = stream.boxed();
 
+        Ok(stream.to_output_stream())
+    } else if fields.len() > 1 {
+        let stream = input.map(move |item| get_column_paths_as_row(&fields, ignore_case, &item));
+
+        Ok(stream.to_output_stream())
+    } else if starts_with_index(&fields[0]) {
+        // A path starting with a row number indexes into the input as a whole: the table, when a
+        // single one comes in, or else the stream, read only as far as the row asked for
+        let path = fields.remove(0);
+
+        let stream = async_stream! {
+            let mut seen = vec![];
+
+            if let Some(first) = input.next().await {
+                let is_table = matches!(first.value, UntaggedValue::Table(_));
+                seen.push(first);
+
+                if is_table {
+                    if let Some(second) = input.next().await {
+                        seen.push(second);
+                    }
+                }
+            }
+
+            let result = if seen.len() == 1 && matches!(seen[0].value, UntaggedValue::Table(_)) {
+                let table = seen.remove(0);
+                get_column_path(&resolve_case(&path, ignore_case, &table), &table)
+            } else {
+                let values = futures::stream::iter(seen).chain(input);
+                get_streamed_column_path(values, &path, ignore_case).await
+            };
+
+            match result {
+                Ok(Value { value: UntaggedValue::Table(rows), .. }) => {
+                    for row in rows {
+                        yield ReturnSuccess::value(row);
+                    }
+                }
+                Ok(other) => yield ReturnSuccess::value(other),
+                Err(reason) => yield Err(reason),
+            }
+        };
+
+        let stream: BoxStream<'static, ReturnValue> = stream.boxed();
+
         Ok(stream.to_output_stream())
     } else {
         let member = fields.remove(0);
@@ -208,7 +341,7 @@ pub fn get(
                     .collect::<Vec<&ColumnPath>>();
 
                 for path in column_paths {
-                    let res = get_column_path(&path, &item);
+                    let res = get_column_path(&resolve_case(path, ignore_case, &item), &item);
 
                     match res {
                         Ok(got) => match got {
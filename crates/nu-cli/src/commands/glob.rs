@@ -0,0 +1,145 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use ::glob::{MatchOptions, Pattern};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::path::{Path, PathBuf};
+
+pub struct Glob;
+
+#[derive(Deserialize)]
+pub struct GlobArgs {
+    pattern: Tagged<String>,
+    depth: Option<Tagged<u64>>,
+    strict: bool,
+}
+
+impl WholeStreamCommand for Glob {
+    fn name(&self) -> &str {
+        "glob"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("glob")
+            .required(
+                "pattern",
+                SyntaxShape::Pattern,
+                "the pattern to expand, like \"*.txt\" or \"**/*.rs\"",
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "the deepest a match may be, in path components from the current directory",
+                Some('d'),
+            )
+            .switch("strict", "error when nothing matches", Some('s'))
+    }
+
+    fn usage(&self) -> &str {
+        "Expand a glob pattern into the paths it matches, relative to the current directory."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, glob)?.run()
+    }
+}
+
+fn glob(
+    GlobArgs {
+        pattern,
+        depth,
+        strict,
+    }: GlobArgs,
+    RunnableContext {
+        shell_manager,
+        name,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let cwd = PathBuf::from(shell_manager.path());
+    let invalid = |e: ::glob::PatternError| {
+        ShellError::labeled_error("Invalid glob pattern", e.msg, &pattern.tag)
+    };
+
+    let paths = match &depth {
+        // The walk itself stops at the depth, so a deep tree isn't read only to be thrown away
+        Some(depth) => {
+            let matcher = Pattern::new(&pattern.item).map_err(invalid)?;
+            let options = MatchOptions {
+                require_literal_separator: true,
+                ..MatchOptions::new()
+            };
+
+            let mut found = vec![];
+            walk(&cwd, &cwd, depth.item, &mut found);
+            found.sort();
+
+            found
+                .into_iter()
+                .filter(|path| matcher.matches_path_with(path, options))
+                .collect::<Vec<_>>()
+        }
+        None => {
+            // The current directory is matched as it is, even if it holds `[` or `*`
+            let full_pattern =
+                PathBuf::from(Pattern::escape(&cwd.to_string_lossy())).join(&pattern.item);
+
+            ::glob::glob(&full_pattern.to_string_lossy())
+                .map_err(invalid)?
+                .filter_map(Result::ok)
+                .map(|path| match path.strip_prefix(&cwd) {
+                    Ok(relative) => relative.to_path_buf(),
+                    Err(_) => path,
+                })
+                .collect()
+        }
+    };
+
+    let paths: VecDeque<_> = paths
+        .into_iter()
+        .map(|path| {
+            ReturnSuccess::value(
+                UntaggedValue::string(path.to_string_lossy()).into_value(name.clone()),
+            )
+        })
+        .collect();
+
+    if strict && paths.is_empty() {
+        return Err(ShellError::labeled_error(
+            "No matches found",
+            "nothing matches this pattern",
+            &pattern.tag,
+        ));
+    }
+
+    Ok(futures::stream::iter(paths).to_output_stream())
+}
+
+/// Gathers the paths under `dir`, relative to `root`, going no more than `depth` levels down.
+fn walk(root: &Path, dir: &Path, depth: u64, found: &mut Vec<PathBuf>) {
+    if depth == 0 {
+        return;
+    }
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+
+        if let Ok(relative) = path.strip_prefix(root) {
+            found.push(relative.to_path_buf());
+        }
+
+        if path.is_dir() {
+            walk(root, &path, depth - 1, found);
+        }
+    }
+}
@@ -1,7 +1,13 @@
+use crate::commands::classified::block::run_block;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use futures::stream::once;
+use indexmap::IndexMap;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Scope, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
 use nu_source::Tagged;
 use nu_value_ext::{as_string, get_data_by_key};
 
@@ -9,7 +15,9 @@ pub struct GroupBy;
 
 #[derive(Deserialize)]
 pub struct GroupByArgs {
-    column_name: Tagged<String>,
+    grouper: Value,
+    #[serde(rename(deserialize = "to-table"))]
+    to_table: bool,
 }
 
 impl WholeStreamCommand for GroupBy {
@@ -18,15 +26,21 @@ impl WholeStreamCommand for GroupBy {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("group-by").required(
-            "column_name",
-            SyntaxShape::String,
-            "the name of the column to group by",
-        )
+        Signature::build("group-by")
+            .required(
+                "grouper",
+                SyntaxShape::Any,
+                "the name of the column to group by, or a block giving each row's group",
+            )
+            .switch(
+                "to-table",
+                "output a table of {group, items} rows rather than a single row",
+                Some('t'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Creates a new table with the data from the table rows grouped by the column given."
+        "Creates a new table with the data from the table rows grouped by the column (or block) given."
     }
 
     fn run(
@@ -34,14 +48,19 @@ impl WholeStreamCommand for GroupBy {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        args.process(registry, group_by)?.run()
+        Ok(args.process_raw(registry, group_by)?.run())
     }
 }
 
 pub fn group_by(
-    GroupByArgs { column_name }: GroupByArgs,
-    RunnableContext { input, name, .. }: RunnableContext,
+    GroupByArgs { grouper, to_table }: GroupByArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
 ) -> Result<OutputStream, ShellError> {
+    let registry = context.registry.clone();
+    let name = context.name.clone();
+    let input = context.input;
+
     let stream = async_stream! {
         let values: Vec<Value> = input.collect().await;
 
@@ -49,27 +68,130 @@ pub fn group_by(
             yield Err(ShellError::labeled_error(
                     "Expected table from pipeline",
                     "requires a table input",
-                    column_name.span()
-                ))
-        } else {
-            match group(&column_name, values, name) {
-                Ok(grouped) => yield ReturnSuccess::value(grouped),
-                Err(err) => yield Err(err)
+                    &grouper.tag
+                ));
+            return;
+        }
+
+        let groups = match &grouper.value {
+            UntaggedValue::Block(block) => {
+                let mut context = Context::from_raw(&raw_args, &registry);
+                let scope = raw_args.call_info.scope.clone();
+                let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+                let mut failed = None;
+
+                for value in values {
+                    match block_key(block, &mut context, &scope, &value).await {
+                        Ok(key) => groups.entry(key).or_insert_with(Vec::new).push(value),
+                        Err(err) => {
+                            failed = Some(err);
+                            break;
+                        }
+                    }
+                }
+
+                match failed {
+                    Some(err) => Err(err),
+                    None => Ok(groups),
+                }
             }
+            _ => match as_string(&grouper) {
+                Ok(column_name) => group_rows(&column_name.tagged(&grouper.tag), values),
+                Err(err) => Err(err),
+            },
+        };
+
+        match groups {
+            Ok(groups) => match grouped_value(groups, to_table, &name) {
+                Value { value: UntaggedValue::Table(rows), .. } => {
+                    for row in rows {
+                        yield ReturnSuccess::value(row);
+                    }
+                }
+                grouped => yield ReturnSuccess::value(grouped),
+            },
+            Err(err) => yield Err(err),
         }
     };
 
     Ok(stream.to_output_stream())
 }
 
+/// Runs `block` with `value` as `$it` and gives back its first output as the group key.
+async fn block_key(
+    block: &Block,
+    context: &mut Context,
+    scope: &Scope,
+    value: &Value,
+) -> Result<String, ShellError> {
+    let input = value.clone();
+    let input_stream = once(async { Ok(input) }).to_input_stream();
+
+    let mut stream = run_block(
+        block,
+        context,
+        input_stream,
+        &scope.clone().set_it(value.clone()),
+    )
+    .await?;
+
+    if let Some(error) = context.get_errors().first() {
+        return Err(error.clone());
+    }
+
+    match stream.next().await {
+        Some(key) => as_string(&key),
+        None => Err(ShellError::labeled_error(
+            "Expected a group from the block",
+            "the block gave nothing back for this row",
+            &value.tag,
+        )),
+    }
+}
+
+/// The groups as a single row with a column per group, or, for `--to-table`, as a table with
+/// a `{group, items}` row per group.
+fn grouped_value(groups: IndexMap<String, Vec<Value>>, to_table: bool, tag: &Tag) -> Value {
+    if !to_table {
+        let mut out = TaggedDictBuilder::new(tag);
+
+        for (k, v) in groups.iter() {
+            out.insert_untagged(k, UntaggedValue::table(v));
+        }
+
+        return out.into_value();
+    }
+
+    let rows: Vec<Value> = groups
+        .into_iter()
+        .map(|(k, v)| {
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("group", UntaggedValue::string(k));
+            row.insert_untagged("items", UntaggedValue::table(&v));
+            row.into_value()
+        })
+        .collect();
+
+    UntaggedValue::table(&rows).into_value(tag)
+}
+
 pub fn group(
     column_name: &Tagged<String>,
     values: Vec<Value>,
     tag: impl Into<Tag>,
 ) -> Result<Value, ShellError> {
-    let tag = tag.into();
+    Ok(grouped_value(
+        group_rows(column_name, values)?,
+        false,
+        &tag.into(),
+    ))
+}
 
-    let mut groups: indexmap::IndexMap<String, Vec<Value>> = indexmap::IndexMap::new();
+fn group_rows(
+    column_name: &Tagged<String>,
+    values: Vec<Value>,
+) -> Result<IndexMap<String, Vec<Value>>, ShellError> {
+    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
 
     for value in values {
         let group_key = get_data_by_key(&value, column_name.borrow_spanned());
@@ -104,13 +226,7 @@ pub fn group(
         }
     }
 
-    let mut out = TaggedDictBuilder::new(&tag);
-
-    for (k, v) in groups.iter() {
-        out.insert_untagged(k, UntaggedValue::table(v));
-    }
-
-    Ok(out.into_value())
+    Ok(groups)
 }
 
 #[cfg(test)]
@@ -15,6 +15,9 @@ pub struct Histogram;
 pub struct HistogramArgs {
     column_name: Tagged<String>,
     rest: Vec<Tagged<String>>,
+    width: Option<Tagged<u64>>,
+    #[serde(rename = "percentage-col")]
+    percentage_col: bool,
 }
 
 impl WholeStreamCommand for Histogram {
@@ -29,6 +32,17 @@ impl WholeStreamCommand for Histogram {
                 SyntaxShape::String,
                 "the name of the column to graph by",
             )
+            .named(
+                "width",
+                SyntaxShape::Int,
+                "the widest a bar may be, in characters (defaults to 100)",
+                Some('w'),
+            )
+            .switch(
+                "percentage-col",
+                "add a percentage column with each group's share of the rows",
+                Some('p'),
+            )
             .rest(
                 SyntaxShape::String,
                 "column name to give the histogram's frequency column",
@@ -49,9 +63,16 @@ impl WholeStreamCommand for Histogram {
 }
 
 pub fn histogram(
-    HistogramArgs { column_name, rest }: HistogramArgs,
+    HistogramArgs {
+        column_name,
+        rest,
+        width,
+        percentage_col,
+    }: HistogramArgs,
     RunnableContext { input, name, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    let width = width.map(|width| width.item as usize).unwrap_or(100);
+
     let stream = async_stream! {
         let values: Vec<Value> = input.collect().await;
 
@@ -64,6 +85,8 @@ pub fn histogram(
         let reduced = reduce(&evaled, None, &name)?;
         let maxima = map_max(&reduced, None, &name)?;
         let percents = percentages(&reduced, maxima, &name)?;
+        let counts = group_counts(&reduced);
+        let total: u64 = counts.iter().sum();
 
         match percents {
             Value {
@@ -90,8 +113,15 @@ pub fn histogram(
                         let value: Tagged<String> = group_labels.get(idx).ok_or_else(|| ShellError::labeled_error("Unable to load group labels", "unabled to load group labels", &name))?.clone();
                         fact.insert_value(&column, UntaggedValue::string(value.item).into_value(value.tag));
 
+                        if percentage_col {
+                            let count = counts.get(idx).copied().unwrap_or(0);
+                            let share = if total == 0 { 0.0 } else { count as f64 * 100.0 / total as f64 };
+                            fact.insert_untagged("percentage", UntaggedValue::string(format!("{:.2}%", share)));
+                        }
+
                         if let Value { value: UntaggedValue::Primitive(Primitive::Int(ref num)), ref tag } = percentage.clone() {
-                            let string = std::iter::repeat("*").take(num.to_i32().ok_or_else(|| ShellError::labeled_error("Expected a number", "expected a number", tag))? as usize).collect::<String>();
+                            let percent = num.to_usize().ok_or_else(|| ShellError::labeled_error("Expected a number", "expected a number", tag))?;
+                            let string = std::iter::repeat("*").take(percent * width / 100).collect::<String>();
                             fact.insert_untagged(&frequency_column_name, UntaggedValue::string(string));
                         }
 
@@ -108,6 +138,29 @@ pub fn histogram(
     Ok(stream.to_output_stream())
 }
 
+/// The number of rows in each group, in the same order as the groups' labels.
+fn group_counts(reduced: &Value) -> Vec<u64> {
+    match reduced {
+        Value {
+            value: UntaggedValue::Table(datasets),
+            ..
+        } => match datasets.get(0) {
+            Some(Value {
+                value: UntaggedValue::Table(data),
+                ..
+            }) => data
+                .iter()
+                .map(|d| match &d.value {
+                    UntaggedValue::Primitive(Primitive::Int(n)) => n.to_u64().unwrap_or(0),
+                    _ => 0,
+                })
+                .collect(),
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
 fn percentages(values: &Value, max: Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     let tag = tag.into();
 
@@ -3,13 +3,16 @@ use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
 
 pub struct History;
 
 #[derive(Deserialize)]
-pub struct HistoryArgs;
+pub struct HistoryArgs {
+    clear: bool,
+}
 
 impl WholeStreamCommand for History {
     fn name(&self) -> &str {
@@ -17,7 +20,7 @@ impl WholeStreamCommand for History {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("history")
+        Signature::build("history").switch("clear", "clears the command history", Some('c'))
     }
 
     fn usage(&self) -> &str {
@@ -33,23 +36,89 @@ impl WholeStreamCommand for History {
     }
 }
 
+fn read_history(path: &Path) -> io::Result<Vec<String>> {
+    let reader = BufReader::new(File::open(path)?);
+
+    Ok(reader.lines().filter_map(Result::ok).collect())
+}
+
+fn clear_history(path: &Path) -> io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(path)?;
+
+    Ok(())
+}
+
 fn history(
-    _: HistoryArgs,
+    HistoryArgs { clear }: HistoryArgs,
     RunnableContext { name: tag, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    let history_path = HistoryFile::path();
+
+    if clear {
+        return match clear_history(&history_path) {
+            Ok(()) => {
+                HistoryFile::mark_cleared();
+                Ok(OutputStream::empty())
+            }
+            Err(_) => Err(ShellError::labeled_error(
+                "Could not clear history",
+                "history file could not be cleared",
+                tag,
+            )),
+        };
+    }
+
     let stream = async_stream! {
-        let history_path = HistoryFile::path();
-        let file = File::open(history_path);
-        if let Ok(file) = file {
-            let reader = BufReader::new(file);
-            for line in reader.lines() {
-                if let Ok(line) = line {
+        match read_history(&history_path) {
+            Ok(lines) => {
+                for line in lines {
                     yield ReturnSuccess::value(UntaggedValue::string(line).into_value(tag.clone()));
                 }
             }
-        } else {
-            yield Err(ShellError::labeled_error("Could not open history", "history file could not be opened", tag.clone()));
+            Err(_) => {
+                yield Err(ShellError::labeled_error("Could not open history", "history file could not be opened", tag.clone()));
+            }
         }
     };
     Ok(stream.to_output_stream())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{clear_history, read_history};
+    use std::io::Write;
+
+    #[test]
+    fn reads_back_history_entries() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("history.txt");
+
+        let mut file = std::fs::File::create(&path)?;
+        writeln!(file, "ls | get name")?;
+        writeln!(file, "echo hello")?;
+
+        assert_eq!(
+            read_history(&path)?,
+            vec!["ls | get name".to_string(), "echo hello".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_empties_history() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("history.txt");
+
+        std::fs::write(&path, "ls\necho hello\n")?;
+        clear_history(&path)?;
+
+        assert!(read_history(&path)?.is_empty());
+
+        Ok(())
+    }
+}
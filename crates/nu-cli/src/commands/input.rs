@@ -0,0 +1,145 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::io::{BufRead, Write};
+
+pub struct Input;
+
+#[derive(Deserialize)]
+pub struct InputArgs {
+    prompt: Option<Tagged<String>>,
+    numeric: bool,
+    password: bool,
+}
+
+impl WholeStreamCommand for Input {
+    fn name(&self) -> &str {
+        "input"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("input")
+            .optional("prompt", SyntaxShape::String, "the text to ask with")
+            .switch(
+                "numeric",
+                "only accept a whole number, given as an int",
+                Some('n'),
+            )
+            .switch(
+                "password",
+                "don't show what is typed, for passwords and secrets",
+                Some('p'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Read a line typed by the user, optionally asking with a prompt."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, input)?.run()
+    }
+}
+
+fn input(
+    InputArgs {
+        prompt,
+        numeric,
+        password,
+    }: InputArgs,
+    RunnableContext { name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if let Some(prompt) = &prompt {
+        print!("{}", prompt.item);
+        let _ = std::io::stdout().flush();
+    }
+
+    let line = if password {
+        rpassword::read_password().map_err(|e| read_error(e, &name))?
+    } else {
+        let stdin = std::io::stdin();
+        let mut handle = stdin.lock();
+        read_line(&mut handle, &name)?
+    };
+
+    let value = parse_input(line, numeric, &name)?;
+
+    Ok(futures::stream::iter(vec![ReturnSuccess::value(value)]).to_output_stream())
+}
+
+/// The next line from `reader`, without its line ending.
+fn read_line(reader: &mut impl BufRead, tag: &Tag) -> Result<String, ShellError> {
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|e| read_error(e, tag))?;
+
+    let trimmed = line.trim_end_matches(|c| c == '\n' || c == '\r').len();
+    line.truncate(trimmed);
+
+    Ok(line)
+}
+
+fn parse_input(line: String, numeric: bool, tag: &Tag) -> Result<Value, ShellError> {
+    if !numeric {
+        return Ok(UntaggedValue::string(line).into_value(tag));
+    }
+
+    match line.trim().parse::<i64>() {
+        Ok(number) => Ok(UntaggedValue::int(number).into_value(tag)),
+        Err(_) => Err(ShellError::labeled_error(
+            format!("Expected a whole number, got '{}'", line.trim()),
+            "requires numeric input",
+            tag,
+        )),
+    }
+}
+
+fn read_error(error: std::io::Error, tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        format!("Could not read input ({})", error),
+        "could not read input",
+        tag,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, read_line};
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+    use std::io::Cursor;
+
+    fn read(typed: &str, numeric: bool) -> Result<UntaggedValue, nu_errors::ShellError> {
+        let mut stdin = Cursor::new(typed.as_bytes());
+        let line = read_line(&mut stdin, &Tag::unknown())?;
+
+        Ok(parse_input(line, numeric, &Tag::unknown())?.value)
+    }
+
+    #[test]
+    fn reads_a_line_without_its_ending() {
+        assert_eq!(
+            read("Andrés\r\nnext line\n", false).unwrap(),
+            UntaggedValue::string("Andrés")
+        );
+    }
+
+    #[test]
+    fn reads_a_number_with_numeric() {
+        assert_eq!(read(" 42\n", true).unwrap(), UntaggedValue::int(42));
+    }
+
+    #[test]
+    fn errors_on_text_with_numeric() {
+        let error = read("forty-two\n", true).unwrap_err();
+
+        assert!(format!("{:?}", error).contains("Expected a whole number"));
+    }
+}
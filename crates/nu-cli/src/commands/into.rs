@@ -0,0 +1,336 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value::format_leaf;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_value_ext::{as_string, ValueExt};
+use std::str::FromStr;
+
+pub struct IntoType;
+
+#[derive(Deserialize)]
+pub struct IntoArgs {
+    rest: Vec<ColumnPath>,
+    int: bool,
+    string: bool,
+    float: bool,
+    #[serde(rename(deserialize = "bool"))]
+    boolean: bool,
+    filesize: bool,
+    duration: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Target {
+    Int,
+    String,
+    Float,
+    Bool,
+    Filesize,
+    Duration,
+}
+
+impl Target {
+    fn name(self) -> &'static str {
+        match self {
+            Target::Int => "integer",
+            Target::String => "string",
+            Target::Float => "decimal",
+            Target::Bool => "boolean",
+            Target::Filesize => "filesize",
+            Target::Duration => "duration",
+        }
+    }
+}
+
+impl WholeStreamCommand for IntoType {
+    fn name(&self) -> &str {
+        "into"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into")
+            .switch("int", "convert to an integer", Some('i'))
+            .switch("string", "convert to a string", Some('s'))
+            .switch("float", "convert to a decimal", Some('f'))
+            .switch("bool", "convert to a boolean", Some('b'))
+            .switch(
+                "filesize",
+                "convert to a file size, parsing strings like \"1.5 MB\"",
+                None,
+            )
+            .switch(
+                "duration",
+                "convert to a duration, parsing strings like \"2h30m\" or \"2:30:00\"",
+                None,
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally, the columns to convert (defaults to the whole value)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert values (or columns) into another type."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, into)?.run()
+    }
+}
+
+fn into(
+    IntoArgs {
+        rest: columns,
+        int,
+        string,
+        float,
+        boolean,
+        filesize,
+        duration,
+    }: IntoArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let targets = [
+        (int, Target::Int),
+        (string, Target::String),
+        (float, Target::Float),
+        (boolean, Target::Bool),
+        (filesize, Target::Filesize),
+        (duration, Target::Duration),
+    ]
+    .iter()
+    .filter(|(requested, _)| *requested)
+    .map(|(_, target)| *target)
+    .collect::<Vec<_>>();
+
+    let target = match targets.as_slice() {
+        [target] => *target,
+        [] => {
+            return Err(ShellError::labeled_error(
+                "Into requires a type to convert to",
+                "needs --int, --string, --float, --bool, --filesize or --duration",
+                name,
+            ))
+        }
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Into can only convert to one type at a time",
+                "too many types requested",
+                name,
+            ))
+        }
+    };
+
+    Ok(input
+        .map(move |value| {
+            if columns.is_empty() {
+                return ReturnSuccess::value(convert(&value, target)?);
+            }
+
+            let mut converted = value;
+
+            for path in &columns {
+                let cell = converted
+                    .get_data_by_column_path(path, Box::new(move |(_, _, error)| error))?;
+                let cell = convert(&cell, target)?;
+
+                converted = match converted.replace_data_at_column_path(path, cell) {
+                    Some(replaced) => replaced,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Into could not find the column to convert",
+                            "column name",
+                            &converted.tag,
+                        ))
+                    }
+                };
+            }
+
+            ReturnSuccess::value(converted)
+        })
+        .to_output_stream())
+}
+
+fn conversion_error(value: &Value, target: Target) -> ShellError {
+    ShellError::labeled_error(
+        format!("Can't convert {} to {}", value.type_name(), target.name()),
+        format!("can't convert to {}", target.name()),
+        &value.tag,
+    )
+}
+
+fn parse_error(value: &Value, target: Target, example: &str) -> ShellError {
+    ShellError::labeled_error(
+        format!("Can't parse string as a {}", target.name()),
+        format!("expected a {}, eg) {}", target.name(), example),
+        &value.tag,
+    )
+}
+
+/// The bytes in a filesize written with nu's units, like `2kb` or `1.5 MB`, where a kilobyte
+/// is 1024 bytes just as in filesize literals.
+fn parse_filesize(input: &str) -> Option<u64> {
+    let input = input.trim();
+    let (number, unit) = input.split_at(
+        input
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(input.len()),
+    );
+
+    // the same units, and sizes, as filesize literals
+    let unit: u64 = match unit {
+        "" | "b" | "B" => 1,
+        "kb" | "KB" | "Kb" => 1024,
+        "mb" | "MB" | "Mb" => 1024 * 1024,
+        "gb" | "GB" | "Gb" => 1024 * 1024 * 1024,
+        "tb" | "TB" | "Tb" => 1024 * 1024 * 1024 * 1024,
+        "pb" | "PB" | "Pb" => 1024 * 1024 * 1024 * 1024 * 1024,
+        _ => return None,
+    };
+
+    let number = BigDecimal::from_str(number.trim()).ok()?;
+
+    (number * BigDecimal::from(unit)).to_u64()
+}
+
+/// The seconds in a duration written with nu's units, like `2h30m` or `1d 12h`, or as the
+/// clock-style `m:ss`, `h:mm:ss` or `d:hh:mm:ss` that durations are displayed as.
+fn parse_duration(input: &str) -> Option<i64> {
+    let input = input.trim();
+
+    if input.contains(':') {
+        let parts: Vec<&str> = input.split(':').collect();
+        if parts.len() < 2 || parts.len() > 4 {
+            return None;
+        }
+
+        let mut seconds: i64 = 0;
+        for (part, unit) in parts.iter().rev().zip(&[1, 60, 60 * 60, 24 * 60 * 60]) {
+            let part: i64 = part.parse().ok()?;
+            seconds = seconds.checked_add(part.checked_mul(*unit)?)?;
+        }
+
+        return Some(seconds);
+    }
+
+    let mut seconds: i64 = 0;
+    let mut number = String::new();
+    let mut any = false;
+
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+
+        if c.is_whitespace() {
+            continue;
+        }
+
+        // the same units, and lengths, as duration literals
+        let unit: i64 = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            'M' => 30 * 24 * 60 * 60,
+            'y' => 365 * 24 * 60 * 60,
+            _ => return None,
+        };
+
+        let amount: i64 = number.parse().ok()?;
+        seconds = seconds.checked_add(amount.checked_mul(unit)?)?;
+        number.clear();
+        any = true;
+    }
+
+    if any && number.is_empty() {
+        Some(seconds)
+    } else {
+        None
+    }
+}
+
+fn convert(value: &Value, target: Target) -> Result<Value, ShellError> {
+    let primitive = match &value.value {
+        UntaggedValue::Primitive(primitive) => primitive,
+        _ => return Err(conversion_error(value, target)),
+    };
+
+    let converted = match target {
+        Target::String => match as_string(value) {
+            Ok(s) => UntaggedValue::string(s),
+            Err(_) => UntaggedValue::string(format_leaf(value).plain_string(100_000)),
+        },
+        Target::Int => match primitive {
+            Primitive::Int(i) => UntaggedValue::int(i.clone()),
+            Primitive::Bytes(b) => UntaggedValue::int(*b),
+            Primitive::Duration(secs) => UntaggedValue::int(*secs),
+            Primitive::Boolean(b) => UntaggedValue::int(if *b { 1 } else { 0 }),
+            Primitive::Decimal(d) => match d.to_i64() {
+                Some(i) => UntaggedValue::int(i),
+                None => return Err(conversion_error(value, target)),
+            },
+            Primitive::String(s) | Primitive::Line(s) => match s.trim().parse::<BigInt>() {
+                Ok(i) => UntaggedValue::int(i),
+                Err(_) => return Err(conversion_error(value, target)),
+            },
+            _ => return Err(conversion_error(value, target)),
+        },
+        Target::Float => match primitive {
+            Primitive::Decimal(d) => UntaggedValue::decimal(d.clone()),
+            Primitive::Int(i) => UntaggedValue::decimal(BigDecimal::from(i.clone())),
+            Primitive::Bytes(b) => UntaggedValue::decimal(BigDecimal::from(*b)),
+            Primitive::String(s) | Primitive::Line(s) => match BigDecimal::from_str(s.trim()) {
+                Ok(d) => UntaggedValue::decimal(d),
+                Err(_) => return Err(conversion_error(value, target)),
+            },
+            _ => return Err(conversion_error(value, target)),
+        },
+        Target::Bool => match primitive {
+            Primitive::Boolean(b) => UntaggedValue::boolean(*b),
+            Primitive::Int(i) => UntaggedValue::boolean(*i != BigInt::from(0)),
+            Primitive::String(s) | Primitive::Line(s) => match s.trim() {
+                "true" => UntaggedValue::boolean(true),
+                "false" => UntaggedValue::boolean(false),
+                _ => return Err(conversion_error(value, target)),
+            },
+            _ => return Err(conversion_error(value, target)),
+        },
+        Target::Filesize => match primitive {
+            Primitive::Bytes(b) => UntaggedValue::bytes(*b),
+            Primitive::Int(i) => match i.to_u64() {
+                Some(b) => UntaggedValue::bytes(b),
+                None => return Err(conversion_error(value, target)),
+            },
+            Primitive::String(s) | Primitive::Line(s) => match parse_filesize(s) {
+                Some(b) => UntaggedValue::bytes(b),
+                None => return Err(parse_error(value, target, "1.5 MB")),
+            },
+            _ => return Err(conversion_error(value, target)),
+        },
+        Target::Duration => match primitive {
+            Primitive::Duration(secs) => UntaggedValue::duration(*secs),
+            Primitive::Int(i) => match i.to_i64() {
+                Some(secs) => UntaggedValue::duration(secs),
+                None => return Err(conversion_error(value, target)),
+            },
+            Primitive::String(s) | Primitive::Line(s) => match parse_duration(s) {
+                Some(secs) => UntaggedValue::duration(secs),
+                None => return Err(parse_error(value, target, "2h30m")),
+            },
+            _ => return Err(conversion_error(value, target)),
+        },
+    };
+
+    Ok(converted.into_value(&value.tag))
+}
@@ -0,0 +1,83 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct IsAdmin;
+
+impl WholeStreamCommand for IsAdmin {
+    fn name(&self) -> &str {
+        "is-admin"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-admin")
+    }
+
+    fn usage(&self) -> &str {
+        "Check if nu is running with elevated privileges (root, or an administrator on Windows)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        is_admin(args, registry)
+    }
+}
+
+pub fn is_admin(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once(registry)?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::boolean(is_root()).into_value(args.name_tag()),
+    )))
+}
+
+#[cfg(unix)]
+fn is_root() -> bool {
+    users::get_effective_uid() == 0
+}
+
+#[cfg(windows)]
+fn is_root() -> bool {
+    use std::mem;
+    use std::ptr;
+    use winapi::shared::minwindef::DWORD;
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::processthreadsapi::{GetCurrentProcess, OpenProcessToken};
+    use winapi::um::securitybaseapi::GetTokenInformation;
+    use winapi::um::winnt::{TokenElevation, HANDLE, TOKEN_ELEVATION, TOKEN_QUERY};
+
+    let mut handle: HANDLE = ptr::null_mut();
+    let mut elevated = false;
+
+    unsafe {
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut handle) != 0 {
+            let mut elevation: TOKEN_ELEVATION = mem::zeroed();
+            let size = mem::size_of::<TOKEN_ELEVATION>() as DWORD;
+            let mut returned = size;
+
+            if GetTokenInformation(
+                handle,
+                TokenElevation,
+                &mut elevation as *mut TOKEN_ELEVATION as *mut _,
+                size,
+                &mut returned,
+            ) != 0
+            {
+                elevated = elevation.TokenIsElevated != 0;
+            }
+
+            CloseHandle(handle);
+        }
+    }
+
+    elevated
+}
+
+#[cfg(not(any(unix, windows)))]
+fn is_root() -> bool {
+    false
+}
@@ -15,6 +15,8 @@ enum IsEmptyFor {
 
 pub struct IsEmpty;
 
+pub struct IsEmptyStream;
+
 #[derive(Deserialize)]
 pub struct IsEmptyArgs {
     rest: Vec<Value>,
@@ -45,6 +47,49 @@ impl WholeStreamCommand for IsEmpty {
     }
 }
 
+impl WholeStreamCommand for IsEmptyStream {
+    fn name(&self) -> &str {
+        "is-empty"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-empty")
+    }
+
+    fn usage(&self) -> &str {
+        "Checks whether the stream has no rows, reading at most the first one."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        is_empty_stream(args, registry)
+    }
+}
+
+fn is_empty_stream(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once(registry)?;
+    let tag = args.call_info.name_tag.clone();
+    let mut input = args.input;
+
+    let stream = async_stream! {
+        let empty = stream_is_empty(&mut input).await;
+        yield ReturnSuccess::value(UntaggedValue::boolean(empty).into_value(&tag));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Pulls at most one row, so the rest of the stream (which may be endless) is never read.
+async fn stream_is_empty(input: &mut InputStream) -> bool {
+    input.next().await.is_none()
+}
+
 fn is_empty(
     IsEmptyArgs { rest }: IsEmptyArgs,
     RunnableContext { input, .. }: RunnableContext,
@@ -196,3 +241,40 @@ fn is_empty(
         })
         .to_output_stream())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::stream_is_empty;
+    use crate::prelude::*;
+    use nu_protocol::{UntaggedValue, Value};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn counted_stream(rows: usize, pulled: Arc<AtomicUsize>) -> InputStream {
+        InputStream::from_stream(futures::stream::iter(0..rows).map(move |row| {
+            pulled.fetch_add(1, Ordering::SeqCst);
+            UntaggedValue::int(row).into_untagged_value()
+        }))
+    }
+
+    #[test]
+    fn an_empty_stream_is_empty() {
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let mut input = counted_stream(0, pulled.clone());
+
+        assert!(futures::executor::block_on(stream_is_empty(&mut input)));
+        assert_eq!(pulled.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn a_stream_with_rows_is_not_empty_after_pulling_one() {
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let mut input = counted_stream(1_000, pulled.clone());
+
+        assert!(!futures::executor::block_on(stream_is_empty(&mut input)));
+        assert_eq!(pulled.load(Ordering::SeqCst), 1);
+
+        let rest: Vec<Value> = futures::executor::block_on(input.collect());
+        assert_eq!(rest.len(), 999);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::{IndexMap, IndexSet};
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Join;
+
+#[derive(Deserialize)]
+pub struct JoinArgs {
+    block: Block,
+    column: Tagged<String>,
+    left: bool,
+}
+
+impl WholeStreamCommand for Join {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("join")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block giving the rows to join with, like { open other.csv }",
+            )
+            .required(
+                "column",
+                SyntaxShape::String,
+                "the column whose values have to match in both tables",
+            )
+            .switch(
+                "left",
+                "keep the rows without a match, with nothing in the joined columns",
+                Some('l'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Join the rows with the rows of another table where they share the value of a column."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, join)?.run())
+    }
+}
+
+fn join(
+    JoinArgs {
+        block,
+        column,
+        left,
+    }: JoinArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let registry = context.registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let input = context.input;
+
+    let stream = async_stream! {
+        let mut block_context = Context::from_raw(&raw_args, &registry);
+        let right: Vec<Value> = match run_block(
+            &block,
+            &mut block_context,
+            InputStream::empty(),
+            &scope,
+        ).await {
+            Ok(stream) => stream.collect().await,
+            Err(e) => {
+                yield Err(e);
+                return;
+            }
+        };
+
+        if let Some(error) = block_context.get_errors().first() {
+            yield Err(error.clone());
+            return;
+        }
+
+        let right = match rows_of(right) {
+            Ok(rows) => rows,
+            Err(e) => {
+                yield Err(e);
+                return;
+            }
+        };
+
+        let right_columns: IndexSet<String> = right
+            .iter()
+            .flat_map(|(entries, _)| entries.keys().cloned())
+            .collect();
+
+        let left_rows: Vec<Value> = input.collect().await;
+        let left_rows = match rows_of(left_rows) {
+            Ok(rows) => rows,
+            Err(e) => {
+                yield Err(e);
+                return;
+            }
+        };
+
+        for (entries, tag) in left_rows {
+            let key = entries.get(&column.item);
+            let mut matched = false;
+
+            for (right_entries, _) in &right {
+                let same = match (key, right_entries.get(&column.item)) {
+                    (Some(a), Some(b)) => a.value == b.value,
+                    _ => false,
+                };
+
+                if same {
+                    matched = true;
+
+                    let mut joined = entries.clone();
+                    for (name, value) in right_entries {
+                        joined.insert(name.clone(), value.clone());
+                    }
+
+                    yield ReturnSuccess::value(UntaggedValue::row(joined).into_value(&tag));
+                }
+            }
+
+            if left && !matched {
+                let mut joined = entries.clone();
+                for name in &right_columns {
+                    if !joined.contains_key(name) {
+                        joined.insert(name.clone(), UntaggedValue::nothing().into_value(&tag));
+                    }
+                }
+
+                yield ReturnSuccess::value(UntaggedValue::row(joined).into_value(&tag));
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// A row's entries, along with the tag of the row they came from
+type Entries = (IndexMap<String, Value>, Tag);
+
+fn rows_of(values: Vec<Value>) -> Result<Vec<Entries>, ShellError> {
+    values
+        .into_iter()
+        .map(|value| match value.value {
+            UntaggedValue::Row(row) => Ok((row.entries, value.tag)),
+            _ => Err(ShellError::labeled_error(
+                "Expected rows to join",
+                "requires a table",
+                value.tag,
+            )),
+        })
+        .collect()
+}
@@ -0,0 +1,143 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Keypress;
+
+impl WholeStreamCommand for Keypress {
+    fn name(&self) -> &str {
+        "keypress"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("keypress")
+    }
+
+    fn usage(&self) -> &str {
+        "Wait for a single key to be pressed and give its name, like a, enter, up or ctrl-c."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        keypress(args)
+    }
+}
+
+fn keypress(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag;
+    let key = read_key().map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not read a key ({})", e),
+            "could not read a key",
+            &tag,
+        )
+    })?;
+
+    Ok(futures::stream::iter(vec![ReturnSuccess::value(
+        UntaggedValue::string(key_name(key)).into_value(&tag),
+    )])
+    .to_output_stream())
+}
+
+/// Raw mode for as long as it's alive, so the terminal is put back however reading ends.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> crossterm::Result<RawMode> {
+        crossterm::terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+fn read_key() -> crossterm::Result<KeyEvent> {
+    let _raw = RawMode::enable()?;
+
+    loop {
+        if let Event::Key(key) = crossterm::event::read()? {
+            return Ok(key);
+        }
+    }
+}
+
+fn key_name(key: KeyEvent) -> String {
+    let name = match key.code {
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("f{}", n),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Left => "left".to_string(),
+        KeyCode::Right => "right".to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::PageUp => "pageup".to_string(),
+        KeyCode::PageDown => "pagedown".to_string(),
+        KeyCode::Tab => "tab".to_string(),
+        KeyCode::BackTab => "backtab".to_string(),
+        KeyCode::Delete => "delete".to_string(),
+        KeyCode::Insert => "insert".to_string(),
+        KeyCode::Null => "null".to_string(),
+        KeyCode::Esc => "esc".to_string(),
+    };
+
+    // Shift is already part of the character typed, so it's only named for the other keys
+    let shifted = match key.code {
+        KeyCode::Char(_) => false,
+        _ => key.modifiers.contains(KeyModifiers::SHIFT),
+    };
+
+    let mut modifiers = vec![];
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        modifiers.push("ctrl");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        modifiers.push("alt");
+    }
+    if shifted {
+        modifiers.push("shift");
+    }
+
+    modifiers.push(&name);
+    modifiers.join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::key_name;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> String {
+        key_name(KeyEvent { code, modifiers })
+    }
+
+    #[test]
+    fn names_plain_keys() {
+        assert_eq!(press(KeyCode::Char('a'), KeyModifiers::NONE), "a");
+        assert_eq!(press(KeyCode::Char(' '), KeyModifiers::NONE), "space");
+        assert_eq!(press(KeyCode::Enter, KeyModifiers::NONE), "enter");
+        assert_eq!(press(KeyCode::F(5), KeyModifiers::NONE), "f5");
+    }
+
+    #[test]
+    fn names_modifiers_before_the_key() {
+        assert_eq!(press(KeyCode::Char('c'), KeyModifiers::CONTROL), "ctrl-c");
+        assert_eq!(press(KeyCode::Char('A'), KeyModifiers::SHIFT), "A");
+        assert_eq!(
+            press(KeyCode::Up, KeyModifiers::ALT | KeyModifiers::SHIFT),
+            "alt-shift-up"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use regex::Regex;
+
+pub struct Matches;
+
+#[derive(Deserialize)]
+pub struct MatchesArgs {
+    pattern: Tagged<String>,
+}
+
+impl WholeStreamCommand for Matches {
+    fn name(&self) -> &str {
+        "matches"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("matches").required(
+            "pattern",
+            SyntaxShape::String,
+            "the regex to test each string against",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Check whether each string matches a regex, giving true or false."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, matches)?.run()
+    }
+}
+
+fn matches(
+    MatchesArgs { pattern }: MatchesArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let regex = Regex::new(&pattern.item).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Invalid regex ({})", e),
+            "could not compile this regex",
+            &pattern.tag,
+        )
+    })?;
+
+    Ok(input
+        .map(move |value| ReturnSuccess::value(is_match(&regex, &value)?))
+        .to_output_stream())
+}
+
+fn is_match(regex: &Regex, value: &Value) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(text))
+        | UntaggedValue::Primitive(Primitive::Line(text)) => {
+            Ok(UntaggedValue::boolean(regex.is_match(text)).into_value(&value.tag))
+        }
+        _ => Err(ShellError::labeled_error(
+            format!("Can't match a regex against {}", value.type_name()),
+            "expected a string",
+            &value.tag,
+        )),
+    }
+}
@@ -0,0 +1,115 @@
+use crate::commands::stddev::column_name;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_value_ext::ValueExt;
+
+pub struct Mode;
+
+#[derive(Deserialize)]
+pub struct ModeArgs {
+    rest: Vec<ColumnPath>,
+}
+
+impl WholeStreamCommand for Mode {
+    fn name(&self) -> &str {
+        "mode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("mode").rest(
+            SyntaxShape::ColumnPath,
+            "optionally, the columns to find it for (defaults to the whole value)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gives the most frequent value (or values of columns), or a list of them when several tie."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, mode)?.run()
+    }
+}
+
+fn mode(
+    ModeArgs { rest: columns }: ModeArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.collect().await;
+
+        let result = if columns.is_empty() {
+            mode_of(values, &name)
+        } else {
+            columns_mode(&values, &columns, &name)
+        };
+
+        match result {
+            Ok(value) => yield ReturnSuccess::value(value),
+            Err(e) => yield Err(e),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// A row with the mode of each column under the column's name, eg) `{type: File}`
+fn columns_mode(values: &[Value], columns: &[ColumnPath], tag: &Tag) -> Result<Value, ShellError> {
+    let mut row = TaggedDictBuilder::new(tag);
+
+    for path in columns {
+        let cells = values
+            .iter()
+            .map(|value| value.get_data_by_column_path(path, Box::new(move |(_, _, error)| error)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        row.insert_value(column_name(path), mode_of(cells, tag)?);
+    }
+
+    Ok(row.into_value())
+}
+
+fn mode_of(values: Vec<Value>, tag: &Tag) -> Result<Value, ShellError> {
+    let mut modes = most_frequent(values);
+
+    if modes.is_empty() {
+        Err(ShellError::labeled_error(
+            "Expected values to find the mode of",
+            "requires at least one value",
+            tag,
+        ))
+    } else if modes.len() == 1 {
+        Ok(modes.remove(0))
+    } else {
+        Ok(UntaggedValue::table(&modes).into_value(tag))
+    }
+}
+
+/// The values seen the most times, in the order they first came in.
+fn most_frequent(values: Vec<Value>) -> Vec<Value> {
+    let mut counts: IndexMap<UntaggedValue, (Value, usize)> = IndexMap::new();
+
+    for value in values {
+        counts
+            .entry(value.value.clone())
+            .or_insert_with(|| (value, 0))
+            .1 += 1;
+    }
+
+    let highest = counts.values().map(|(_, count)| *count).max().unwrap_or(0);
+
+    counts
+        .into_iter()
+        .filter(|(_, (_, count))| *count == highest)
+        .map(|(_, (value, _))| value)
+        .collect()
+}
@@ -0,0 +1,165 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct MoveColumn;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    rest: Vec<Tagged<String>>,
+    before: Option<Tagged<String>>,
+    after: Option<Tagged<String>>,
+}
+
+enum Anchor {
+    Before(Tagged<String>),
+    After(Tagged<String>),
+}
+
+impl WholeStreamCommand for MoveColumn {
+    fn name(&self) -> &str {
+        "move"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("move")
+            .rest(SyntaxShape::String, "the names of the columns to move")
+            .named(
+                "before",
+                SyntaxShape::String,
+                "the column the moved columns will be placed before",
+                Some('b'),
+            )
+            .named(
+                "after",
+                SyntaxShape::String,
+                "the column the moved columns will be placed after",
+                Some('a'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Move columns before or after another column."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, move_)?.run()
+    }
+}
+
+fn move_(
+    Arguments {
+        rest: columns,
+        before,
+        after,
+    }: Arguments,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if columns.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Move requires columns to move",
+            "needs parameter",
+            name,
+        ));
+    }
+
+    let anchor = match (before, after) {
+        (Some(before), None) => Anchor::Before(before),
+        (None, Some(after)) => Anchor::After(after),
+        (Some(_), Some(after)) => {
+            return Err(ShellError::labeled_error(
+                "Move accepts either --before or --after, not both",
+                "conflicting anchor",
+                after.tag,
+            ))
+        }
+        (None, None) => {
+            return Err(ShellError::labeled_error(
+                "Move requires a column to move around",
+                "needs --before or --after",
+                name,
+            ))
+        }
+    };
+
+    Ok(input
+        .map(move |item| move_columns(item, &columns, &anchor))
+        .to_output_stream())
+}
+
+fn move_columns(item: Value, columns: &[Tagged<String>], anchor: &Anchor) -> ReturnValue {
+    let (anchor_column, place_after) = match anchor {
+        Anchor::Before(column) => (column, false),
+        Anchor::After(column) => (column, true),
+    };
+
+    let row = match &item.value {
+        UntaggedValue::Row(row) => row,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a row",
+                "requires table input",
+                &item.tag,
+            ))
+        }
+    };
+
+    for column in columns.iter().chain(std::iter::once(anchor_column)) {
+        if !row.contains_key(&column.item) {
+            return Err(ShellError::labeled_error_with_secondary(
+                format!("Unknown column \"{}\"", column.item),
+                "column not found",
+                &column.tag,
+                "value originates from here",
+                &item.tag,
+            ));
+        }
+    }
+
+    if columns.iter().any(|column| column.item == anchor_column.item) {
+        return Err(ShellError::labeled_error(
+            "Can't move a column relative to itself",
+            "anchor is also being moved",
+            &anchor_column.tag,
+        ));
+    }
+
+    let mut moved = IndexMap::new();
+
+    for (key, value) in row.entries.iter() {
+        if columns.iter().any(|column| &column.item == key) {
+            continue;
+        }
+
+        if key == &anchor_column.item && !place_after {
+            insert_moved(&mut moved, row.entries(), columns);
+        }
+
+        moved.insert(key.clone(), value.clone());
+
+        if key == &anchor_column.item && place_after {
+            insert_moved(&mut moved, row.entries(), columns);
+        }
+    }
+
+    ReturnSuccess::value(UntaggedValue::row(moved).into_value(&item.tag))
+}
+
+fn insert_moved(
+    moved: &mut IndexMap<String, Value>,
+    entries: &IndexMap<String, Value>,
+    columns: &[Tagged<String>],
+) {
+    for column in columns {
+        if let Some(value) = entries.get(&column.item) {
+            moved.insert(column.item.clone(), value.clone());
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Normalize;
+
+#[derive(Deserialize)]
+pub struct NormalizeArgs {
+    depth: Option<Tagged<u64>>,
+    explode: bool,
+}
+
+impl WholeStreamCommand for Normalize {
+    fn name(&self) -> &str {
+        "normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("normalize")
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "how many levels of nested rows to flatten (defaults to all of them)",
+                Some('d'),
+            )
+            .switch(
+                "explode",
+                "turn list columns into one row per item",
+                Some('e'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Flatten nested rows into dotted columns (and optionally lists into rows) for a flat table."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, normalize)?.run()
+    }
+}
+
+fn normalize(
+    NormalizeArgs { depth, explode }: NormalizeArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let depth = depth.map(|d| d.item);
+
+    Ok(input
+        .map(move |value| futures::stream::iter(normalize_value(value, depth, explode)))
+        .flatten()
+        .to_output_stream())
+}
+
+fn normalize_value(value: Value, depth: Option<u64>, explode: bool) -> Vec<ReturnValue> {
+    let tag = value.tag;
+
+    let entries = match value.value {
+        UntaggedValue::Row(row) => row.entries,
+        other => return vec![ReturnSuccess::value(other.into_value(tag))],
+    };
+
+    let mut flat = IndexMap::new();
+    flatten_row(None, &entries, depth, &mut flat);
+
+    let rows = if explode {
+        explode_lists(flat)
+    } else {
+        vec![flat]
+    };
+
+    rows.into_iter()
+        .map(|row| ReturnSuccess::value(UntaggedValue::row(row).into_value(&tag)))
+        .collect()
+}
+
+/// Moves the columns of nested rows up into `out` as `parent.child`, going at most `depth`
+/// levels down.
+fn flatten_row(
+    prefix: Option<&str>,
+    row: &IndexMap<String, Value>,
+    depth: Option<u64>,
+    out: &mut IndexMap<String, Value>,
+) {
+    for (key, value) in row {
+        let key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.clone(),
+        };
+
+        match &value.value {
+            UntaggedValue::Row(nested) if depth != Some(0) => {
+                flatten_row(Some(&key), &nested.entries, depth.map(|d| d - 1), out)
+            }
+            _ => {
+                out.insert(key, value.clone());
+            }
+        }
+    }
+}
+
+/// One row for each combination of the items in the list columns. An empty list gives nothing
+/// in that column rather than dropping the row.
+fn explode_lists(row: IndexMap<String, Value>) -> Vec<IndexMap<String, Value>> {
+    let mut rows = vec![row.clone()];
+
+    for (key, value) in &row {
+        let items = match &value.value {
+            UntaggedValue::Table(items) => items,
+            _ => continue,
+        };
+
+        let nothing = UntaggedValue::nothing().into_value(&value.tag);
+        let items: Vec<&Value> = if items.is_empty() {
+            vec![&nothing]
+        } else {
+            items.iter().collect()
+        };
+
+        rows = rows
+            .into_iter()
+            .flat_map(|current| {
+                items.iter().map(move |item| {
+                    let mut exploded = current.clone();
+                    exploded.insert(key.clone(), (*item).clone());
+                    exploded
+                })
+            })
+            .collect();
+    }
+
+    rows
+}
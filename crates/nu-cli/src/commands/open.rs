@@ -1,5 +1,6 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use chardetng::EncodingDetector;
 use nu_errors::ShellError;
 use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
 use nu_source::{AnchorLocation, Span, Tagged};
@@ -11,8 +12,12 @@ pub struct Open;
 pub struct OpenArgs {
     path: Tagged<PathBuf>,
     raw: Tagged<bool>,
+    #[serde(rename(deserialize = "show-encoding"))]
+    show_encoding: bool,
 }
 
+const UTF_8: &str = "UTF-8";
+
 impl WholeStreamCommand for Open {
     fn name(&self) -> &str {
         "open"
@@ -27,9 +32,14 @@ impl WholeStreamCommand for Open {
             )
             .switch(
                 "raw",
-                "load content as a string instead of a table",
+                "load content as a string instead of a table (or as binary, when it isn't UTF-8)",
                 Some('r'),
             )
+            .switch(
+                "show-encoding",
+                "give back the name of the encoding the file is read in, rather than its contents",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -46,13 +56,43 @@ impl WholeStreamCommand for Open {
 }
 
 fn open(
-    OpenArgs { path, raw }: OpenArgs,
+    OpenArgs {
+        path,
+        raw,
+        show_encoding,
+    }: OpenArgs,
     RunnableContext { shell_manager, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let cwd = PathBuf::from(shell_manager.path());
     let full_path = cwd;
 
     let stream = async_stream! {
+        if raw.item || show_encoding {
+            let (_, bytes, tag) = match read_file(&full_path, &path.item, path.tag.span) {
+                Ok(file) => file,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+            let decoded = decode_text(&bytes);
+
+            if show_encoding {
+                let encoding = decoded.map(|(_, encoding)| encoding).unwrap_or("binary");
+                yield ReturnSuccess::value(UntaggedValue::string(encoding).into_value(&tag));
+                return;
+            }
+
+            // Raw contents are the file as it is: text when it's UTF-8 (or UTF-16 with a byte
+            // order mark), and bytes when its encoding would have to be guessed
+            match decoded {
+                Some((text, encoding)) if encoding == UTF_8 || encoding.starts_with("UTF-16") => {
+                    yield ReturnSuccess::value(UntaggedValue::string(text).into_value(&tag))
+                }
+                _ => yield ReturnSuccess::value(UntaggedValue::binary(bytes).into_value(&tag)),
+            }
+            return;
+        }
 
         let result = fetch(&full_path, &path.item, path.tag.span).await;
 
@@ -62,13 +102,10 @@ fn open(
         }
         let (file_extension, contents, contents_tag) = result?;
 
-        let file_extension = if raw.item {
-            None
-        } else {
-            // If the extension could not be determined via mimetype, try to use the path
-            // extension. Some file types do not declare their mimetypes (such as bson files).
-            file_extension.or(path.extension().map(|x| x.to_string_lossy().to_string()))
-        };
+        // If the extension could not be determined via mimetype, try to use the path
+        // extension. Some file types do not declare their mimetypes (such as bson files).
+        let file_extension =
+            file_extension.or(path.extension().map(|x| x.to_string_lossy().to_string()));
 
         let tagged_contents = contents.into_value(&contents_tag);
 
@@ -87,132 +124,73 @@ pub async fn fetch(
     location: &PathBuf,
     span: Span,
 ) -> Result<(Option<String>, UntaggedValue, Tag), ShellError> {
-    let mut cwd = cwd.clone();
-
-    cwd.push(Path::new(location));
-    if let Ok(cwd) = dunce::canonicalize(cwd) {
-        match std::fs::read(&cwd) {
-            Ok(bytes) => match std::str::from_utf8(&bytes) {
-                Ok(s) => Ok((
-                    cwd.extension()
-                        .map(|name| name.to_string_lossy().to_string()),
-                    UntaggedValue::string(s),
-                    Tag {
-                        span,
-                        anchor: Some(AnchorLocation::File(cwd.to_string_lossy().to_string())),
-                    },
-                )),
-                Err(_) => {
-                    //Non utf8 data.
-                    match (bytes.get(0), bytes.get(1)) {
-                        (Some(x), Some(y)) if *x == 0xff && *y == 0xfe => {
-                            // Possibly UTF-16 little endian
-                            let utf16 = read_le_u16(&bytes[2..]);
-
-                            if let Some(utf16) = utf16 {
-                                match std::string::String::from_utf16(&utf16) {
-                                    Ok(s) => Ok((
-                                        cwd.extension()
-                                            .map(|name| name.to_string_lossy().to_string()),
-                                        UntaggedValue::string(s),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                    Err(_) => Ok((
-                                        None,
-                                        UntaggedValue::binary(bytes),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                }
-                            } else {
-                                Ok((
-                                    None,
-                                    UntaggedValue::binary(bytes),
-                                    Tag {
-                                        span,
-                                        anchor: Some(AnchorLocation::File(
-                                            cwd.to_string_lossy().to_string(),
-                                        )),
-                                    },
-                                ))
-                            }
-                        }
-                        (Some(x), Some(y)) if *x == 0xfe && *y == 0xff => {
-                            // Possibly UTF-16 big endian
-                            let utf16 = read_be_u16(&bytes[2..]);
-
-                            if let Some(utf16) = utf16 {
-                                match std::string::String::from_utf16(&utf16) {
-                                    Ok(s) => Ok((
-                                        cwd.extension()
-                                            .map(|name| name.to_string_lossy().to_string()),
-                                        UntaggedValue::string(s),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                    Err(_) => Ok((
-                                        None,
-                                        UntaggedValue::binary(bytes),
-                                        Tag {
-                                            span,
-                                            anchor: Some(AnchorLocation::File(
-                                                cwd.to_string_lossy().to_string(),
-                                            )),
-                                        },
-                                    )),
-                                }
-                            } else {
-                                Ok((
-                                    None,
-                                    UntaggedValue::binary(bytes),
-                                    Tag {
-                                        span,
-                                        anchor: Some(AnchorLocation::File(
-                                            cwd.to_string_lossy().to_string(),
-                                        )),
-                                    },
-                                ))
-                            }
-                        }
-                        _ => Ok((
-                            None,
-                            UntaggedValue::binary(bytes),
-                            Tag {
-                                span,
-                                anchor: Some(AnchorLocation::File(
-                                    cwd.to_string_lossy().to_string(),
-                                )),
-                            },
-                        )),
-                    }
-                }
-            },
-            Err(_) => Err(ShellError::labeled_error(
-                "File could not be opened",
-                "file not found",
-                span,
-            )),
+    let (path, bytes, tag) = read_file(cwd, location, span)?;
+
+    match decode_text(&bytes) {
+        Some((text, _)) => Ok((
+            path.extension()
+                .map(|name| name.to_string_lossy().to_string()),
+            UntaggedValue::string(text),
+            tag,
+        )),
+        None => Ok((None, UntaggedValue::binary(bytes), tag)),
+    }
+}
+
+fn read_file(
+    cwd: &Path,
+    location: &Path,
+    span: Span,
+) -> Result<(PathBuf, Vec<u8>, Tag), ShellError> {
+    let cwd = cwd.join(location);
+
+    let not_found =
+        || ShellError::labeled_error("File could not be opened", "file not found", span);
+    let path = dunce::canonicalize(cwd).map_err(|_| not_found())?;
+    let bytes = std::fs::read(&path).map_err(|_| not_found())?;
+    let tag = Tag {
+        span,
+        anchor: Some(AnchorLocation::File(path.to_string_lossy().to_string())),
+    };
+
+    Ok((path, bytes, tag))
+}
+
+/// The text of a file and the name of the encoding it was read in. Text that isn't UTF-8 (or
+/// UTF-16 with a byte order mark) has its encoding guessed. Bytes holding NULs, or that don't
+/// decode cleanly in the guessed encoding, are taken to be binary data, giving `None`.
+fn decode_text(bytes: &[u8]) -> Option<(String, &'static str)> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        // Windows tools often start UTF-8 files with a byte order mark, which would trip
+        // up the parsers of the formats the text is converted from
+        return Some((text.trim_start_matches('\u{feff}').to_string(), UTF_8));
+    }
+
+    match (bytes.get(0), bytes.get(1)) {
+        (Some(0xff), Some(0xfe)) => {
+            return read_le_u16(&bytes[2..])
+                .and_then(|utf16| String::from_utf16(&utf16).ok())
+                .map(|text| (text, "UTF-16LE"))
         }
-    } else {
-        Err(ShellError::labeled_error(
-            "File could not be opened",
-            "file not found",
-            span,
-        ))
+        (Some(0xfe), Some(0xff)) => {
+            return read_be_u16(&bytes[2..])
+                .and_then(|utf16| String::from_utf16(&utf16).ok())
+                .map(|text| (text, "UTF-16BE"))
+        }
+        _ => {}
+    }
+
+    if bytes.contains(&0) {
+        return None;
     }
+
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(None, true);
+
+    encoding
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(|text| (text.into_owned(), encoding.name()))
 }
 
 fn read_le_u16(input: &[u8]) -> Option<Vec<u16>> {
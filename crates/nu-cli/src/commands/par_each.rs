@@ -0,0 +1,182 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::each::kept_error;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+
+use futures::stream::once;
+use rayon::prelude::*;
+
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct ParEach;
+
+#[derive(Deserialize)]
+pub struct ParEachArgs {
+    block: Block,
+    #[serde(rename = "keep-errors")]
+    keep_errors: bool,
+    threads: Option<Tagged<u64>>,
+}
+
+impl WholeStreamCommand for ParEach {
+    fn name(&self) -> &str {
+        "par-each"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("par-each")
+            .required("block", SyntaxShape::Block, "the block to run on each row")
+            .switch(
+                "keep-errors",
+                "when the block fails on a row, give {error: ...} for it and keep going",
+                Some('k'),
+            )
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the most rows to run the block on at once (defaults to one per CPU)",
+                Some('t'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block on each row of the table, in parallel, keeping the order of the rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, par_each)?.run())
+    }
+}
+
+fn run_block_on_row(
+    block: &Block,
+    raw_args: &RawCommandArgs,
+    registry: &CommandRegistry,
+    input: Value,
+) -> Vec<ReturnValue> {
+    let mut context = Context::from_raw(raw_args, registry);
+    let scope = raw_args.call_info.scope.clone().set_it(input.clone());
+    let input_stream = once(async { Ok(input) }).to_input_stream();
+
+    futures::executor::block_on(async {
+        match run_block(block, &mut context, input_stream, &scope).await {
+            Ok(mut stream) => {
+                let mut output: Vec<ReturnValue> = vec![];
+
+                while let Some(result) = stream.next().await {
+                    output.push(ReturnSuccess::value(result));
+                }
+
+                // The block's commands only report some errors once their output is read
+                if let Some(error) = context.get_errors().first() {
+                    output.push(Err(error.clone()));
+                }
+
+                output
+            }
+            Err(e) => vec![Err(e)],
+        }
+    })
+}
+
+/// Cuts a row's output off at its first error, which becomes an `{error: ...}` row.
+fn keep_errors_in(output: Vec<ReturnValue>, tag: &Tag) -> Vec<ReturnValue> {
+    let mut kept = vec![];
+
+    for result in output {
+        match result {
+            Err(e)
+            | Ok(ReturnSuccess::Value(Value {
+                value: UntaggedValue::Error(e),
+                ..
+            })) => {
+                kept.push(ReturnSuccess::value(kept_error(e, tag)));
+                break;
+            }
+            result => kept.push(result),
+        }
+    }
+
+    kept
+}
+
+/// A pool of `threads` workers, for `--threads` to run the rows on instead of rayon's global
+/// pool of one per CPU.
+fn thread_pool(threads: &Tagged<u64>) -> Result<rayon::ThreadPool, ShellError> {
+    if threads.item == 0 {
+        return Err(ShellError::labeled_error(
+            "par-each needs at least one thread",
+            "use 1 or more threads",
+            &threads.tag,
+        ));
+    }
+
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.item as usize)
+        .build()
+        .map_err(|e| {
+            ShellError::labeled_error(
+                format!("Could not start the par-each threads ({})", e),
+                "could not start this many threads",
+                &threads.tag,
+            )
+        })
+}
+
+fn par_each(
+    ParEachArgs {
+        block,
+        keep_errors,
+        threads,
+    }: ParEachArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let registry = context.registry.clone();
+    let input = context.input;
+    let pool = match threads {
+        Some(threads) => Some(thread_pool(&threads)?),
+        None => None,
+    };
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.collect().await;
+
+        // rayon keeps the results in the order of the rows they came from
+        let run = || -> Vec<Vec<ReturnValue>> {
+            rows
+                .into_par_iter()
+                .map(|row| {
+                    let tag = row.tag.clone();
+                    let output = run_block_on_row(&block, &raw_args, &registry, row);
+
+                    if keep_errors {
+                        keep_errors_in(output, &tag)
+                    } else {
+                        output
+                    }
+                })
+                .collect()
+        };
+
+        let results = match &pool {
+            Some(pool) => pool.install(run),
+            None => run(),
+        };
+
+        for result in results.into_iter().flatten() {
+            yield result;
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
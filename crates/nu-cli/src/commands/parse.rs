@@ -5,6 +5,7 @@ use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
 use nu_source::Tagged;
 use regex::Regex;
+use std::str::FromStr;
 
 #[derive(Debug)]
 enum ParseCommand {
@@ -52,6 +53,75 @@ fn parse(input: &str) -> Vec<ParseCommand> {
     output
 }
 
+#[derive(Clone, Copy)]
+enum CaptureType {
+    String,
+    Int,
+    Float,
+    Bool,
+}
+
+impl CaptureType {
+    fn name(self) -> &'static str {
+        match self {
+            CaptureType::String => "string",
+            CaptureType::Int => "int",
+            CaptureType::Float => "float",
+            CaptureType::Bool => "bool",
+        }
+    }
+
+    fn convert(self, captured: &str) -> Option<UntaggedValue> {
+        match self {
+            CaptureType::String => Some(UntaggedValue::string(captured)),
+            CaptureType::Int => captured
+                .trim()
+                .parse::<BigInt>()
+                .ok()
+                .map(UntaggedValue::int),
+            CaptureType::Float => BigDecimal::from_str(captured.trim())
+                .ok()
+                .map(UntaggedValue::decimal),
+            CaptureType::Bool => match captured.trim() {
+                "true" => Some(UntaggedValue::boolean(true)),
+                "false" => Some(UntaggedValue::boolean(false)),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Splits a column like `count:int` (or `count__int` in a regex, where names can't hold a
+/// colon) into its name and the type its captures convert to. Untyped columns stay strings, as
+/// do regex names like `first__name` whose suffix isn't a type.
+fn typed_column(
+    column: &str,
+    separator: &str,
+    tag: &Tag,
+) -> Result<(String, CaptureType), ShellError> {
+    let (name, type_name) = match column.rfind(separator) {
+        Some(idx) => (&column[..idx], &column[idx + separator.len()..]),
+        None => return Ok((column.to_string(), CaptureType::String)),
+    };
+
+    let capture_type = match type_name {
+        "string" => CaptureType::String,
+        "int" => CaptureType::Int,
+        "float" | "decimal" => CaptureType::Float,
+        "bool" => CaptureType::Bool,
+        _ if separator == "__" => return Ok((column.to_string(), CaptureType::String)),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Unknown capture type \"{}\"", type_name),
+                "expected string, int, float or bool",
+                tag,
+            ))
+        }
+    };
+
+    Ok((name.to_string(), capture_type))
+}
+
 fn column_names(commands: &[ParseCommand]) -> Vec<String> {
     let mut output = vec![];
 
@@ -85,6 +155,8 @@ pub struct Parse;
 #[derive(Deserialize)]
 pub struct ParseArgs {
     pattern: Tagged<String>,
+    regex: bool,
+    strict: bool,
 }
 
 impl WholeStreamCommand for Parse {
@@ -93,11 +165,22 @@ impl WholeStreamCommand for Parse {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("parse").required(
-            "pattern",
-            SyntaxShape::String,
-            "the pattern to match. Eg) \"{foo}: {bar}\"",
-        )
+        Signature::build("parse")
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "the pattern to match, where columns can be typed. Eg) \"{foo}: {bar:int}\"",
+            )
+            .switch(
+                "regex",
+                "use the pattern as a regular expression with named captures, typed with a suffix. Eg) \"(?P<foo>\\w+) (?P<bar__int>\\d+)\"",
+                Some('r'),
+            )
+            .switch(
+                "strict",
+                "with --regex, error on lines that don't match the pattern instead of skipping them",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -113,13 +196,48 @@ impl WholeStreamCommand for Parse {
     }
 }
 
+fn regex_column_names(regex: &Regex) -> Vec<String> {
+    regex
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(idx, name)| match name {
+            Some(name) => name.to_string(),
+            None => format!("Capture{}", idx),
+        })
+        .collect()
+}
+
 fn parse_command(
-    ParseArgs { pattern }: ParseArgs,
+    ParseArgs {
+        pattern,
+        regex,
+        strict,
+    }: ParseArgs,
     RunnableContext { name, input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    let parse_pattern = parse(&pattern.item);
-    let parse_regex = build_regex(&parse_pattern);
-    let column_names = column_names(&parse_pattern);
+    if strict && !regex {
+        return Err(ShellError::labeled_error(
+            "Expected --regex with --strict",
+            "only a --regex pattern can be strict",
+            name,
+        ));
+    }
+
+    // A regex is matched line by line, and can be strict about lines it doesn't match. A
+    // simple pattern is matched against the whole text, as it always has been
+    let by_line = regex;
+
+    let (parse_regex, column_names, separator) = if regex {
+        (pattern.item.clone(), None, "__")
+    } else {
+        let parse_pattern = parse(&pattern.item);
+        (
+            build_regex(&parse_pattern),
+            Some(column_names(&parse_pattern)),
+            ":",
+        )
+    };
     let name = name.span;
     let regex = Regex::new(&parse_regex).map_err(|_| {
         ShellError::labeled_error(
@@ -128,20 +246,66 @@ fn parse_command(
             &pattern.tag,
         )
     })?;
+    let columns = column_names
+        .unwrap_or_else(|| regex_column_names(&regex))
+        .iter()
+        .map(|column| typed_column(column, separator, &pattern.tag))
+        .collect::<Result<Vec<_>, _>>()?;
 
     Ok(input
         .map(move |value| {
             if let Ok(s) = value.as_string() {
                 let mut output = vec![];
-                for cap in regex.captures_iter(&s) {
-                    let mut dict = TaggedDictBuilder::new(value.tag());
-                    for (idx, column_name) in column_names.iter().enumerate() {
-                        dict.insert_untagged(
-                            column_name,
-                            UntaggedValue::string(cap[idx + 1].to_string()),
-                        );
+                let texts: Vec<&str> = if by_line {
+                    s.lines().collect()
+                } else {
+                    vec![&s]
+                };
+
+                for text in texts {
+                    let mut matched = false;
+
+                    'captures: for cap in regex.captures_iter(text) {
+                        matched = true;
+
+                        let mut dict = TaggedDictBuilder::new(value.tag());
+                        for (idx, (column_name, capture_type)) in columns.iter().enumerate() {
+                            let captured = cap.get(idx + 1).map(|m| m.as_str()).unwrap_or("");
+
+                            match capture_type.convert(captured) {
+                                Some(converted) => dict.insert_untagged(column_name, converted),
+                                None => {
+                                    output.push(Err(ShellError::labeled_error_with_secondary(
+                                        format!(
+                                            "Could not convert the capture to {}",
+                                            capture_type.name()
+                                        ),
+                                        format!(
+                                            "could not convert \"{}\" in column {} to {}",
+                                            captured,
+                                            column_name,
+                                            capture_type.name()
+                                        ),
+                                        name,
+                                        "value originated here",
+                                        value.tag.clone(),
+                                    )));
+                                    continue 'captures;
+                                }
+                            }
+                        }
+                        output.push(Ok(ReturnSuccess::Value(dict.into_value())));
+                    }
+
+                    if strict && !matched {
+                        output.push(Err(ShellError::labeled_error_with_secondary(
+                            "Line does not match the pattern",
+                            format!("could not match \"{}\"", text),
+                            name,
+                            "value originated here",
+                            value.tag.clone(),
+                        )));
                     }
-                    output.push(Ok(ReturnSuccess::Value(dict.into_value())));
                 }
                 output
             } else {
@@ -0,0 +1,225 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+use std::path::{Component, Path, PathBuf};
+
+pub struct PathCommand;
+
+#[derive(Deserialize)]
+pub struct PathArgs {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "relative-to"))]
+    relative_to: Option<Tagged<PathBuf>>,
+}
+
+impl WholeStreamCommand for PathCommand {
+    fn name(&self) -> &str {
+        "path"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path")
+            .named(
+                "relative-to",
+                SyntaxShape::Path,
+                "the path to take the paths relative to, eg) ../docs from ./src",
+                Some('r'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally, the columns holding the paths (defaults to the whole value)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Work with paths (or columns of them)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, path)?.run()
+    }
+}
+
+fn path(
+    PathArgs {
+        rest: columns,
+        relative_to,
+    }: PathArgs,
+    RunnableContext {
+        input,
+        name,
+        shell_manager,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let base = match relative_to {
+        Some(base) => base,
+        None => {
+            return Err(ShellError::labeled_error(
+                "Path requires an operation",
+                "needs --relative-to",
+                name,
+            ))
+        }
+    };
+
+    let cwd = PathBuf::from(shell_manager.path());
+
+    Ok(input
+        .map(move |value| {
+            if columns.is_empty() {
+                return ReturnSuccess::value(relative_value(&value, &base, &cwd)?);
+            }
+
+            let mut converted = value;
+
+            for column in &columns {
+                let cell = converted
+                    .get_data_by_column_path(column, Box::new(move |(_, _, error)| error))?;
+                let cell = relative_value(&cell, &base, &cwd)?;
+
+                converted = match converted.replace_data_at_column_path(column, cell) {
+                    Some(replaced) => replaced,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Path could not find the column to change",
+                            "column name",
+                            &converted.tag,
+                        ))
+                    }
+                };
+            }
+
+            ReturnSuccess::value(converted)
+        })
+        .to_output_stream())
+}
+
+fn relative_value(value: &Value, base: &Tagged<PathBuf>, cwd: &Path) -> Result<Value, ShellError> {
+    let target = match &value.value {
+        UntaggedValue::Primitive(Primitive::Path(p)) => p.clone(),
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => PathBuf::from(s),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Expected a path, found {}", value.type_name()),
+                "expected a path",
+                &value.tag,
+            ))
+        }
+    };
+
+    match relative_path(&cwd.join(target), &cwd.join(&base.item)) {
+        Some(relative) => {
+            Ok(UntaggedValue::string(relative.to_string_lossy()).into_value(&value.tag))
+        }
+        None => Err(ShellError::labeled_error_with_secondary(
+            "Can't make a path relative to one on another root",
+            "path is on another root",
+            &value.tag,
+            "relative to this",
+            &base.tag,
+        )),
+    }
+}
+
+/// Resolves `.` and `..` without touching the filesystem, so paths that don't exist work too.
+fn normalize(path: &Path) -> Vec<Component> {
+    let mut components = vec![];
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => components.push(component),
+            },
+            _ => components.push(component),
+        }
+    }
+
+    components
+}
+
+/// The path that leads from the `base` directory to `target`, both absolute. There is none when
+/// they start from different roots, like two drives on Windows.
+fn relative_path(target: &Path, base: &Path) -> Option<PathBuf> {
+    let target = normalize(target);
+    let base = normalize(base);
+
+    let root = |components: &[Component]| match components.first() {
+        Some(Component::Prefix(prefix)) => Some(prefix.as_os_str().to_os_string()),
+        _ => None,
+    };
+
+    if root(&target) != root(&base) {
+        return None;
+    }
+
+    let common = target
+        .iter()
+        .zip(base.iter())
+        .take_while(|(target, base)| target == base)
+        .count();
+
+    let mut relative: PathBuf = std::iter::repeat(Component::ParentDir)
+        .take(base.len() - common)
+        .chain(target[common..].iter().cloned())
+        .collect();
+
+    if relative.as_os_str().is_empty() {
+        relative.push(Component::CurDir);
+    }
+
+    Some(relative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::relative_path;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn goes_down_into_a_subpath() {
+        assert_eq!(
+            relative_path(Path::new("/home/nu/projects/shell"), Path::new("/home/nu")),
+            Some(PathBuf::from("projects/shell"))
+        );
+    }
+
+    #[test]
+    fn goes_up_and_across_to_a_sibling() {
+        assert_eq!(
+            relative_path(Path::new("/home/nu/docs"), Path::new("/home/nu/projects/")),
+            Some(PathBuf::from("../docs"))
+        );
+    }
+
+    #[test]
+    fn resolves_dots_before_comparing() {
+        assert_eq!(
+            relative_path(
+                Path::new("/home/nu/./docs"),
+                Path::new("/home/nu/projects/..")
+            ),
+            Some(PathBuf::from("docs"))
+        );
+        assert_eq!(
+            relative_path(Path::new("/home/nu"), Path::new("/home/nu")),
+            Some(PathBuf::from("."))
+        );
+    }
+}
@@ -7,11 +7,13 @@ use nu_protocol::{
     TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::span_for_spanned_list;
-use nu_value_ext::{as_string, get_data_by_column_path};
+use nu_value_ext::{as_string, column_path_ignoring_case, get_data_by_column_path};
 
 #[derive(Deserialize)]
 struct PickArgs {
     rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-case"))]
+    ignore_case: bool,
 }
 
 pub struct Pick;
@@ -22,10 +24,16 @@ impl WholeStreamCommand for Pick {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("pick").rest(
-            SyntaxShape::ColumnPath,
-            "the columns to select from the table",
-        )
+        Signature::build("pick")
+            .switch(
+                "ignore-case",
+                "match column names regardless of case",
+                Some('i'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "the columns to select from the table",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -42,7 +50,10 @@ impl WholeStreamCommand for Pick {
 }
 
 fn pick(
-    PickArgs { rest: mut fields }: PickArgs,
+    PickArgs {
+        rest: mut fields,
+        ignore_case,
+    }: PickArgs,
     RunnableContext {
         mut input, name, ..
     }: RunnableContext,
@@ -70,6 +81,12 @@ fn pick(
 
         while let Some(value) = input.next().await {
             for path in &column_paths {
+                let path = if ignore_case {
+                    column_path_ignoring_case(&value, path)
+                } else {
+                    path.clone()
+                };
+
                 let path_members_span = span_for_spanned_list(path.members().iter().map(|p| p.span));
 
                 let fetcher = get_data_by_column_path(&value, &path, Box::new(move |(obj_source, path_member_tried, error)| {
@@ -0,0 +1,107 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::sleep::{duration_seconds, sleep_for};
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+pub struct Poll;
+
+#[derive(Deserialize)]
+pub struct PollArgs {
+    interval: Value,
+    block: Block,
+    count: Option<Tagged<u64>>,
+}
+
+impl WholeStreamCommand for Poll {
+    fn name(&self) -> &str {
+        "poll"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("poll")
+            .required(
+                "interval",
+                SyntaxShape::Unit,
+                "the time to wait between runs, eg) 10s",
+            )
+            .required("block", SyntaxShape::Block, "the block to run")
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "the number of times to run the block (defaults to running until ctrl-c)",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block again and again on an interval, streaming out what it gives each time."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, poll)?.run())
+    }
+}
+
+fn poll(
+    PollArgs {
+        interval,
+        block,
+        count,
+    }: PollArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let seconds = duration_seconds(&interval)?;
+    let count = count.map(|count| count.item);
+    let registry = context.registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let ctrl_c = context.ctrl_c;
+
+    let stream = async_stream! {
+        let mut runs = 0;
+
+        loop {
+            if count.map_or(false, |count| runs >= count) || ctrl_c.load(Ordering::SeqCst) {
+                break;
+            }
+
+            if runs > 0 && !sleep_for(Duration::from_secs(seconds), &ctrl_c).await {
+                break;
+            }
+
+            runs += 1;
+
+            let mut context = Context::from_raw(&raw_args, &registry);
+            let result = run_block(&block, &mut context, InputStream::empty(), &scope).await;
+
+            match result {
+                Ok(mut stream) => {
+                    while let Some(result) = stream.next().await {
+                        yield ReturnSuccess::value(result);
+                    }
+
+                    if let Some(error) = context.get_errors().first() {
+                        yield Err(error.clone());
+                        return;
+                    }
+                }
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
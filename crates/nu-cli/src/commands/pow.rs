@@ -0,0 +1,190 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use num_bigint::BigInt;
+use num_traits::cast::FromPrimitive;
+use num_traits::Zero;
+
+pub struct Sqrt;
+
+#[derive(Deserialize)]
+pub struct SqrtArgs {
+    lenient: bool,
+}
+
+impl WholeStreamCommand for Sqrt {
+    fn name(&self) -> &str {
+        "sqrt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sqrt").switch(
+            "lenient",
+            "gives nothing for negative numbers instead of an error",
+            Some('l'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Take the square root of numbers, as decimals."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sqrt)?.run()
+    }
+}
+
+fn sqrt(
+    SqrtArgs { lenient }: SqrtArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(input
+        .map(move |value| {
+            let number = as_f64(&value)?;
+
+            if number < 0.0 {
+                if lenient {
+                    return ReturnSuccess::value(UntaggedValue::nothing().into_value(&value.tag));
+                }
+
+                return Err(ShellError::labeled_error(
+                    "Can't take the square root of a negative number",
+                    "negative number",
+                    &value.tag,
+                ));
+            }
+
+            ReturnSuccess::value(from_f64(number.sqrt(), &value)?)
+        })
+        .to_output_stream())
+}
+
+pub struct Pow;
+
+#[derive(Deserialize)]
+pub struct PowArgs {
+    exponent: Value,
+}
+
+impl WholeStreamCommand for Pow {
+    fn name(&self) -> &str {
+        "pow"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pow").required(
+            "exponent",
+            SyntaxShape::Number,
+            "the power to raise each number to",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Raise numbers to a power."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, pow)?.run()
+    }
+}
+
+/// The most bits an exact whole power may take, so a huge exponent errors instead of running
+/// on for ages.
+const MAX_POWER_BITS: usize = 1 << 20;
+
+fn pow(
+    PowArgs { exponent }: PowArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let float_exponent = as_f64(&exponent)?;
+    let exponent_tag = exponent.tag.clone();
+
+    // Whole powers of integers stay exact, anything else goes through floats
+    let int_exponent = match &exponent.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_usize(),
+        _ => None,
+    };
+
+    Ok(input
+        .map(move |value| match (&value.value, int_exponent) {
+            (UntaggedValue::Primitive(Primitive::Int(base)), Some(exponent)) => {
+                if let Some(power) = known_power(base, exponent) {
+                    return ReturnSuccess::value(UntaggedValue::int(power).into_value(&value.tag));
+                }
+
+                if base.bits().saturating_mul(exponent) > MAX_POWER_BITS {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Result is too large to compute",
+                        "exponent too large",
+                        &exponent_tag,
+                        "for this number",
+                        &value.tag,
+                    ));
+                }
+
+                ReturnSuccess::value(
+                    UntaggedValue::int(num_traits::pow(base.clone(), exponent))
+                        .into_value(&value.tag),
+                )
+            }
+            _ => ReturnSuccess::value(from_f64(as_f64(&value)?.powf(float_exponent), &value)?),
+        })
+        .to_output_stream())
+}
+
+/// Powers of 0, 1 and -1 are known up front, however large the exponent
+fn known_power(base: &BigInt, exponent: usize) -> Option<BigInt> {
+    let one = BigInt::from(1);
+
+    if base.is_zero() || *base == one {
+        Some(if exponent == 0 { one } else { base.clone() })
+    } else if *base == -one.clone() {
+        Some(if exponent % 2 == 0 { one } else { base.clone() })
+    } else {
+        None
+    }
+}
+
+fn as_f64(value: &Value) -> Result<f64, ShellError> {
+    let number = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                format!("Expected a number, found {}", value.type_name()),
+                "expected a number",
+                &value.tag,
+            ))
+        }
+    };
+
+    number.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Number is too large to compute with",
+            "number too large",
+            &value.tag,
+        )
+    })
+}
+
+fn from_f64(number: f64, value: &Value) -> Result<Value, ShellError> {
+    match BigDecimal::from_f64(number) {
+        Some(d) => Ok(UntaggedValue::decimal(d).into_value(&value.tag)),
+        None => Err(ShellError::labeled_error(
+            "Result is not a finite number",
+            "result is not finite",
+            &value.tag,
+        )),
+    }
+}
@@ -7,6 +7,7 @@ use nu_protocol::{Signature, SyntaxShape, Value};
 #[derive(Deserialize)]
 struct PrependArgs {
     row: Value,
+    rest: Vec<Value>,
 }
 
 pub struct Prepend;
@@ -17,15 +18,20 @@ impl WholeStreamCommand for Prepend {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("prepend").required(
-            "row value",
-            SyntaxShape::Any,
-            "the value of the row to prepend to the table",
-        )
+        Signature::build("prepend")
+            .required(
+                "row value",
+                SyntaxShape::Any,
+                "the value of the row to prepend to the table",
+            )
+            .rest(
+                SyntaxShape::Any,
+                "more rows to prepend, kept in the order given",
+            )
     }
 
     fn usage(&self) -> &str {
-        "Prepend the given row to the front of the table"
+        "Prepend the given rows to the front of the table"
     }
 
     fn run(
@@ -38,10 +44,12 @@ impl WholeStreamCommand for Prepend {
 }
 
 fn prepend(
-    PrependArgs { row }: PrependArgs,
+    PrependArgs { row, rest }: PrependArgs,
     RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    let prepend = futures::stream::iter(vec![row]);
+    let mut before = vec![row];
+    before.extend(rest);
+    let prepend = futures::stream::iter(before);
 
     Ok(prepend.chain(input).to_output_stream())
 }
@@ -0,0 +1,87 @@
+use crate::commands::to_text::render_value;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use std::io::Write;
+
+pub struct Print;
+
+#[derive(Deserialize)]
+pub struct PrintArgs {
+    rest: Vec<Value>,
+    stderr: bool,
+}
+
+impl WholeStreamCommand for Print {
+    fn name(&self) -> &str {
+        "print"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("print")
+            .rest(
+                SyntaxShape::Any,
+                "the values to print (defaults to the input)",
+            )
+            .switch("stderr", "print to stderr instead of stdout", Some('e'))
+    }
+
+    fn usage(&self) -> &str {
+        "Print values as text right away, giving back nothing. Useful inside blocks, where the output isn't shown."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, print)?.run()
+    }
+}
+
+fn print(
+    PrintArgs { rest, stderr }: PrintArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = if rest.is_empty() {
+            input.collect().await
+        } else {
+            rest
+        };
+
+        for value in &values {
+            let text = match render_value(value, false) {
+                Ok(text) => text,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+
+            if let Err(e) = write_line(&text, stderr) {
+                yield Err(ShellError::labeled_error(
+                    format!("Could not print ({})", e),
+                    "could not write this value",
+                    &value.tag,
+                ));
+                return;
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn write_line(text: &str, stderr: bool) -> std::io::Result<()> {
+    if stderr {
+        let mut err = std::io::stderr();
+        writeln!(err, "{}", text)?;
+        err.flush()
+    } else {
+        let mut out = std::io::stdout();
+        writeln!(out, "{}", text)?;
+        out.flush()
+    }
+}
@@ -3,7 +3,7 @@ use crate::context::CommandRegistry;
 use crate::deserializer::NumericRange;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
+use nu_protocol::{RangeInclusion, Signature, SyntaxShape};
 use nu_source::Tagged;
 
 #[derive(Deserialize)]
@@ -22,7 +22,7 @@ impl WholeStreamCommand for Range {
         Signature::build("range").required(
             "rows ",
             SyntaxShape::Range,
-            "range of rows to return: Eg) 4..7 (=> from 4 to 7)",
+            "range of rows to return: Eg) 4..7 (=> from 4 to 7), 4..<7 (=> from 4 to 6)",
         )
     }
 
@@ -45,10 +45,16 @@ fn range(
 ) -> Result<OutputStream, ShellError> {
     let range = area.item;
     let (from, _) = range.from;
-    let (to, _) = range.to;
+    let (to, to_inclusion) = range.to;
 
     let from = *from as usize;
-    let to = *to as usize;
-
-    Ok(input.skip(from).take(to - from + 1).to_output_stream())
+    let to = match to_inclusion {
+        RangeInclusion::Inclusive => *to as usize + 1,
+        RangeInclusion::Exclusive => *to as usize,
+    };
+
+    Ok(input
+        .skip(from)
+        .take(to.saturating_sub(from))
+        .to_output_stream())
 }
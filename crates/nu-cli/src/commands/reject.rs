@@ -2,12 +2,15 @@ use crate::commands::WholeStreamCommand;
 use crate::data::base::reject_fields;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_protocol::{ColumnPath, PathMember, Signature, SyntaxShape, UnspannedPathMember, Value};
+use nu_source::{Span, Tagged};
+use nu_value_ext::column_path_ignoring_case;
 
 #[derive(Deserialize)]
 pub struct RejectArgs {
     rest: Vec<Tagged<String>>,
+    #[serde(rename(deserialize = "ignore-case"))]
+    ignore_case: bool,
 }
 
 pub struct Reject;
@@ -18,7 +21,16 @@ impl WholeStreamCommand for Reject {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("reject").rest(SyntaxShape::String, "the names of columns to remove")
+        Signature::build("reject")
+            .switch(
+                "ignore-case",
+                "match column names regardless of case",
+                Some('i'),
+            )
+            .rest(
+                SyntaxShape::String,
+                "the names of columns to remove, with dots for nested columns (like address.city)",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -35,7 +47,10 @@ impl WholeStreamCommand for Reject {
 }
 
 fn reject(
-    RejectArgs { rest: fields }: RejectArgs,
+    RejectArgs {
+        rest: fields,
+        ignore_case,
+    }: RejectArgs,
     RunnableContext { input, name, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     if fields.is_empty() {
@@ -48,7 +63,37 @@ fn reject(
 
     let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
 
-    let stream = input.map(move |item| reject_fields(&item, &fields, &item.tag));
+    let stream = input.map(move |item| {
+        let fields: Vec<_> = if ignore_case {
+            fields
+                .iter()
+                .map(|field| field_ignoring_case(&item, field))
+                .collect()
+        } else {
+            fields.clone()
+        };
+
+        reject_fields(&item, &fields, &item.tag)
+    });
 
     Ok(stream.from_input_stream())
 }
+
+/// The dotted field with each name spelled the way `value` spells it, matching pick and get.
+fn field_ignoring_case(value: &Value, field: &str) -> String {
+    let path = ColumnPath::new(
+        field
+            .split('.')
+            .map(|name| PathMember::string(name, Span::unknown()))
+            .collect(),
+    );
+
+    column_path_ignoring_case(value, &path)
+        .iter()
+        .map(|member| match &member.unspanned {
+            UnspannedPathMember::String(name) => name.clone(),
+            UnspannedPathMember::Int(index) => index.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
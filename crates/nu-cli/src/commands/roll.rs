@@ -0,0 +1,101 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Roll;
+
+#[derive(Deserialize)]
+pub struct RollArgs {
+    by: Option<Tagged<u64>>,
+    columns: bool,
+    reverse: bool,
+}
+
+impl WholeStreamCommand for Roll {
+    fn name(&self) -> &str {
+        "roll"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("roll")
+            .optional("by", SyntaxShape::Int, "the number of times to roll")
+            .switch(
+                "columns",
+                "roll the columns of each row instead of the rows",
+                Some('c'),
+            )
+            .switch(
+                "reverse",
+                "roll rows up (or columns left) instead of down (or right)",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rotate the rows (or columns) of the table."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, roll)?.run()
+    }
+}
+
+fn rotate<T>(items: &mut [T], by: usize, reverse: bool) {
+    if items.is_empty() {
+        return;
+    }
+
+    let by = by % items.len();
+
+    if reverse {
+        items.rotate_left(by);
+    } else {
+        items.rotate_right(by);
+    }
+}
+
+fn roll_columns(item: Value, by: usize, reverse: bool) -> Value {
+    match item.value {
+        UntaggedValue::Row(dict) => {
+            let mut entries = dict.entries.into_iter().collect::<Vec<_>>();
+            rotate(&mut entries, by, reverse);
+
+            UntaggedValue::row(entries.into_iter().collect()).into_value(item.tag)
+        }
+        _ => item,
+    }
+}
+
+fn roll(
+    RollArgs {
+        by,
+        columns,
+        reverse,
+    }: RollArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let by = by.map(|by| by.item as usize).unwrap_or(1);
+
+    if columns {
+        return Ok(input
+            .map(move |item| ReturnSuccess::value(roll_columns(item, by, reverse)))
+            .to_output_stream());
+    }
+
+    let stream = async_stream! {
+        let mut rows: Vec<Value> = input.into_vec().await;
+        rotate(&mut rows, by, reverse);
+
+        for row in rows {
+            yield ReturnSuccess::value(row);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,92 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    merge_descriptors, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_value_ext::get_data_by_key;
+
+pub struct Rotate;
+
+#[derive(Deserialize)]
+pub struct RotateArgs {
+    ccw: bool,
+}
+
+impl WholeStreamCommand for Rotate {
+    fn name(&self) -> &str {
+        "rotate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("rotate").switch(
+            "ccw",
+            "rotate counterclockwise rather than clockwise",
+            Some('c'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Rotates the table a quarter turn, so its columns (names included) become rows."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, rotate)?.run()
+    }
+}
+
+pub fn rotate(
+    RotateArgs { ccw }: RotateArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let input: Vec<Value> = input.collect().await;
+
+        for row in rotate_rows(&input, ccw, &name) {
+            yield ReturnSuccess::value(row);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Turning clockwise, the last row ends up on the left and the column names on the right, so
+/// each column becomes a row reading bottom to top followed by its name. Counterclockwise is
+/// the mirror of that: the last column comes first, and each reads its name and then its
+/// cells top to bottom.
+fn rotate_rows(input: &[Value], ccw: bool, tag: &Tag) -> Vec<Value> {
+    let mut columns = merge_descriptors(input);
+
+    if ccw {
+        columns.reverse();
+    }
+
+    columns
+        .iter()
+        .map(|column| {
+            let header = UntaggedValue::string(column).into_value(tag);
+            let cells = input.iter().map(|row| {
+                get_data_by_key(row, column[..].spanned_unknown())
+                    .unwrap_or_else(|| UntaggedValue::nothing().into_value(tag))
+            });
+
+            let line: Vec<Value> = if ccw {
+                std::iter::once(header).chain(cells).collect()
+            } else {
+                let mut line: Vec<Value> = cells.rev().collect();
+                line.push(header);
+                line
+            };
+
+            let mut rotated = TaggedDictBuilder::new(tag);
+            for (idx, value) in line.into_iter().enumerate() {
+                rotated.insert_value(format!("Column{}", idx + 1), value);
+            }
+            rotated.into_value()
+        })
+        .collect()
+}
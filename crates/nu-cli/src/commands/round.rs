@@ -0,0 +1,153 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+use num_traits::{One, Zero};
+
+pub struct Round;
+
+#[derive(Deserialize)]
+pub struct RoundArgs {
+    rest: Vec<ColumnPath>,
+    floor: bool,
+    ceil: bool,
+    precision: Option<Tagged<u64>>,
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Nearest,
+    Floor,
+    Ceil,
+}
+
+impl WholeStreamCommand for Round {
+    fn name(&self) -> &str {
+        "round"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("round")
+            .switch("floor", "round down, towards negative infinity", Some('f'))
+            .switch("ceil", "round up, towards positive infinity", Some('c'))
+            .named(
+                "precision",
+                SyntaxShape::Int,
+                "the number of decimal places to keep (defaults to 0)",
+                Some('p'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally, the columns to round (defaults to the whole value)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Round decimal values (or columns) to a number of decimal places."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, round)?.run()
+    }
+}
+
+fn round(
+    RoundArgs {
+        rest: columns,
+        floor,
+        ceil,
+        precision,
+    }: RoundArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let mode = match (floor, ceil) {
+        (false, false) => Mode::Nearest,
+        (true, false) => Mode::Floor,
+        (false, true) => Mode::Ceil,
+        (true, true) => {
+            return Err(ShellError::labeled_error(
+                "Round can either floor or ceil, not both",
+                "use either --floor or --ceil",
+                name,
+            ))
+        }
+    };
+
+    let precision = precision.map(|p| p.item as i64).unwrap_or(0);
+
+    Ok(input
+        .map(move |value| {
+            if columns.is_empty() {
+                return ReturnSuccess::value(round_value(&value, precision, mode)?);
+            }
+
+            let mut rounded = value;
+
+            for path in &columns {
+                let cell =
+                    rounded.get_data_by_column_path(path, Box::new(move |(_, _, error)| error))?;
+                let cell = round_value(&cell, precision, mode)?;
+
+                rounded = match rounded.replace_data_at_column_path(path, cell) {
+                    Some(replaced) => replaced,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Round could not find the column to round",
+                            "column name",
+                            &rounded.tag,
+                        ))
+                    }
+                };
+            }
+
+            ReturnSuccess::value(rounded)
+        })
+        .to_output_stream())
+}
+
+fn round_value(value: &Value, precision: i64, mode: Mode) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(_)) => Ok(value.clone()),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            Ok(UntaggedValue::decimal(round_decimal(d, precision, mode)).into_value(&value.tag))
+        }
+        _ => Err(ShellError::labeled_error(
+            format!("Can't round {}", value.type_name()),
+            "expected a number",
+            &value.tag,
+        )),
+    }
+}
+
+/// Rounds to `precision` decimal places by shifting the digits to keep in front of the decimal
+/// point, rounding to a whole number there, and shifting them back.
+fn round_decimal(d: &BigDecimal, precision: i64, mode: Mode) -> BigDecimal {
+    let shifted = d.clone() * BigDecimal::new(BigInt::one(), -precision);
+    let truncated = shifted.with_scale(0);
+
+    let whole = match mode {
+        Mode::Floor if shifted < truncated => truncated - BigDecimal::one(),
+        Mode::Ceil if shifted > truncated => truncated + BigDecimal::one(),
+        Mode::Nearest => {
+            let half = BigDecimal::new(BigInt::from(5), 1);
+
+            if shifted < BigDecimal::zero() {
+                (shifted - half).with_scale(0)
+            } else {
+                (shifted + half).with_scale(0)
+            }
+        }
+        _ => truncated,
+    };
+
+    (whole * BigDecimal::new(BigInt::one(), precision)).with_scale(precision)
+}
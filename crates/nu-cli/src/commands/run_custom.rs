@@ -0,0 +1,96 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+
+use derive_new::new;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, NamedType, ReturnSuccess, Signature, UntaggedValue};
+
+/// A command defined with `def`, which runs its block with the arguments
+/// bound to variables named after its parameters
+#[derive(new, Clone)]
+pub struct CustomCommand {
+    signature: Signature,
+    block: Block,
+}
+
+impl WholeStreamCommand for CustomCommand {
+    fn name(&self) -> &str {
+        &self.signature.name
+    }
+
+    fn signature(&self) -> Signature {
+        self.signature.clone()
+    }
+
+    fn usage(&self) -> &str {
+        &self.signature.usage
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let tag = args.call_info.name_tag.clone();
+        let call_info = args.call_info.clone();
+        let registry = registry.clone();
+        let block = self.block.clone();
+        let signature = self.signature.clone();
+        let mut context = Context::from_args(&args, &registry);
+        let input = args.input;
+
+        let stream = async_stream! {
+            let mut scope = call_info.scope.clone();
+            let evaluated = call_info.evaluate(&registry)?;
+
+            if let Some(positional) = &evaluated.args.positional {
+                for ((param, _), arg) in signature.positional.iter().zip(positional.iter()) {
+                    scope = scope.set_var(format!("${}", param.name()), arg.clone());
+                }
+            }
+
+            for (flag, (kind, _)) in signature.named.iter() {
+                let value = match (kind, evaluated.args.get(flag)) {
+                    (_, Some(value)) => value.clone(),
+                    (NamedType::Switch(_), None) => UntaggedValue::boolean(false).into_value(&tag),
+                    (_, None) => UntaggedValue::nothing().into_value(&tag),
+                };
+
+                scope = scope.set_var(format!("${}", flag), value);
+            }
+
+            let result = run_block(&block, &mut context, input, &scope).await;
+
+            match result {
+                Ok(mut stream) => {
+                    while let Some(result) = stream.next().await {
+                        yield Ok(ReturnSuccess::Value(result));
+                    }
+
+                    let errors = context.get_errors();
+                    if let Some(x) = errors.first() {
+                        yield Err(ShellError::labeled_error_with_secondary(
+                            "Command failed to run",
+                            "command failed to run",
+                            tag.clone(),
+                            x.to_string(),
+                            tag
+                        ));
+                    }
+                }
+                Err(e) => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Command failed to run",
+                        "command failed to run",
+                        tag.clone(),
+                        e.to_string(),
+                        tag
+                    ));
+                }
+            }
+        };
+
+        Ok(stream.to_output_stream())
+    }
+}
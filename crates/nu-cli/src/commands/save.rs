@@ -1,3 +1,4 @@
+use crate::commands::to_text::render_value;
 use crate::commands::{UnevaluatedCallInfo, WholeStreamCommand};
 use crate::prelude::*;
 use nu_errors::ShellError;
@@ -37,7 +38,7 @@ macro_rules! process_string {
                 _ => {
                     break $scope Err(ShellError::labeled_error(
                         "Save requires string data",
-                        "consider converting data to string (see `help commands`)",
+                        "files without a to-* command for their extension can only hold strings (see `help commands`)",
                         $name_tag,
                     ));
                 }
@@ -281,8 +282,14 @@ fn string_from(input: &[Value]) -> String {
             } else {
                 first = false;
             }
-            if let Ok(data) = &i.as_string() {
-                save_data.push_str(data);
+            // Anything that isn't a string is written as to-text would render it
+            match i.as_string() {
+                Ok(data) => save_data.push_str(&data),
+                Err(_) => {
+                    if let Ok(data) = render_value(i, false) {
+                        save_data.push_str(&data);
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,105 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::{DateTime, NaiveDate, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct SeqDate;
+
+#[derive(Deserialize)]
+pub struct SeqDateArgs {
+    start: Tagged<String>,
+    end: Tagged<String>,
+    step: Option<Value>,
+}
+
+impl WholeStreamCommand for SeqDate {
+    fn name(&self) -> &str {
+        "seq-date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("seq-date")
+            .required(
+                "start",
+                SyntaxShape::String,
+                "the first date (YYYY-MM-DD, or a full RFC 3339 date and time)",
+            )
+            .required(
+                "end",
+                SyntaxShape::String,
+                "the date to stop at, included if a step lands on it",
+            )
+            .named(
+                "step",
+                SyntaxShape::Unit,
+                "the time between dates, like 1d or 2w (defaults to 1d)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Generates dates from a start date to an end date, a step apart."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, seq_date)?.run()
+    }
+}
+
+fn seq_date(
+    SeqDateArgs { start, end, step }: SeqDateArgs,
+    RunnableContext { name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let start_date = parse_date(&start)?;
+    let end_date = parse_date(&end)?;
+
+    let step = match step {
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Duration(secs)),
+            ..
+        }) if secs > 0 => secs,
+        Some(Value { tag, .. }) => {
+            return Err(ShellError::labeled_error(
+                "Expected a positive duration to step by",
+                "requires a duration like 1d",
+                tag,
+            ))
+        }
+        None => 24 * 60 * 60,
+    };
+
+    let step = chrono::Duration::seconds(step);
+    let mut dates = vec![];
+    let mut current = start_date;
+
+    while current <= end_date {
+        dates.push(ReturnSuccess::value(
+            UntaggedValue::date(current).into_value(&name),
+        ));
+        current = current + step;
+    }
+
+    Ok(futures::stream::iter(dates).to_output_stream())
+}
+
+fn parse_date(date: &Tagged<String>) -> Result<DateTime<Utc>, ShellError> {
+    if let Ok(day) = NaiveDate::parse_from_str(&date.item, "%Y-%m-%d") {
+        return Ok(DateTime::from_utc(day.and_hms(0, 0, 0), Utc));
+    }
+
+    DateTime::parse_from_rfc3339(&date.item)
+        .map(|parsed| parsed.with_timezone(&Utc))
+        .map_err(|_| {
+            ShellError::labeled_error(
+                "Could not parse date",
+                "expected a date like 2020-01-31",
+                &date.tag,
+            )
+        })
+}
@@ -1,7 +1,7 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, TaggedDictBuilder};
+use nu_protocol::{Signature, TaggedDictBuilder, UntaggedValue};
 use std::sync::atomic::Ordering;
 
 pub struct Shells;
@@ -35,11 +35,8 @@ fn shells(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream
     for (index, shell) in args.shell_manager.shells.lock().iter().enumerate() {
         let mut dict = TaggedDictBuilder::new(&tag);
 
-        if index == (*args.shell_manager.current_shell).load(Ordering::SeqCst) {
-            dict.insert_untagged("active", "X".to_string());
-        } else {
-            dict.insert_untagged("active", " ".to_string());
-        }
+        let active = index == (*args.shell_manager.current_shell).load(Ordering::SeqCst);
+        dict.insert_untagged("active", UntaggedValue::boolean(active));
         dict.insert_untagged("name", shell.name());
         dict.insert_untagged("path", shell.path());
 
@@ -0,0 +1,148 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use futures_timer::Delay;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+pub(crate) const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+pub struct Sleep;
+
+#[derive(Deserialize)]
+pub struct SleepArgs {
+    duration: Value,
+}
+
+impl WholeStreamCommand for Sleep {
+    fn name(&self) -> &str {
+        "sleep"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sleep").required(
+            "duration",
+            SyntaxShape::Unit,
+            "the time to sleep, eg) 10s",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Wait for the given time, then pass the input through unchanged."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sleep)?.run()
+    }
+}
+
+/// Sleeps for the given duration, waking up regularly to check for ctrl-c.
+/// Returns false if the sleep was interrupted.
+///
+/// The wait is on a timer rather than the thread, so other work keeps running meanwhile.
+pub(crate) async fn sleep_for(duration: Duration, ctrl_c: &AtomicBool) -> bool {
+    let start = Instant::now();
+
+    loop {
+        if ctrl_c.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed >= duration {
+            return true;
+        }
+
+        Delay::new(std::cmp::min(duration - elapsed, CTRL_C_CHECK_INTERVAL)).await;
+    }
+}
+
+/// Reads a duration argument (like `10s`, or a plain number of seconds) as whole seconds.
+pub(crate) fn duration_seconds(duration: &Value) -> Result<u64, ShellError> {
+    let negative = || {
+        ShellError::labeled_error(
+            "Duration must not be negative",
+            "expected a duration of zero or more",
+            &duration.tag,
+        )
+    };
+
+    match &duration.value {
+        UntaggedValue::Primitive(Primitive::Duration(seconds)) => {
+            seconds.to_u64().ok_or_else(negative)
+        }
+        UntaggedValue::Primitive(Primitive::Int(seconds)) => seconds.to_u64().ok_or_else(negative),
+        _ => Err(ShellError::labeled_error(
+            "Expected a duration",
+            "expected a duration, eg) 10s",
+            &duration.tag,
+        )),
+    }
+}
+
+fn sleep(
+    SleepArgs { duration }: SleepArgs,
+    RunnableContext {
+        mut input, ctrl_c, ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let seconds = duration_seconds(&duration)?;
+
+    let stream = async_stream! {
+        if !sleep_for(Duration::from_secs(seconds), &ctrl_c).await {
+            return;
+        }
+
+        while let Some(item) = input.next().await {
+            yield ReturnSuccess::value(item);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{duration_seconds, sleep_for};
+    use futures::executor::block_on;
+    use nu_protocol::UntaggedValue;
+    use std::sync::atomic::AtomicBool;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn sleeps_for_the_whole_duration() {
+        let start = Instant::now();
+
+        assert!(block_on(sleep_for(
+            Duration::from_millis(250),
+            &AtomicBool::new(false)
+        )));
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[test]
+    fn wakes_up_early_on_ctrl_c() {
+        let start = Instant::now();
+
+        assert!(!block_on(sleep_for(
+            Duration::from_secs(60),
+            &AtomicBool::new(true)
+        )));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn rejects_a_negative_duration_as_negative() {
+        for duration in vec![UntaggedValue::duration(-5), UntaggedValue::int(-5)] {
+            let error = duration_seconds(&duration.into_untagged_value())
+                .expect_err("a negative duration can't be slept for");
+
+            assert!(format!("{:?}", error).contains("Duration must not be negative"));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, UntaggedValue};
+
+pub struct Sort;
+
+#[derive(Deserialize)]
+pub struct SortArgs {
+    reverse: bool,
+    insensitive: bool,
+}
+
+impl WholeStreamCommand for Sort {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sort")
+            .switch("reverse", "sort from largest to smallest", Some('r'))
+            .switch("insensitive", "ignore case when sorting text", Some('i'))
+    }
+
+    fn usage(&self) -> &str {
+        "Sort plain values, like numbers or text. Use sort-by to sort rows by a column."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, sort)?.run()
+    }
+}
+
+fn sort(
+    SortArgs {
+        reverse,
+        insensitive,
+    }: SortArgs,
+    mut context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(OutputStream::new(async_stream! {
+        let mut vec = context.input.drain_vec().await;
+
+        if let Some(row) = vec
+            .iter()
+            .find(|item| !matches!(item.value, UntaggedValue::Primitive(_)))
+        {
+            yield Err(ShellError::labeled_error(
+                "Sort can only sort plain values",
+                "use sort-by to sort rows by a column",
+                &row.tag,
+            ));
+            return;
+        }
+
+        if insensitive {
+            vec.sort_by_cached_key(|item| match &item.value {
+                UntaggedValue::Primitive(Primitive::String(s))
+                | UntaggedValue::Primitive(Primitive::Line(s)) => {
+                    UntaggedValue::string(s.to_lowercase()).into_value(&item.tag)
+                }
+                _ => item.clone(),
+            });
+        } else {
+            vec.sort();
+        }
+
+        if reverse {
+            vec.reverse();
+        }
+
+        for item in vec {
+            yield item.into();
+        }
+    }))
+}
@@ -1,9 +1,11 @@
 use crate::commands::WholeStreamCommand;
+use crate::data::base::sort_order;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 use nu_value_ext::get_data_by_key;
+use std::cmp::Ordering;
 
 pub struct SortBy;
 
@@ -50,7 +52,7 @@ fn sort_by(
                 value: UntaggedValue::Primitive(_),
                 ..
             } => {
-                vec.sort();
+                vec.sort_by(|left, right| sort_order(&left.value, &right.value));
             },
             _ => {
                 let calc_key = |item: &Value| {
@@ -58,7 +60,10 @@ fn sort_by(
                         .map(|f| get_data_by_key(item, f.borrow_spanned()))
                         .collect::<Vec<Option<Value>>>()
                 };
-                vec.sort_by_cached_key(calc_key);
+
+                let mut keyed: Vec<_> = vec.into_iter().map(|item| (calc_key(&item), item)).collect();
+                keyed.sort_by(|(left, _), (right, _)| compare_keys(left, right));
+                vec = keyed.into_iter().map(|(_, item)| item).collect();
             },
         };
 
@@ -67,3 +72,19 @@ fn sort_by(
         }
     }))
 }
+
+/// Compares the sort columns of two rows in turn, treating a missing column as nothing.
+fn compare_keys(left: &[Option<Value>], right: &[Option<Value>]) -> Ordering {
+    let nothing = UntaggedValue::nothing();
+
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| {
+            sort_order(
+                left.as_ref().map_or(&nothing, |value| &value.value),
+                right.as_ref().map_or(&nothing, |value| &value.value),
+            )
+        })
+        .find(|order| *order != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
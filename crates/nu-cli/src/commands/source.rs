@@ -0,0 +1,117 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_parser::LiteBlock;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::{HasFallibleSpan, Tagged};
+use std::path::PathBuf;
+
+pub struct Source;
+
+#[derive(Deserialize)]
+pub struct SourceArgs {
+    filename: Tagged<PathBuf>,
+}
+
+impl WholeStreamCommand for Source {
+    fn name(&self) -> &str {
+        "source"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("source").required(
+            "filename",
+            SyntaxShape::Path,
+            "the script to run in the current shell",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Run the statements of a script in the current shell, keeping the commands and aliases it defines."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, source)?.run())
+    }
+}
+
+/// Points a parse error at the line of the script it happened on
+fn parse_error(filename: &Tagged<PathBuf>, contents: &str, error: ShellError) -> ShellError {
+    let location = match error.maybe_span() {
+        Some(span) if span.start() <= contents.len() => format!(
+            "{}:{}",
+            filename.item.display(),
+            contents[..span.start()].matches('\n').count() + 1
+        ),
+        _ => filename.item.display().to_string(),
+    };
+
+    ShellError::labeled_error(
+        format!("Could not parse {}", location),
+        error.to_string(),
+        &filename.tag,
+    )
+}
+
+fn source(
+    SourceArgs { filename }: SourceArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let path = PathBuf::from(context.shell_manager.path()).join(&filename.item);
+
+    let contents = std::fs::read_to_string(&path).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not read {}", filename.item.display()),
+            e.to_string(),
+            &filename.tag,
+        )
+    })?;
+
+    let lite_block = nu_parser::lite_parse(&contents, 0)
+        .map_err(|e| parse_error(&filename, &contents, e.into()))?;
+
+    let registry = context.registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+
+    let stream = async_stream! {
+        let mut context = Context::from_raw(&raw_args, &registry);
+        let mut output = vec![];
+
+        // Each statement is classified only once the ones before it have run,
+        // so it can use the commands they define
+        for pipeline in lite_block.block {
+            let classified = nu_parser::classify_block(&LiteBlock { block: vec![pipeline] }, &registry);
+
+            if let Some(failure) = classified.failed {
+                yield Err(parse_error(&filename, &contents, failure.into()));
+                return;
+            }
+
+            output = match run_block(&classified.block, &mut context, InputStream::empty(), &scope).await {
+                Ok(mut stream) => stream.drain_vec().await,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+
+            if let Some(error) = context.get_errors().first() {
+                yield Err(error.clone());
+                return;
+            }
+        }
+
+        for value in output {
+            yield ReturnSuccess::value(value);
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
@@ -0,0 +1,207 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder,
+    UnspannedPathMember, UntaggedValue, Value,
+};
+use nu_value_ext::ValueExt;
+use num_traits::ToPrimitive;
+
+pub struct Stddev;
+
+pub struct Variance;
+
+#[derive(Deserialize)]
+pub struct DeviationArgs {
+    rest: Vec<ColumnPath>,
+    sample: bool,
+}
+
+impl WholeStreamCommand for Stddev {
+    fn name(&self) -> &str {
+        "stddev"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stddev")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally, the columns to compute it for (defaults to the whole value)",
+            )
+            .switch(
+                "sample",
+                "the sample standard deviation instead of the population one",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Gives the standard deviation of the numbers (or columns)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, stddev)?.run()
+    }
+}
+
+impl WholeStreamCommand for Variance {
+    fn name(&self) -> &str {
+        "variance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("variance")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally, the columns to compute it for (defaults to the whole value)",
+            )
+            .switch(
+                "sample",
+                "the sample variance instead of the population one",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Gives the variance of the numbers (or columns)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, variance)?.run()
+    }
+}
+
+fn stddev(args: DeviationArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    deviation(args, true, context)
+}
+
+fn variance(args: DeviationArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    deviation(args, false, context)
+}
+
+fn deviation(
+    DeviationArgs {
+        rest: columns,
+        sample,
+    }: DeviationArgs,
+    square_root: bool,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.collect().await;
+
+        let result = if columns.is_empty() {
+            deviation_of(&values, sample, square_root, &name)
+                .map(|result| UntaggedValue::decimal(result).into_value(&name))
+        } else {
+            columns_deviation(&values, &columns, sample, square_root, &name)
+        };
+
+        match result {
+            Ok(value) => yield ReturnSuccess::value(value),
+            Err(e) => yield Err(e),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// A row with the result for each column under the column's name, eg) `{size: 3.5}`
+fn columns_deviation(
+    values: &[Value],
+    columns: &[ColumnPath],
+    sample: bool,
+    square_root: bool,
+    tag: &Tag,
+) -> Result<Value, ShellError> {
+    let mut row = TaggedDictBuilder::new(tag);
+
+    for path in columns {
+        let cells = values
+            .iter()
+            .map(|value| value.get_data_by_column_path(path, Box::new(move |(_, _, error)| error)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let result = deviation_of(&cells, sample, square_root, tag)?;
+        row.insert_untagged(column_name(path), UntaggedValue::decimal(result));
+    }
+
+    Ok(row.into_value())
+}
+
+fn deviation_of(
+    values: &[Value],
+    sample: bool,
+    square_root: bool,
+    tag: &Tag,
+) -> Result<f64, ShellError> {
+    let numbers = values.iter().map(as_f64).collect::<Result<Vec<_>, _>>()?;
+    let variance = compute_variance(&numbers, sample, tag)?;
+
+    Ok(if square_root {
+        variance.sqrt()
+    } else {
+        variance
+    })
+}
+
+pub(crate) fn column_name(path: &ColumnPath) -> String {
+    path.iter()
+        .map(|member| match &member.unspanned {
+            UnspannedPathMember::String(name) => name.clone(),
+            UnspannedPathMember::Int(index) => index.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn as_f64(value: &Value) -> Result<f64, ShellError> {
+    let number = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        _ => None,
+    };
+
+    number.ok_or_else(|| {
+        ShellError::labeled_error("Expected a number", "requires numeric input", &value.tag)
+    })
+}
+
+/// The population variance of `numbers`, or the sample variance (dividing by one less than the
+/// count) when `sample` is set.
+fn compute_variance(numbers: &[f64], sample: bool, tag: &Tag) -> Result<f64, ShellError> {
+    if numbers.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Expected numbers to compute with",
+            "requires at least one number",
+            tag,
+        ));
+    }
+
+    if sample && numbers.len() < 2 {
+        return Err(ShellError::labeled_error(
+            "A sample needs at least two numbers",
+            "requires at least two numbers with --sample",
+            tag,
+        ));
+    }
+
+    let count = numbers.len() as f64;
+    let mean = numbers.iter().sum::<f64>() / count;
+    let squares: f64 = numbers.iter().map(|n| (n - mean).powi(2)).sum();
+
+    Ok(if sample {
+        squares / (count - 1.0)
+    } else {
+        squares / count
+    })
+}
@@ -1,3 +1,4 @@
+use crate::commands::term_size::terminal_size;
 use crate::commands::WholeStreamCommand;
 use crate::format::TableView;
 use crate::prelude::*;
@@ -16,12 +17,18 @@ impl WholeStreamCommand for Table {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("table").named(
-            "start_number",
-            SyntaxShape::Number,
-            "row number to start viewing from",
-            Some('n'),
-        )
+        Signature::build("table")
+            .named(
+                "start_number",
+                SyntaxShape::Number,
+                "row number to start viewing from",
+                Some('n'),
+            )
+            .switch(
+                "expand",
+                "show every column in full, even when wider than the terminal",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -57,6 +64,12 @@ fn table(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
             }
         };
 
+        let termwidth = if args.has("expand") {
+            None
+        } else {
+            Some(terminal_size().0 as usize)
+        };
+
         let mut delay_slot = None;
 
         while !finished {
@@ -109,7 +122,7 @@ fn table(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
 
             if input.len() > 0 {
                 let mut host = host.lock();
-                let view = TableView::from_list(&input, start_number);
+                let view = TableView::from_list(&input, start_number, termwidth);
 
                 if let Some(view) = view {
                     handle_unexpected(&mut *host, |host| crate::format::print_view(&view, host));
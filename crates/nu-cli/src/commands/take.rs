@@ -0,0 +1,57 @@
+use crate::commands::drop::all_but_last;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct Take;
+
+#[derive(Deserialize)]
+pub struct TakeArgs {
+    rows: Option<Tagged<i64>>,
+}
+
+impl WholeStreamCommand for Take {
+    fn name(&self) -> &str {
+        "take"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("take").optional(
+            "rows",
+            SyntaxShape::Number,
+            "starting from the front, the number of rows to take (negative takes all but that many from the back)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Take the first number of rows, or all but the last with a negative number."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, take)?.run()
+    }
+}
+
+fn take(
+    TakeArgs { rows }: TakeArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let rows = rows.map(|quantity| quantity.item).unwrap_or(1);
+    let count = rows.checked_abs().unwrap_or(i64::max_value()) as usize;
+
+    if rows < 0 {
+        return Ok(all_but_last(input, count));
+    }
+
+    Ok(input
+        .take(count)
+        .map(ReturnSuccess::value)
+        .to_output_stream())
+}
@@ -0,0 +1,73 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+
+pub struct TermSize;
+
+impl WholeStreamCommand for TermSize {
+    fn name(&self) -> &str {
+        "term-size"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("term-size")
+    }
+
+    fn usage(&self) -> &str {
+        "Give the columns and rows of the terminal (80 by 24 when not writing to one)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        term_size(args)
+    }
+}
+
+fn term_size(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (columns, rows) = terminal_size();
+
+    let mut dict = TaggedDictBuilder::new(&args.call_info.name_tag);
+    dict.insert_untagged("columns", UntaggedValue::int(columns));
+    dict.insert_untagged("rows", UntaggedValue::int(rows));
+
+    Ok(futures::stream::iter(vec![ReturnSuccess::value(dict.into_value())]).to_output_stream())
+}
+
+/// The columns and rows of the terminal nu writes to, or 80 by 24 when output isn't going to
+/// one (or its size can't be read).
+pub(crate) fn terminal_size() -> (u16, u16) {
+    let size = if atty::is(atty::Stream::Stdout) {
+        crossterm::terminal::size().ok()
+    } else {
+        None
+    };
+
+    size_or_fallback(size)
+}
+
+fn size_or_fallback(size: Option<(u16, u16)>) -> (u16, u16) {
+    match size {
+        Some((columns, rows)) if columns > 0 && rows > 0 => (columns, rows),
+        _ => (80, 24),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::size_or_fallback;
+
+    #[test]
+    fn uses_the_terminal_size_when_there_is_one() {
+        assert_eq!(size_or_fallback(Some((120, 40))), (120, 40));
+    }
+
+    #[test]
+    fn falls_back_to_80_by_24() {
+        assert_eq!(size_or_fallback(None), (80, 24));
+        assert_eq!(size_or_fallback(Some((0, 0))), (80, 24));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::sleep::{duration_seconds, CTRL_C_CHECK_INTERVAL};
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+pub struct Timeout;
+
+#[derive(Deserialize)]
+pub struct TimeoutArgs {
+    duration: Value,
+    block: Block,
+}
+
+impl WholeStreamCommand for Timeout {
+    fn name(&self) -> &str {
+        "timeout"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("timeout")
+            .required(
+                "duration",
+                SyntaxShape::Unit,
+                "the longest the block may run, eg) 10s",
+            )
+            .required("block", SyntaxShape::Block, "the block to run")
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block, giving up with an error if it doesn't finish within the given time."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, timeout)?.run())
+    }
+}
+
+fn timeout(
+    TimeoutArgs { duration, block }: TimeoutArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let seconds = duration_seconds(&duration)?;
+    let registry = context.registry.clone();
+    let ctrl_c = context.ctrl_c;
+    let input = context.input;
+
+    let stream = async_stream! {
+        let input: Vec<Value> = input.collect().await;
+        let (sender, receiver) = mpsc::channel();
+
+        // The block runs on its own thread so we can stop waiting on it. It gets a ctrl-c of its
+        // own, which we set when it times out or the user interrupts, so that it stops too.
+        let cancel = Arc::new(AtomicBool::new(false));
+        let block_cancel = cancel.clone();
+
+        std::thread::spawn(move || {
+            let result = run_to_completion(&block, &raw_args, &registry, block_cancel, input);
+            let _ = sender.send(result);
+        });
+
+        let limit = Duration::from_secs(seconds);
+        let start = Instant::now();
+
+        let result = loop {
+            if ctrl_c.load(Ordering::SeqCst) {
+                cancel.store(true, Ordering::SeqCst);
+                return;
+            }
+
+            let remaining = limit.checked_sub(start.elapsed()).unwrap_or_default();
+            let wait = std::cmp::min(remaining, CTRL_C_CHECK_INTERVAL);
+
+            match receiver.recv_timeout(wait) {
+                Ok(result) => break result,
+                Err(RecvTimeoutError::Timeout) if start.elapsed() >= limit => {
+                    cancel.store(true, Ordering::SeqCst);
+
+                    break Err(ShellError::labeled_error(
+                        format!("Operation timed out after {}s", seconds),
+                        "operation timed out",
+                        &duration.tag,
+                    ));
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    break Err(ShellError::labeled_error(
+                        "The block stopped without finishing",
+                        "block stopped without finishing",
+                        &duration.tag,
+                    ));
+                }
+            }
+        };
+
+        match result {
+            Ok(values) => {
+                for value in values {
+                    yield ReturnSuccess::value(value);
+                }
+            }
+            Err(e) => yield Err(e),
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Runs `block` over the input to its end, gathering everything it outputs. The block sees
+/// `cancel` as its ctrl-c.
+fn run_to_completion(
+    block: &Block,
+    raw_args: &RawCommandArgs,
+    registry: &CommandRegistry,
+    cancel: Arc<AtomicBool>,
+    input: Vec<Value>,
+) -> Result<Vec<Value>, ShellError> {
+    let mut context = Context::from_raw(raw_args, registry);
+    context.ctrl_c = cancel;
+    let scope = raw_args.call_info.scope.clone();
+
+    futures::executor::block_on(async {
+        let stream = run_block(block, &mut context, InputStream::from(input), &scope).await?;
+        let output: Vec<Value> = stream.interruptible(context.ctrl_c.clone()).collect().await;
+
+        match context.get_errors().first() {
+            Some(error) => Err(error.clone()),
+            None => Ok(output),
+        }
+    })
+}
@@ -1,6 +1,7 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use bson::{encode_document, oid::ObjectId, spec::BinarySubtype, Bson, Document};
+use chrono::Utc;
 use nu_errors::{CoerceInto, ShellError};
 use nu_protocol::{
     Dictionary, Primitive, ReturnSuccess, Signature, SpannedTypeName, UnspannedPathMember,
@@ -46,7 +47,7 @@ pub fn value_to_bson_value(v: &Value) -> Result<Bson, ShellError> {
                 .expect("Unimplemented BUG: What about big decimals?"),
         ),
         UntaggedValue::Primitive(Primitive::Duration(secs)) => Bson::I64(*secs as i64),
-        UntaggedValue::Primitive(Primitive::Date(d)) => Bson::UtcDatetime(*d),
+        UntaggedValue::Primitive(Primitive::Date(d)) => Bson::UtcDatetime(d.with_timezone(&Utc)),
         UntaggedValue::Primitive(Primitive::EndOfStream) => Bson::Null,
         UntaggedValue::Primitive(Primitive::BeginningOfStream) => Bson::Null,
         UntaggedValue::Primitive(Primitive::Decimal(d)) => {
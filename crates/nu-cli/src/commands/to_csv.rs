@@ -10,6 +10,7 @@ pub struct ToCSV;
 pub struct ToCSVArgs {
     headerless: bool,
     separator: Option<Value>,
+    crlf: bool,
 }
 
 impl WholeStreamCommand for ToCSV {
@@ -30,6 +31,11 @@ impl WholeStreamCommand for ToCSV {
                 "do not output the columns names as the first row",
                 None,
             )
+            .switch(
+                "crlf",
+                "end lines with \\r\\n instead of \\n, as Windows programs expect",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -49,6 +55,7 @@ fn to_csv(
     ToCSVArgs {
         separator,
         headerless,
+        crlf,
     }: ToCSVArgs,
     runnable_context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
@@ -75,5 +82,5 @@ fn to_csv(
         _ => ',',
     };
 
-    to_delimited_data(headerless, sep, "CSV", runnable_context)
+    to_delimited_data(headerless, crlf, sep, "CSV", runnable_context)
 }
@@ -1,22 +1,36 @@
 use crate::prelude::*;
-use csv::WriterBuilder;
+use csv::{Terminator, Writer, WriterBuilder};
 use indexmap::{indexset, IndexSet};
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue, Value};
 use nu_source::Spanned;
 use nu_value_ext::{as_string, get_data_by_key};
 
+/// A writer ending records with `\r\n` given `crlf`, and `\n` otherwise.
+fn writer(separator: char, crlf: bool) -> Writer<Vec<u8>> {
+    let terminator = if crlf {
+        Terminator::CRLF
+    } else {
+        Terminator::Any(b'\n')
+    };
+
+    WriterBuilder::new()
+        .delimiter(separator as u8)
+        .terminator(terminator)
+        .from_writer(vec![])
+}
+
 fn from_value_to_delimited_string(
     tagged_value: &Value,
     separator: char,
+    headerless: bool,
+    crlf: bool,
 ) -> Result<String, ShellError> {
     let v = &tagged_value.value;
 
     match v {
         UntaggedValue::Row(o) => {
-            let mut wtr = WriterBuilder::new()
-                .delimiter(separator as u8)
-                .from_writer(vec![]);
+            let mut wtr = writer(separator, crlf);
             let mut fields: VecDeque<String> = VecDeque::new();
             let mut values: VecDeque<String> = VecDeque::new();
 
@@ -26,7 +40,9 @@ fn from_value_to_delimited_string(
                 values.push_back(to_string_tagged_value(&v)?);
             }
 
-            wtr.write_record(fields).expect("can not write.");
+            if !headerless {
+                wtr.write_record(fields).expect("can not write.");
+            }
             wtr.write_record(values).expect("can not write.");
 
             let v = String::from_utf8(wtr.into_inner().map_err(|_| {
@@ -46,9 +62,7 @@ fn from_value_to_delimited_string(
             Ok(v)
         }
         UntaggedValue::Table(list) => {
-            let mut wtr = WriterBuilder::new()
-                .delimiter(separator as u8)
-                .from_writer(vec![]);
+            let mut wtr = writer(separator, crlf);
 
             let merged_descriptors = merge_descriptors(&list);
 
@@ -60,8 +74,10 @@ fn from_value_to_delimited_string(
                 )
                 .expect("can not write");
             } else {
-                wtr.write_record(merged_descriptors.iter().map(|item| &item.item[..]))
-                    .expect("can not write.");
+                if !headerless {
+                    wtr.write_record(merged_descriptors.iter().map(|item| &item.item[..]))
+                        .expect("can not write.");
+                }
 
                 for l in list {
                     let mut row = vec![];
@@ -167,6 +183,7 @@ fn merge_descriptors(values: &[Value]) -> Vec<Spanned<String>> {
 
 pub fn to_delimited_data(
     headerless: bool,
+    crlf: bool,
     sep: char,
     format_name: &'static str,
     RunnableContext { input, name, .. }: RunnableContext,
@@ -187,14 +204,8 @@ pub fn to_delimited_data(
         };
 
         for value in to_process_input {
-            match from_value_to_delimited_string(&clone_tagged_value(&value), sep) {
-                Ok(mut x) => {
-                    if headerless {
-                        x.find('\n').map(|second_line|{
-                            let start = second_line + 1;
-                            x.replace_range(0..start, "");
-                        });
-                    }
+            match from_value_to_delimited_string(&clone_tagged_value(&value), sep, headerless, crlf) {
+                Ok(x) => {
                     yield ReturnSuccess::value(UntaggedValue::Primitive(Primitive::String(x)).into_value(&name_tag))
                 }
                 Err(x) => {
@@ -214,3 +225,97 @@ pub fn to_delimited_data(
 
     Ok(stream.to_output_stream())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::from_value_to_delimited_string;
+    use crate::commands::from_delimited_data::{CellTypes, DelimitedParser};
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+
+    // Joins the generated pieces with the characters that need quoting, so
+    // every value exercises commas, quotes and line breaks. The leading "x"
+    // keeps values from being read back as numbers.
+    fn nasty(pieces: &[String]) -> String {
+        let separators = [",", "\"", "\n", "\r\n", "\"\"", ", \""];
+        let mut value = String::from("x");
+
+        for (idx, piece) in pieces.iter().enumerate() {
+            value.push_str(separators[idx % separators.len()]);
+            value.push_str(piece);
+        }
+
+        value
+    }
+
+    fn row(name: &str, notes: &str) -> Value {
+        let mut dict = TaggedDictBuilder::new(Tag::unknown());
+        dict.insert_untagged("name", UntaggedValue::string(name));
+        dict.insert_untagged("notes", UntaggedValue::string(notes));
+        dict.into_value()
+    }
+
+    fn roundtrip(table: &[Value]) -> Option<Vec<Value>> {
+        let value = UntaggedValue::Table(table.to_vec()).into_untagged_value();
+        let csv = from_value_to_delimited_string(&value, ',', false, false).ok()?;
+
+        // The same parser from-csv streams its input through
+        let mut parser = DelimitedParser::new(false, ',', false, CellTypes::Text);
+        let mut rows = parser.push(&csv, &Tag::unknown()).ok()?;
+        rows.extend(parser.finish(&Tag::unknown()).ok()?);
+
+        Some(rows)
+    }
+
+    #[test]
+    fn quotes_values_with_commas_quotes_and_newlines() {
+        let table = vec![
+            row("Andrés", "says \"hi\", then\nleaves"),
+            row("Jonathan", "line one\r\nline two"),
+        ];
+
+        assert_eq!(roundtrip(&table), Some(table));
+    }
+
+    #[test]
+    fn headerless_output_skips_headers_with_newlines() {
+        let mut dict = TaggedDictBuilder::new(Tag::unknown());
+        dict.insert_untagged("first\nname", UntaggedValue::string("Andrés"));
+        let value = dict.into_value();
+
+        assert_eq!(
+            from_value_to_delimited_string(&value, ',', true, false).ok(),
+            Some(String::from("Andrés\n"))
+        );
+    }
+
+    #[test]
+    fn crlf_ends_every_record_with_a_carriage_return() {
+        let table = UntaggedValue::Table(vec![
+            row("Andrés", "hi"),
+            row("Jonathan", "line one\nline two"),
+        ])
+        .into_untagged_value();
+
+        assert_eq!(
+            from_value_to_delimited_string(&table, ',', false, true).ok(),
+            Some(String::from(
+                "name,notes\r\nAndrés,hi\r\nJonathan,\"line one\nline two\"\r\n"
+            ))
+        );
+    }
+
+    #[quickcheck]
+    fn csv_roundtrips_nasty_values(rows: Vec<(Vec<String>, Vec<String>)>) -> bool {
+        if rows.is_empty() {
+            return true;
+        }
+
+        let table = rows
+            .iter()
+            .map(|(name, notes)| row(&nasty(name), &nasty(notes)))
+            .collect::<Vec<_>>();
+
+        roundtrip(&table) == Some(table)
+    }
+}
@@ -1,10 +1,22 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::{CoerceInto, ShellError};
-use nu_protocol::{Primitive, ReturnSuccess, Signature, UnspannedPathMember, UntaggedValue, Value};
+use nu_protocol::{
+    Dictionary, Primitive, ReturnSuccess, Signature, SyntaxShape, UnspannedPathMember,
+    UntaggedValue, Value,
+};
+use serde::Serialize;
 
 pub struct ToJSON;
 
+/// The key of the single-entry object binary values are written as.
+pub const BINARY_KEY: &str = "$binary";
+
+/// How many levels of tables and rows to-json and to-yaml write before refusing, unless given
+/// `--max-depth`. Both serializers recurse once per level, so without a limit a deep enough
+/// structure overflows the stack instead of failing cleanly.
+pub const DEFAULT_MAX_DEPTH: u64 = 128;
+
 impl WholeStreamCommand for ToJSON {
     fn name(&self) -> &str {
         "to-json"
@@ -12,6 +24,43 @@ impl WholeStreamCommand for ToJSON {
 
     fn signature(&self) -> Signature {
         Signature::build("to-json")
+            .switch(
+                "pretty",
+                "formats the JSON text with indentation and newlines",
+                Some('p'),
+            )
+            .named(
+                "indent",
+                SyntaxShape::Int,
+                "formats the JSON text indented by the given number of spaces",
+                Some('i'),
+            )
+            .switch(
+                "tabs",
+                "formats the JSON text indented with tabs",
+                Some('t'),
+            )
+            .switch(
+                "raw",
+                "formats the JSON text compactly on a single line (the default)",
+                Some('r'),
+            )
+            .switch(
+                "lenient",
+                "writes null for decimals that JSON numbers can't hold",
+                Some('l'),
+            )
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "the most levels of nested tables and rows to write (defaults to 128)",
+                Some('m'),
+            )
+            .switch(
+                "sort-keys",
+                "writes the keys of objects in sorted order instead of column order",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -27,7 +76,7 @@ impl WholeStreamCommand for ToJSON {
     }
 }
 
-pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
+pub fn value_to_json_value(v: &Value, lenient: bool) -> Result<serde_json::Value, ShellError> {
     Ok(match &v.value {
         UntaggedValue::Primitive(Primitive::Boolean(b)) => serde_json::Value::Bool(*b),
         UntaggedValue::Primitive(Primitive::Bytes(b)) => serde_json::Value::Number(
@@ -36,36 +85,47 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
         UntaggedValue::Primitive(Primitive::Duration(secs)) => {
             serde_json::Value::Number(serde_json::Number::from(*secs))
         }
-        UntaggedValue::Primitive(Primitive::Date(d)) => serde_json::Value::String(d.to_string()),
+        UntaggedValue::Primitive(Primitive::Date(d)) => serde_json::Value::String(d.to_rfc3339()),
         UntaggedValue::Primitive(Primitive::EndOfStream) => serde_json::Value::Null,
         UntaggedValue::Primitive(Primitive::BeginningOfStream) => serde_json::Value::Null,
         UntaggedValue::Primitive(Primitive::Decimal(f)) => {
-            if let Some(f) = f.to_f64() {
-                if let Some(num) = serde_json::Number::from_f64(
-                    f.to_f64().expect("TODO: What about really big decimals?"),
-                ) {
-                    serde_json::Value::Number(num)
-                } else {
-                    return Err(ShellError::labeled_error(
-                        "Could not convert value to decimal number",
-                        "could not convert to decimal",
-                        &v.tag,
-                    ));
-                }
-            } else {
-                return Err(ShellError::labeled_error(
-                    "Could not convert value to decimal number",
-                    "could not convert to decimal",
+            // Decimals too large for a float come out as infinity, which JSON has no way to
+            // write, so they are either refused or, when lenient, written as null. Parsing the
+            // decimal's text gives the nearest float, where `to_f64` can be off in the last digit
+            match f
+                .to_string()
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+            {
+                Some(num) => serde_json::Value::Number(num),
+                None if lenient => serde_json::Value::Null,
+                None => return Err(ShellError::labeled_error(
+                    "Could not convert non-finite decimal to JSON (use --lenient to write null)",
+                    "decimal is not finite as a JSON number",
                     &v.tag,
-                ));
+                )),
             }
         }
-
         UntaggedValue::Primitive(Primitive::Int(i)) => {
-            serde_json::Value::Number(serde_json::Number::from(CoerceInto::<i64>::coerce_into(
-                i.tagged(&v.tag),
-                "converting to JSON number",
-            )?))
+            if let Some(n) = i.to_i64() {
+                serde_json::Value::Number(serde_json::Number::from(n))
+            } else if let Some(n) = i.to_u64() {
+                serde_json::Value::Number(serde_json::Number::from(n))
+            } else {
+                // JSON numbers beyond u64 can only be written as floats, so
+                // fall back to the nearest one rather than refusing the value
+                match i.to_f64().and_then(serde_json::Number::from_f64) {
+                    Some(num) => serde_json::Value::Number(num),
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Could not convert value to JSON number",
+                            "integer is too large for JSON",
+                            &v.tag,
+                        ))
+                    }
+                }
+            }
         }
         UntaggedValue::Primitive(Primitive::Nothing) => serde_json::Value::Null,
         UntaggedValue::Primitive(Primitive::Pattern(s)) => serde_json::Value::String(s.clone()),
@@ -90,51 +150,164 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
             serde_json::Value::String(s.display().to_string())
         }
 
-        UntaggedValue::Table(l) => serde_json::Value::Array(json_list(l)?),
+        UntaggedValue::Table(l) => serde_json::Value::Array(json_list(l, lenient)?),
         UntaggedValue::Error(e) => return Err(e.clone()),
         UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => {
             serde_json::Value::Null
         }
-        UntaggedValue::Primitive(Primitive::Binary(b)) => serde_json::Value::Array(
-            b.iter()
-                .map(|x| {
-                    serde_json::Number::from_f64(*x as f64).ok_or_else(|| {
-                        ShellError::labeled_error(
-                            "Can not convert number from floating point",
-                            "can not convert to number",
-                            &v.tag,
-                        )
-                    })
-                })
-                .collect::<Result<Vec<serde_json::Number>, ShellError>>()?
-                .into_iter()
-                .map(serde_json::Value::Number)
-                .collect(),
-        ),
+        UntaggedValue::Primitive(Primitive::Binary(b)) => {
+            // JSON has no bytes, so binary is written as base64 under a `$binary` key, which
+            // from-json turns back into binary
+            let mut m = serde_json::Map::new();
+            m.insert(
+                BINARY_KEY.to_string(),
+                serde_json::Value::String(base64::encode(b)),
+            );
+            serde_json::Value::Object(m)
+        }
         UntaggedValue::Row(o) => {
             let mut m = serde_json::Map::new();
             for (k, v) in o.entries.iter() {
-                m.insert(k.clone(), value_to_json_value(v)?);
+                m.insert(k.clone(), value_to_json_value(v, lenient)?);
             }
             serde_json::Value::Object(m)
         }
     })
 }
 
-fn json_list(input: &[Value]) -> Result<Vec<serde_json::Value>, ShellError> {
+/// Errors when `value` holds tables or rows nested more than `max_depth` levels deep. The walk
+/// keeps its own stack, so it stays safe however deep the value goes.
+pub(crate) fn check_depth(
+    value: &Value,
+    max_depth: u64,
+    format: &str,
+    name_tag: &Tag,
+) -> Result<(), ShellError> {
+    let mut pending = vec![(value, 0)];
+
+    while let Some((value, depth)) = pending.pop() {
+        let children: Vec<&Value> = match &value.value {
+            UntaggedValue::Table(list) => list.iter().collect(),
+            UntaggedValue::Row(row) => row.entries.values().collect(),
+            _ => continue,
+        };
+
+        if depth >= max_depth {
+            return Err(ShellError::labeled_error_with_secondary(
+                format!(
+                    "Could not convert to {}: nested more than {} levels deep",
+                    format, max_depth
+                ),
+                format!("nested more than {} levels deep", max_depth),
+                name_tag,
+                "use --max-depth to allow deeper nesting",
+                &value.tag,
+            ));
+        }
+
+        pending.extend(children.into_iter().map(|child| (child, depth + 1)));
+    }
+
+    Ok(())
+}
+
+/// A copy of `value` with the columns of every row, however deeply nested, in sorted order.
+/// Callers check the depth first, as this recurses once per level.
+pub(crate) fn sort_keys(value: &Value) -> Value {
+    match &value.value {
+        UntaggedValue::Row(row) => {
+            let mut entries: Vec<(&String, &Value)> = row.entries.iter().collect();
+            entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+            let entries = entries
+                .into_iter()
+                .map(|(key, value)| (key.clone(), sort_keys(value)))
+                .collect();
+
+            UntaggedValue::Row(Dictionary::new(entries)).into_value(&value.tag)
+        }
+        UntaggedValue::Table(list) => {
+            UntaggedValue::Table(list.iter().map(sort_keys).collect()).into_value(&value.tag)
+        }
+        _ => value.clone(),
+    }
+}
+
+fn json_list(input: &[Value], lenient: bool) -> Result<Vec<serde_json::Value>, ShellError> {
     let mut out = vec![];
 
     for value in input {
-        out.push(value_to_json_value(value)?);
+        out.push(value_to_json_value(value, lenient)?);
     }
 
     Ok(out)
 }
 
+/// The JSON text of `value`, on one line or, given an indentation, one level of it per nesting.
+fn json_text(value: &serde_json::Value, indentation: Option<&[u8]>) -> serde_json::Result<String> {
+    let indentation = match indentation {
+        Some(indentation) => indentation,
+        None => return serde_json::to_string(value),
+    };
+
+    let mut text = vec![];
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indentation);
+    value.serialize(&mut serde_json::Serializer::with_formatter(
+        &mut text, formatter,
+    ))?;
+
+    Ok(String::from_utf8_lossy(&text).into_owned())
+}
+
 fn to_json(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once(registry)?;
     let name_tag = args.name_tag();
     let name_span = name_tag.span;
+
+    let pretty = args.has("pretty");
+    let lenient = args.has("lenient");
+    let tabs = args.has("tabs");
+    let sorted = args.has("sort-keys");
+    let max_depth = match args.get("max-depth") {
+        Some(depth) => depth.as_u64()?,
+        None => DEFAULT_MAX_DEPTH,
+    };
+    let indent = match args.get("indent") {
+        Some(indent) => Some(indent.as_u64()? as usize),
+        None => None,
+    };
+
+    if pretty && args.has("raw") {
+        return Err(ShellError::labeled_error(
+            "Conflicting flags: --pretty and --raw",
+            "use either --pretty or --raw, not both",
+            &name_tag,
+        ));
+    }
+
+    if tabs && indent.is_some() {
+        return Err(ShellError::labeled_error(
+            "Conflicting flags: --tabs and --indent",
+            "use either --tabs or --indent, not both",
+            &name_tag,
+        ));
+    }
+
+    let indentation = match (tabs, indent) {
+        (true, _) => Some(b"\t".to_vec()),
+        (false, Some(width)) => Some(vec![b' '; width]),
+        (false, None) if pretty => Some(b"  ".to_vec()),
+        (false, None) => None,
+    };
+
+    if indentation.is_some() && args.has("raw") {
+        return Err(ShellError::labeled_error(
+            "Conflicting flags: --raw and indentation",
+            "--raw writes everything on one line, so it can't be indented",
+            &name_tag,
+        ));
+    }
+
     let stream = async_stream! {
         let input: Vec<Value> = args.input.collect().await;
 
@@ -148,11 +321,20 @@ fn to_json(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
         };
 
         for value in to_process_input {
-            match value_to_json_value(&value) {
+            if let Err(e) = check_depth(&value, max_depth, "JSON", &name_tag) {
+                yield Err(e);
+                continue;
+            }
+
+            let value = if sorted { sort_keys(&value) } else { value };
+
+            match value_to_json_value(&value, lenient) {
                 Ok(json_value) => {
                     let value_span = value.tag.span;
 
-                    match serde_json::to_string(&json_value) {
+                    let text = json_text(&json_value, indentation.as_deref());
+
+                    match text {
                         Ok(x) => yield ReturnSuccess::value(
                             UntaggedValue::Primitive(Primitive::String(x)).into_value(&name_tag),
                         ),
@@ -165,10 +347,7 @@ fn to_json(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
                         )),
                     }
                 }
-                _ => yield Err(ShellError::labeled_error(
-                    "Expected a table with JSON-compatible structure from pipeline",
-                    "requires JSON-compatible input",
-                    &name_tag))
+                Err(e) => yield Err(e),
             }
         }
     };
@@ -0,0 +1,231 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
+
+pub struct ToNUON;
+
+impl WholeStreamCommand for ToNUON {
+    fn name(&self) -> &str {
+        "to-nuon"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-nuon")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into .nuon text, nu's own literal syntax, keeping file sizes and durations."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        to_nuon(args, registry)
+    }
+}
+
+/// Writes the value the way it would be typed as a literal, eg) `{name: "nu", size: 2048b}`
+///
+/// Nothing, booleans, ints, decimals, file sizes, durations, strings, and lists and rows of
+/// these read back as the same values. Lines, paths and patterns are written as strings, so
+/// they read back as strings. Dates, binary data, ranges, column paths, blocks, empty rows and
+/// strings holding both kinds of quotes have no literal and are refused.
+pub fn value_to_nuon(v: &Value) -> Result<String, ShellError> {
+    Ok(match &v.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => "$nothing".to_string(),
+        UntaggedValue::Primitive(Primitive::Boolean(true)) => "$true".to_string(),
+        UntaggedValue::Primitive(Primitive::Boolean(false)) => "$false".to_string(),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            let decimal = d.to_string();
+
+            // A decimal without a fraction would be read back as an int
+            if decimal.contains('.') {
+                decimal
+            } else {
+                format!("{}.0", decimal)
+            }
+        }
+        UntaggedValue::Primitive(Primitive::Bytes(b)) => format!("{}b", b),
+        UntaggedValue::Primitive(Primitive::Duration(d)) => format!("{}s", d),
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::Pattern(s)) => quote(s, &v.tag)?,
+        UntaggedValue::Primitive(Primitive::Path(p)) => quote(&p.display().to_string(), &v.tag)?,
+        UntaggedValue::Table(l) => format!(
+            "[{}]",
+            l.iter()
+                .map(value_to_nuon)
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+        UntaggedValue::Row(o) if o.entries.is_empty() => {
+            return Err(ShellError::labeled_error(
+                "Can't write an empty row as nuon",
+                "an empty row reads back as a block",
+                &v.tag,
+            ))
+        }
+        UntaggedValue::Row(o) => {
+            let mut entries = vec![];
+
+            for (k, v) in o.entries.iter() {
+                entries.push(format!(
+                    "{}: {}",
+                    column_name(k, &v.tag)?,
+                    value_to_nuon(v)?
+                ));
+            }
+
+            format!("{{{}}}", entries.join(", "))
+        }
+        UntaggedValue::Error(e) => return Err(e.clone()),
+        other => {
+            return Err(ShellError::labeled_error(
+                format!("Can't write {} as nuon", other.type_name()),
+                "nuon has no literal for this value",
+                &v.tag,
+            ))
+        }
+    })
+}
+
+/// Strings are always quoted, so that eg) "10" doesn't read back as a number
+fn quote(s: &str, tag: &Tag) -> Result<String, ShellError> {
+    if !s.contains('"') {
+        Ok(format!("\"{}\"", s))
+    } else if !s.contains('\'') {
+        Ok(format!("'{}'", s))
+    } else {
+        Err(ShellError::labeled_error(
+            "Can't write a string holding both kinds of quotes as nuon",
+            "string holds both ' and \"",
+            tag,
+        ))
+    }
+}
+
+fn column_name(name: &str, tag: &Tag) -> Result<String, ShellError> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        Ok(name.to_string())
+    } else {
+        quote(name, tag)
+    }
+}
+
+fn to_nuon(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once(registry)?;
+    let name_tag = args.name_tag();
+    let stream = async_stream! {
+        let input: Vec<Value> = args.input.collect().await;
+
+        let to_process_input = if input.len() > 1 {
+            let tag = input[0].tag.clone();
+            vec![Value { value: UntaggedValue::Table(input), tag } ]
+        } else if input.len() == 1 {
+            input
+        } else {
+            vec![]
+        };
+
+        for value in to_process_input {
+            match value_to_nuon(&value) {
+                Ok(x) => yield ReturnSuccess::value(
+                    UntaggedValue::Primitive(Primitive::String(x)).into_value(&name_tag),
+                ),
+                Err(e) => yield Err(e),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::value_to_nuon;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn row(entries: Vec<(&str, Value)>) -> Value {
+        let mut row = nu_protocol::TaggedDictBuilder::new(Tag::unknown());
+
+        for (key, value) in entries {
+            row.insert_value(key, value);
+        }
+
+        row.into_value()
+    }
+
+    #[test]
+    fn writes_file_sizes_and_durations_with_their_units() {
+        let value = row(vec![
+            ("size", UntaggedValue::bytes(2048u64).into_untagged_value()),
+            ("age", UntaggedValue::duration(90).into_untagged_value()),
+        ]);
+
+        assert_eq!(value_to_nuon(&value).unwrap(), "{size: 2048b, age: 90s}");
+    }
+
+    #[test]
+    fn quotes_strings_and_odd_column_names() {
+        let value = row(vec![
+            (
+                "first name",
+                UntaggedValue::string("10").into_untagged_value(),
+            ),
+            (
+                "quote",
+                UntaggedValue::string("say \"hi\"").into_untagged_value(),
+            ),
+        ]);
+
+        assert_eq!(
+            value_to_nuon(&value).unwrap(),
+            r#"{"first name": "10", quote: 'say "hi"'}"#
+        );
+    }
+
+    #[test]
+    fn keeps_decimals_apart_from_ints() {
+        let value = UntaggedValue::table(&[
+            UntaggedValue::int(2).into_untagged_value(),
+            UntaggedValue::decimal(bigdecimal::BigDecimal::from(2)).into_untagged_value(),
+        ])
+        .into_untagged_value();
+
+        assert_eq!(value_to_nuon(&value).unwrap(), "[2, 2.0]");
+    }
+
+    #[test]
+    fn writes_a_table_nested_inside_a_row() {
+        let files = UntaggedValue::table(&[
+            row(vec![
+                ("name", UntaggedValue::string("a").into_untagged_value()),
+                ("size", UntaggedValue::bytes(1u64).into_untagged_value()),
+            ]),
+            row(vec![
+                ("name", UntaggedValue::string("b").into_untagged_value()),
+                ("size", UntaggedValue::bytes(2u64).into_untagged_value()),
+            ]),
+        ])
+        .into_untagged_value();
+
+        let value = row(vec![
+            ("dir", UntaggedValue::string("nu").into_untagged_value()),
+            ("files", files),
+        ]);
+
+        assert_eq!(
+            value_to_nuon(&value).unwrap(),
+            r#"{dir: "nu", files: [{name: "a", size: 1b}, {name: "b", size: 2b}]}"#
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::commands::to_json::value_to_json_value;
+use crate::commands::WholeStreamCommand;
+use crate::data::value::format_leaf;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct ToText;
+
+#[derive(Deserialize)]
+pub struct ToTextArgs {
+    #[serde(rename(deserialize = "no-newline"))]
+    no_newline: bool,
+    separator: Option<Tagged<String>>,
+    json: bool,
+}
+
+impl WholeStreamCommand for ToText {
+    fn name(&self) -> &str {
+        "to-text"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-text")
+            .switch("no-newline", "leave out the trailing newline", Some('n'))
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the text to put between rows (defaults to a newline)",
+                Some('s'),
+            )
+            .switch(
+                "json",
+                "render rows as JSON instead of key: value lines",
+                Some('j'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert values into plain text, one row after another."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_text)?.run()
+    }
+}
+
+fn to_text(
+    ToTextArgs {
+        no_newline,
+        separator,
+        json,
+    }: ToTextArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let separator = separator
+        .map(|s| s.item)
+        .unwrap_or_else(|| "\n".to_string());
+
+    let stream = async_stream! {
+        let input: Vec<Value> = input.collect().await;
+
+        let mut rendered = vec![];
+        for value in &input {
+            match render_value(value, json) {
+                Ok(text) => rendered.push(text),
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            }
+        }
+
+        let mut text = rendered.join(&separator);
+        if !no_newline {
+            text.push('\n');
+        }
+
+        yield ReturnSuccess::value(UntaggedValue::string(text).into_value(&name));
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub(crate) fn render_value(value: &Value, json: bool) -> Result<String, ShellError> {
+    match &value.value {
+        UntaggedValue::Row(_) if json => serde_json::to_string(&value_to_json_value(value, false)?)
+            .map_err(|_| {
+                ShellError::labeled_error(
+                    "Could not render row as JSON",
+                    "row is not JSON-compatible",
+                    &value.tag,
+                )
+            }),
+        UntaggedValue::Row(row) => Ok(row
+            .entries
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, render_leaf(value)))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        _ => Ok(render_leaf(value)),
+    }
+}
+
+fn render_leaf(value: &Value) -> String {
+    format_leaf(value).plain_string(100_000)
+}
@@ -9,6 +9,7 @@ pub struct ToTSV;
 #[derive(Deserialize)]
 pub struct ToTSVArgs {
     headerless: bool,
+    crlf: bool,
 }
 
 impl WholeStreamCommand for ToTSV {
@@ -17,11 +18,17 @@ impl WholeStreamCommand for ToTSV {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to-tsv").switch(
-            "headerless",
-            "do not output the column names as the first row",
-            None,
-        )
+        Signature::build("to-tsv")
+            .switch(
+                "headerless",
+                "do not output the column names as the first row",
+                None,
+            )
+            .switch(
+                "crlf",
+                "end lines with \\r\\n instead of \\n, as Windows programs expect",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -38,8 +45,8 @@ impl WholeStreamCommand for ToTSV {
 }
 
 fn to_tsv(
-    ToTSVArgs { headerless }: ToTSVArgs,
+    ToTSVArgs { headerless, crlf }: ToTSVArgs,
     runnable_context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    to_delimited_data(headerless, '\t', "TSV", runnable_context)
+    to_delimited_data(headerless, crlf, '\t', "TSV", runnable_context)
 }
@@ -1,7 +1,10 @@
+use crate::commands::to_json::{check_depth, sort_keys, BINARY_KEY, DEFAULT_MAX_DEPTH};
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::{CoerceInto, ShellError};
-use nu_protocol::{Primitive, ReturnSuccess, Signature, UnspannedPathMember, UntaggedValue, Value};
+use nu_protocol::{
+    Primitive, ReturnSuccess, Signature, SyntaxShape, UnspannedPathMember, UntaggedValue, Value,
+};
 
 pub struct ToYAML;
 
@@ -12,6 +15,22 @@ impl WholeStreamCommand for ToYAML {
 
     fn signature(&self) -> Signature {
         Signature::build("to-yaml")
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "the most levels of nested tables and rows to write (defaults to 128)",
+                Some('m'),
+            )
+            .switch(
+                "documents",
+                "write each input value as a document of its own, separated by ---",
+                Some('d'),
+            )
+            .switch(
+                "sort-keys",
+                "writes the keys of mappings in sorted order instead of column order",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -106,11 +125,15 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => {
             serde_yaml::Value::Null
         }
-        UntaggedValue::Primitive(Primitive::Binary(b)) => serde_yaml::Value::Sequence(
-            b.iter()
-                .map(|x| serde_yaml::Value::Number(serde_yaml::Number::from(*x)))
-                .collect(),
-        ),
+        UntaggedValue::Primitive(Primitive::Binary(b)) => {
+            // Written the same way as to-json does, so from-yaml can turn it back into binary
+            let mut m = serde_yaml::Mapping::new();
+            m.insert(
+                serde_yaml::Value::String(BINARY_KEY.to_string()),
+                serde_yaml::Value::String(base64::encode(b)),
+            );
+            serde_yaml::Value::Mapping(m)
+        }
         UntaggedValue::Row(o) => {
             let mut m = serde_yaml::Mapping::new();
             for (k, v) in o.entries.iter() {
@@ -124,14 +147,73 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
     })
 }
 
+/// The YAML text of `value` as a document of a multi-document stream, starting with `---`.
+fn document_text(
+    value: &Value,
+    max_depth: u64,
+    sorted: bool,
+    name_tag: &Tag,
+) -> Result<String, ShellError> {
+    check_depth(value, max_depth, "YAML", name_tag)?;
+
+    let yaml_value = if sorted {
+        value_to_yaml_value(&sort_keys(value))?
+    } else {
+        value_to_yaml_value(value)?
+    };
+
+    let text = serde_yaml::to_string(&yaml_value).map_err(|_| {
+        ShellError::labeled_error_with_secondary(
+            "Expected a table with YAML-compatible structure from pipeline",
+            "requires YAML-compatible input",
+            name_tag,
+            "originates from here",
+            &value.tag,
+        )
+    })?;
+
+    if text.starts_with("---") {
+        Ok(text)
+    } else {
+        Ok(format!("---\n{}", text))
+    }
+}
+
 fn to_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once(registry)?;
     let name_tag = args.name_tag();
     let name_span = name_tag.span;
+    let max_depth = match args.get("max-depth") {
+        Some(depth) => depth.as_u64()?,
+        None => DEFAULT_MAX_DEPTH,
+    };
+    let documents = args.has("documents");
+    let sorted = args.has("sort-keys");
 
     let stream = async_stream! {
         let input: Vec<Value> = args.input.collect().await;
 
+        if documents {
+            let mut texts = vec![];
+
+            for value in &input {
+                match document_text(value, max_depth, sorted, &name_tag) {
+                    Ok(text) => texts.push(text),
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                }
+            }
+
+            if !texts.is_empty() {
+                yield ReturnSuccess::value(
+                    UntaggedValue::string(texts.join("\n")).into_value(&name_tag),
+                );
+            }
+            return;
+        }
+
         let to_process_input = if input.len() > 1 {
             let tag = input[0].tag.clone();
             vec![Value { value: UntaggedValue::Table(input), tag } ]
@@ -144,6 +226,13 @@ fn to_yaml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
         for value in to_process_input {
             let value_span = value.tag.span;
 
+            if let Err(e) = check_depth(&value, max_depth, "YAML", &name_tag) {
+                yield Err(e);
+                continue;
+            }
+
+            let value = if sorted { sort_keys(&value) } else { value };
+
             match value_to_yaml_value(&value) {
                 Ok(yaml_value) => {
                     match serde_yaml::to_string(&yaml_value) {
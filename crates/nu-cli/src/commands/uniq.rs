@@ -1,12 +1,15 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
-use indexmap::set::IndexSet;
+use indexmap::map::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature};
 
 #[derive(Deserialize)]
-struct UniqArgs {}
+struct UniqArgs {
+    repeated: bool,
+    unique: bool,
+}
 
 pub struct Uniq;
 
@@ -17,6 +20,16 @@ impl WholeStreamCommand for Uniq {
 
     fn signature(&self) -> Signature {
         Signature::build("uniq")
+            .switch(
+                "repeated",
+                "only return rows that appear more than once",
+                Some('d'),
+            )
+            .switch(
+                "unique",
+                "only return rows that appear exactly once",
+                Some('u'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -33,14 +46,33 @@ impl WholeStreamCommand for Uniq {
 }
 
 fn uniq(
-    UniqArgs {}: UniqArgs,
-    RunnableContext { input, .. }: RunnableContext,
+    UniqArgs { repeated, unique }: UniqArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    if repeated && unique {
+        return Err(ShellError::labeled_error(
+            "Uniq can't return both repeated and unique rows",
+            "use either --repeated or --unique",
+            name,
+        ));
+    }
+
     let stream = async_stream! {
-        let uniq_values: IndexSet<_> = input.collect().await;
+        let values: Vec<_> = input.collect().await;
+
+        // Every row has to be counted before we know which ones repeat. Rows are compared
+        // without their tags, since the same value can come from different places.
+        let mut counts = IndexMap::new();
+        for value in values {
+            counts.entry(value.value.clone()).or_insert((value, 0)).1 += 1;
+        }
+
+        for (_, (row, count)) in counts {
+            if (repeated && count < 2) || (unique && count > 1) {
+                continue;
+            }
 
-        for item in uniq_values.iter().map(|row| ReturnSuccess::value(row.clone())) {
-            yield item;
+            yield ReturnSuccess::value(row);
         }
     };
 
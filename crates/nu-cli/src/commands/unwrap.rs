@@ -0,0 +1,54 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct Unwrap;
+
+impl WholeStreamCommand for Unwrap {
+    fn name(&self) -> &str {
+        "unwrap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unwrap")
+    }
+
+    fn usage(&self) -> &str {
+        "Unwraps rows with a single column into their values (the reverse of wrap)."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        unwrap(args, registry)
+    }
+}
+
+fn unwrap(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    Ok(args
+        .input
+        .map(|value| match value {
+            Value {
+                value: UntaggedValue::Row(row),
+                tag,
+            } => {
+                let columns = row.entries.len();
+                let mut entries = row.entries.into_iter();
+
+                match (entries.next(), entries.next()) {
+                    (Some((_, value)), None) => ReturnSuccess::value(value),
+                    _ => Err(ShellError::labeled_error(
+                        "Unwrap needs rows with exactly one column",
+                        format!("this row has {} columns", columns),
+                        tag,
+                    )),
+                }
+            }
+            other => ReturnSuccess::value(other),
+        })
+        .to_output_stream())
+}
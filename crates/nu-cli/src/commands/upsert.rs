@@ -0,0 +1,129 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use futures::stream::once;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_value_ext::ValueExt;
+
+pub struct Upsert;
+
+#[derive(Deserialize)]
+pub struct UpsertArgs {
+    column: ColumnPath,
+    value: Value,
+}
+
+impl WholeStreamCommand for Upsert {
+    fn name(&self) -> &str {
+        "upsert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("upsert")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column to set, whether or not it exists yet",
+            )
+            .required(
+                "value",
+                SyntaxShape::Any,
+                "the value to give the cell(s), or a block computing it from the row in $it",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Set a column to a value, updating it if the column is there and inserting it if not."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args.process_raw(registry, upsert)?.run())
+    }
+}
+
+fn upsert(
+    UpsertArgs { column, value }: UpsertArgs,
+    context: RunnableContext,
+    raw_args: RawCommandArgs,
+) -> Result<OutputStream, ShellError> {
+    let registry = context.registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut input = context.input;
+
+    let stream = async_stream! {
+        while let Some(row) = input.next().await {
+            let is_row = matches!(row.value, UntaggedValue::Row(_));
+
+            if !is_row {
+                yield Err(ShellError::labeled_error(
+                    "Unrecognized type in stream",
+                    "original value",
+                    row.tag,
+                ));
+                return;
+            }
+
+            let new_value = match &value.value {
+                UntaggedValue::Block(block) => {
+                    let mut context = Context::from_raw(&raw_args, &registry);
+                    let input = row.clone();
+                    let input_stream = once(async { Ok(input) }).to_input_stream();
+
+                    let result = run_block(
+                        block,
+                        &mut context,
+                        input_stream,
+                        &scope.clone().set_it(row.clone()),
+                    ).await;
+
+                    match result {
+                        Ok(mut stream) => {
+                            if let Some(error) = context.get_errors().first() {
+                                yield Err(error.clone());
+                                return;
+                            }
+
+                            match stream.next().await {
+                                Some(computed) => computed,
+                                None => UntaggedValue::nothing().into_value(&value.tag),
+                            }
+                        }
+                        Err(e) => {
+                            yield Err(e);
+                            return;
+                        }
+                    }
+                }
+                _ => value.clone(),
+            };
+
+            let exists = row
+                .get_data_by_column_path(&column, Box::new(move |(_, _, error)| error))
+                .is_ok();
+
+            if exists {
+                match row.replace_data_at_column_path(&column, new_value) {
+                    Some(updated) => yield ReturnSuccess::value(updated),
+                    None => yield Err(ShellError::labeled_error(
+                        "upsert could not find place to update column",
+                        "column name",
+                        row.tag,
+                    )),
+                }
+            } else {
+                match row.insert_data_at_column_path(&column, new_value) {
+                    Ok(inserted) => yield ReturnSuccess::value(inserted),
+                    Err(err) => yield Err(err),
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
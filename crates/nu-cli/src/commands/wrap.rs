@@ -9,8 +9,8 @@ use nu_source::Tagged;
 pub struct Wrap;
 
 #[derive(Deserialize)]
-struct WrapArgs {
-    column: Option<Tagged<String>>,
+pub(crate) struct WrapArgs {
+    pub(crate) column: Option<Tagged<String>>,
 }
 
 impl WholeStreamCommand for Wrap {
@@ -39,7 +39,7 @@ impl WholeStreamCommand for Wrap {
     }
 }
 
-fn wrap(
+pub(crate) fn wrap(
     WrapArgs { column }: WrapArgs,
     context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
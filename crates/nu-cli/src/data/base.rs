@@ -1,7 +1,7 @@
 pub(crate) mod shape;
 
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use derive_new::new;
 use nu_errors::ShellError;
 use nu_protocol::{
@@ -74,8 +74,29 @@ pub(crate) fn reject_fields(obj: &Value, fields: &[String], tag: impl Into<Tag>)
     for desc in descs {
         if fields.iter().any(|field| *field == desc) {
             continue;
-        } else {
-            out.insert_value(desc.clone(), obj.get_data(&desc).borrow().clone())
+        }
+
+        let value = obj.get_data(&desc).borrow().clone();
+
+        // A dotted field like `address.city` removes `city` from inside the `address` column
+        let nested: Vec<String> = fields
+            .iter()
+            .filter_map(|field| {
+                let mut parts = field.splitn(2, '.');
+
+                match (parts.next(), parts.next()) {
+                    (Some(head), Some(rest)) if head == desc => Some(rest.to_string()),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        match &value.value {
+            UntaggedValue::Row(_) if !nested.is_empty() => {
+                let tag = value.tag.clone();
+                out.insert_value(desc, reject_fields(&value, &nested, tag))
+            }
+            _ => out.insert_value(desc, value),
         }
     }
 
@@ -86,8 +107,8 @@ pub(crate) enum CompareValues {
     Ints(BigInt, BigInt),
     Decimals(BigDecimal, BigDecimal),
     String(String, String),
-    Date(DateTime<Utc>, DateTime<Utc>),
-    DateDuration(DateTime<Utc>, i64),
+    Date(DateTime<FixedOffset>, DateTime<FixedOffset>),
+    DateDuration(DateTime<FixedOffset>, i64),
     Booleans(bool, bool),
 }
 
@@ -107,7 +128,7 @@ impl CompareValues {
                 } else {
                     (SystemTime::now() - Duration::from_secs(*right as u64)).into()
                 };
-                right.cmp(left)
+                right.cmp(&left.with_timezone(&Utc))
             }
             CompareValues::Booleans(left, right) => left.cmp(right),
         }
@@ -170,6 +191,40 @@ fn coerce_compare_primitive(
         _ => return Err((left.type_name(), right.type_name())),
     })
 }
+
+/// Where a value's type goes when sorting values of different types together:
+/// nothing, booleans, numbers, durations, dates, text, binary, rows and then lists.
+fn sort_rank(value: &UntaggedValue) -> u8 {
+    use Primitive::*;
+
+    match value {
+        UntaggedValue::Primitive(Nothing) => 0,
+        UntaggedValue::Primitive(Boolean(_)) => 1,
+        UntaggedValue::Primitive(Int(_)) | UntaggedValue::Primitive(Decimal(_)) => 2,
+        UntaggedValue::Primitive(Bytes(_)) => 2,
+        UntaggedValue::Primitive(Duration(_)) => 3,
+        UntaggedValue::Primitive(Date(_)) => 4,
+        UntaggedValue::Primitive(String(_)) | UntaggedValue::Primitive(Line(_)) => 5,
+        UntaggedValue::Primitive(Pattern(_)) | UntaggedValue::Primitive(Path(_)) => 5,
+        UntaggedValue::Primitive(ColumnPath(_)) => 5,
+        UntaggedValue::Primitive(Binary(_)) => 6,
+        UntaggedValue::Row(_) => 7,
+        UntaggedValue::Table(_) => 8,
+        _ => 9,
+    }
+}
+
+/// A total order over values of any type, so sorting never fails on mixed data. Values of
+/// different types sort by `sort_rank`, and values of the same kind compare as usual, with
+/// integers, decimals and file sizes compared by their numeric value.
+pub(crate) fn sort_order(left: &UntaggedValue, right: &UntaggedValue) -> std::cmp::Ordering {
+    sort_rank(left)
+        .cmp(&sort_rank(right))
+        .then_with(|| match coerce_compare(left, right) {
+            Ok(values) => values.compare(),
+            Err(_) => left.cmp(right),
+        })
+}
 #[cfg(test)]
 mod tests {
     use indexmap::IndexMap;
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset};
 use nu_protocol::RangeInclusion;
 use nu_protocol::{format_primitive, ColumnPath, Dictionary, Primitive, UntaggedValue, Value};
 use nu_source::{b, PrettyDebug};
@@ -26,7 +26,7 @@ pub enum InlineShape {
     ColumnPath(ColumnPath),
     Pattern(String),
     Boolean(bool),
-    Date(DateTime<Utc>),
+    Date(DateTime<FixedOffset>),
     Duration(i64),
     Path(PathBuf),
     Binary,
@@ -141,8 +141,9 @@ impl PrettyDebug for FormatInlineShape {
                 let (right, right_inclusion) = &range.to;
 
                 let op = match (left_inclusion, right_inclusion) {
-                    (RangeInclusion::Inclusive, RangeInclusion::Exclusive) => "..",
-                    _ => unimplemented!("No syntax for ranges that aren't inclusive on the left and exclusive on the right")
+                    (RangeInclusion::Inclusive, RangeInclusion::Inclusive) => "..=",
+                    (RangeInclusion::Inclusive, RangeInclusion::Exclusive) => "..<",
+                    _ => unimplemented!("No syntax for ranges that aren't inclusive on the left"),
                 };
 
                 left.clone().format().pretty() + b::operator(op) + right.clone().format().pretty()
@@ -267,3 +268,29 @@ impl Into<Column> for &str {
         Column::String(self.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::InlineShape;
+    use nu_protocol::{Primitive, RangeInclusion, UntaggedValue};
+    use nu_source::{PrettyDebug, SpannedItem};
+
+    fn range(to: RangeInclusion) -> UntaggedValue {
+        UntaggedValue::range(
+            (
+                Primitive::Int(1.into()).spanned_unknown(),
+                RangeInclusion::Inclusive,
+            ),
+            (Primitive::Int(3.into()).spanned_unknown(), to),
+        )
+    }
+
+    #[test]
+    fn formats_ranges_with_the_syntax_they_were_written_in() {
+        let inclusive = InlineShape::from_value(&range(RangeInclusion::Inclusive)).format();
+        let exclusive = InlineShape::from_value(&range(RangeInclusion::Exclusive)).format();
+
+        assert_eq!(inclusive.plain_string(100), "1..=3");
+        assert_eq!(exclusive.plain_string(100), "1..<3");
+    }
+}
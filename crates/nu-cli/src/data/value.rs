@@ -7,6 +7,8 @@ use nu_protocol::hir::Operator;
 use nu_protocol::ShellTypeName;
 use nu_protocol::{Primitive, Type, UntaggedValue};
 use nu_source::{DebugDocBuilder, PrettyDebug, Tagged};
+use num_integer::Integer;
+use num_traits::Zero;
 
 pub fn date_from_str(s: Tagged<&str>) -> Result<UntaggedValue, ShellError> {
     let date = DateTime::parse_from_rfc3339(s.item).map_err(|err| {
@@ -19,22 +21,60 @@ pub fn date_from_str(s: Tagged<&str>) -> Result<UntaggedValue, ShellError> {
 
     let date = date.with_timezone(&chrono::offset::Utc);
 
-    Ok(UntaggedValue::Primitive(Primitive::Date(date)))
+    Ok(UntaggedValue::date(date))
+}
+
+/// Why an operator could not compute a value from its operands.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ComputeError {
+    TypeMismatch(&'static str, &'static str),
+    Overflow,
+    DivisionByZero,
+}
+
+impl ComputeError {
+    pub fn type_mismatch(left: &UntaggedValue, right: &UntaggedValue) -> ComputeError {
+        ComputeError::TypeMismatch(left.type_name(), right.type_name())
+    }
+}
+
+impl From<(&'static str, &'static str)> for ComputeError {
+    fn from((left, right): (&'static str, &'static str)) -> ComputeError {
+        ComputeError::TypeMismatch(left, right)
+    }
 }
 
 pub fn compute_values(
     operator: Operator,
     left: &UntaggedValue,
     right: &UntaggedValue,
-) -> Result<UntaggedValue, (&'static str, &'static str)> {
+) -> Result<UntaggedValue, ComputeError> {
     match (left, right) {
         (UntaggedValue::Primitive(lhs), UntaggedValue::Primitive(rhs)) => match (lhs, rhs) {
+            (_, Primitive::Int(y)) if is_division(operator) && y.is_zero() => {
+                Err(ComputeError::DivisionByZero)
+            }
+            (_, Primitive::Decimal(y)) if is_division(operator) && y.is_zero() => {
+                Err(ComputeError::DivisionByZero)
+            }
+            // Text read with `lines` is a line rather than a string, but adds up the same way
+            (Primitive::String(x), Primitive::String(y))
+            | (Primitive::String(x), Primitive::Line(y))
+            | (Primitive::Line(x), Primitive::String(y))
+            | (Primitive::Line(x), Primitive::Line(y)) => match operator {
+                Operator::Plus => Ok(UntaggedValue::Primitive(Primitive::String(format!(
+                    "{}{}",
+                    x, y
+                )))),
+                _ => Err(ComputeError::type_mismatch(left, right)),
+            },
             (Primitive::Bytes(x), Primitive::Bytes(y)) => {
                 let result = match operator {
-                    Operator::Plus => Ok(x + y),
-                    Operator::Minus => Ok(x - y),
-                    _ => Err((left.type_name(), right.type_name())),
-                }?;
+                    Operator::Plus => x.checked_add(*y),
+                    Operator::Minus => x.checked_sub(*y),
+                    _ => return Err(ComputeError::type_mismatch(left, right)),
+                }
+                .ok_or(ComputeError::Overflow)?;
                 Ok(UntaggedValue::Primitive(Primitive::Bytes(result)))
             }
             (Primitive::Int(x), Primitive::Int(y)) => match operator {
@@ -51,7 +91,11 @@ pub fn compute_values(
                         )))
                     }
                 }
-                _ => Err((left.type_name(), right.type_name())),
+                Operator::IntegerDivide => {
+                    Ok(UntaggedValue::Primitive(Primitive::Int(x.div_floor(y))))
+                }
+                Operator::Modulo => Ok(UntaggedValue::Primitive(Primitive::Int(x.mod_floor(y)))),
+                _ => Err(ComputeError::type_mismatch(left, right)),
             },
             (Primitive::Decimal(x), Primitive::Int(y)) => {
                 let result = match operator {
@@ -59,7 +103,7 @@ pub fn compute_values(
                     Operator::Minus => Ok(x - bigdecimal::BigDecimal::from(y.clone())),
                     Operator::Multiply => Ok(x * bigdecimal::BigDecimal::from(y.clone())),
                     Operator::Divide => Ok(x / bigdecimal::BigDecimal::from(y.clone())),
-                    _ => Err((left.type_name(), right.type_name())),
+                    _ => Err(ComputeError::type_mismatch(left, right)),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Decimal(result)))
             }
@@ -69,7 +113,7 @@ pub fn compute_values(
                     Operator::Minus => Ok(bigdecimal::BigDecimal::from(x.clone()) - y),
                     Operator::Multiply => Ok(bigdecimal::BigDecimal::from(x.clone()) * y),
                     Operator::Divide => Ok(bigdecimal::BigDecimal::from(x.clone()) / y),
-                    _ => Err((left.type_name(), right.type_name())),
+                    _ => Err(ComputeError::type_mismatch(left, right)),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Decimal(result)))
             }
@@ -79,37 +123,69 @@ pub fn compute_values(
                     Operator::Minus => Ok(x - y),
                     Operator::Multiply => Ok(x * y),
                     Operator::Divide => Ok(x / y),
-                    _ => Err((left.type_name(), right.type_name())),
+                    _ => Err(ComputeError::type_mismatch(left, right)),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Decimal(result)))
             }
             (Primitive::Date(x), Primitive::Date(y)) => {
                 let result = match operator {
                     Operator::Minus => Ok(x.signed_duration_since(*y).num_seconds()),
-                    _ => Err((left.type_name(), right.type_name())),
+                    _ => Err(ComputeError::type_mismatch(left, right)),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Duration(result)))
             }
             (Primitive::Date(x), Primitive::Duration(y)) => {
                 let result = match operator {
-                    Operator::Plus => Ok(x
+                    Operator::Plus => x
                         .checked_add_signed(chrono::Duration::seconds(*y as i64))
-                        .expect("Overflowing add of duration")),
-                    _ => Err((left.type_name(), right.type_name())),
+                        .ok_or(ComputeError::Overflow),
+                    _ => Err(ComputeError::type_mismatch(left, right)),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Date(result)))
             }
-            _ => Err((left.type_name(), right.type_name())),
+            _ => Err(ComputeError::type_mismatch(left, right)),
+        },
+        (UntaggedValue::Table(x), UntaggedValue::Table(y)) => match operator {
+            Operator::Plus => Ok(UntaggedValue::Table(
+                x.iter().chain(y.iter()).cloned().collect(),
+            )),
+            _ => Err(ComputeError::type_mismatch(left, right)),
         },
-        _ => Err((left.type_name(), right.type_name())),
+        _ => Err(ComputeError::type_mismatch(left, right)),
     }
 }
 
+fn is_division(operator: Operator) -> bool {
+    matches!(
+        operator,
+        Operator::Divide | Operator::IntegerDivide | Operator::Modulo
+    )
+}
+
+/// Compares two values with the given operator.
+///
+/// Nothing only ever equals nothing: `==` and `!=` tell whether both sides are nothing, and the
+/// ordering operators are always false when either side is, rather than an error. That way a
+/// filter like `where size > 10` just leaves out rows missing a size. (Decimals can't be NaN, so
+/// there is no such rule needed for them.)
 pub fn compare_values(
     operator: Operator,
     left: &UntaggedValue,
     right: &UntaggedValue,
 ) -> Result<bool, (&'static str, &'static str)> {
+    let is_nothing =
+        |value: &UntaggedValue| matches!(value, UntaggedValue::Primitive(Primitive::Nothing));
+
+    if is_nothing(left) || is_nothing(right) {
+        let both = is_nothing(left) && is_nothing(right);
+
+        return Ok(match operator {
+            Operator::Equal => both,
+            Operator::NotEqual => !both,
+            _ => false,
+        });
+    }
+
     let coerced = coerce_compare(left, right)?;
     let ordering = coerced.compare();
 
@@ -1,11 +1,13 @@
 use crate::context::CommandRegistry;
+use crate::data::value::ComputeError;
 use crate::evaluate::operator::apply_operator;
 use crate::prelude::*;
 use log::trace;
 use nu_errors::{ArgumentError, ShellError};
 use nu_protocol::hir::{self, Expression, SpannedExpression};
 use nu_protocol::{
-    ColumnPath, Primitive, RangeInclusion, Scope, UnspannedPathMember, UntaggedValue, Value,
+    ColumnPath, Primitive, RangeInclusion, Scope, TaggedDictBuilder, UnspannedPathMember,
+    UntaggedValue, Value,
 };
 
 pub(crate) fn evaluate_baseline_expr(
@@ -41,9 +43,21 @@ pub(crate) fn evaluate_baseline_expr(
                 Expression::Literal(hir::Literal::Operator(op)) => {
                     match apply_operator(op, &left, &right) {
                         Ok(result) => Ok(result.into_value(tag)),
-                        Err((left_type, right_type)) => Err(ShellError::coerce_error(
-                            left_type.spanned(binary.left.span),
-                            right_type.spanned(binary.right.span),
+                        Err(ComputeError::TypeMismatch(left_type, right_type)) => {
+                            Err(ShellError::coerce_error(
+                                left_type.spanned(binary.left.span),
+                                right_type.spanned(binary.right.span),
+                            ))
+                        }
+                        Err(ComputeError::Overflow) => Err(ShellError::labeled_error(
+                            "Integer overflow",
+                            "integer overflow",
+                            binary.left.span.until(binary.right.span),
+                        )),
+                        Err(ComputeError::DivisionByZero) => Err(ShellError::labeled_error(
+                            "Division by zero",
+                            "division by zero",
+                            binary.right.span,
                         )),
                     }
                 }
@@ -63,10 +77,7 @@ pub(crate) fn evaluate_baseline_expr(
                 left.as_primitive()?.spanned(left_span),
                 RangeInclusion::Inclusive,
             );
-            let right = (
-                right.as_primitive()?.spanned(right_span),
-                RangeInclusion::Exclusive,
-            );
+            let right = (right.as_primitive()?.spanned(right_span), range.inclusion);
 
             Ok(UntaggedValue::range(left, right).into_value(tag))
         }
@@ -80,6 +91,16 @@ pub(crate) fn evaluate_baseline_expr(
 
             Ok(UntaggedValue::Table(exprs).into_value(tag))
         }
+        Expression::Row(entries) => {
+            let mut row = TaggedDictBuilder::new(&tag);
+
+            for (key, expr) in entries {
+                let value = evaluate_baseline_expr(expr, registry, scope)?;
+                row.insert_value(key.item.clone(), value);
+            }
+
+            Ok(row.into_value())
+        }
         Expression::Block(block) => Ok(UntaggedValue::Block(block.clone()).into_value(&tag)),
         Expression::Path(path) => {
             let value = evaluate_baseline_expr(&path.head, registry, scope)?;
@@ -89,6 +110,9 @@ pub(crate) fn evaluate_baseline_expr(
                 let next = item.get_data_by_member(member);
 
                 match next {
+                    Err(_) if member.optional => {
+                        return Ok(UntaggedValue::nothing().into_value(&tag));
+                    }
                     Err(err) => {
                         let possibilities = item.data_descriptors();
 
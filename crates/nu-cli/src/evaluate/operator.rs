@@ -1,4 +1,4 @@
-use crate::data::value;
+use crate::data::value::{self, ComputeError};
 use nu_protocol::hir::Operator;
 use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
 use std::ops::Not;
@@ -7,8 +7,8 @@ pub fn apply_operator(
     op: Operator,
     left: &Value,
     right: &Value,
-) -> Result<UntaggedValue, (&'static str, &'static str)> {
-    match op {
+) -> Result<UntaggedValue, ComputeError> {
+    let result = match op {
         Operator::Equal
         | Operator::NotEqual
         | Operator::LessThan
@@ -17,14 +17,16 @@ pub fn apply_operator(
         | Operator::GreaterThanOrEqual => {
             value::compare_values(op, left, right).map(UntaggedValue::boolean)
         }
+        Operator::Plus
+        | Operator::Minus
+        | Operator::Multiply
+        | Operator::Divide
+        | Operator::IntegerDivide
+        | Operator::Modulo => return value::compute_values(op, left, right),
         Operator::Contains => string_contains(left, right).map(UntaggedValue::boolean),
         Operator::NotContains => string_contains(left, right)
             .map(Not::not)
             .map(UntaggedValue::boolean),
-        Operator::Plus => value::compute_values(op, left, right),
-        Operator::Minus => value::compute_values(op, left, right),
-        Operator::Multiply => value::compute_values(op, left, right),
-        Operator::Divide => value::compute_values(op, left, right),
         Operator::In => table_contains(left, right).map(UntaggedValue::boolean),
         Operator::NotIn => table_contains(left, right).map(|x| UntaggedValue::boolean(!x)),
         Operator::And => match (left.as_bool(), right.as_bool()) {
@@ -35,7 +37,9 @@ pub fn apply_operator(
             (Ok(left), Ok(right)) => Ok(UntaggedValue::boolean(left || right)),
             _ => Err((left.type_name(), right.type_name())),
         },
-    }
+    };
+
+    result.map_err(ComputeError::from)
 }
 
 fn string_contains(
@@ -70,6 +74,8 @@ fn table_contains(
     let left = left.clone();
     match right {
         UntaggedValue::Table(values) => Ok(values.iter().any(|x| x.value == left)),
+        UntaggedValue::Primitive(Primitive::String(_))
+        | UntaggedValue::Primitive(Primitive::Line(_)) => string_contains(right, &left),
         _ => Err((left.type_name(), right.type_name())),
     }
 }
@@ -26,19 +26,28 @@ enum TableMode {
 }
 
 impl TableView {
-    pub fn from_list(values: &[Value], starting_idx: usize) -> Option<TableView> {
+    /// The table for `values`, fit to `termwidth` by dropping the columns that don't fit and
+    /// wrapping long cells. Without a width, every column is shown in full.
+    pub fn from_list(
+        values: &[Value],
+        starting_idx: usize,
+        termwidth: Option<usize>,
+    ) -> Option<TableView> {
         if values.is_empty() {
             return None;
         }
 
-        // Different platforms want different amounts of buffer, not sure why
-        let termwidth = std::cmp::max(textwrap::termwidth(), 20);
-
         let mut headers = nu_protocol::merge_descriptors(values);
         let mut entries = values_to_entries(values, &mut headers, starting_idx);
-        let max_per_column = max_per_column(&headers, &entries, values.len());
+
+        let termwidth = match termwidth {
+            // Different platforms want different amounts of buffer, not sure why
+            Some(termwidth) => std::cmp::max(termwidth, 20),
+            None => return Some(TableView { headers, entries }),
+        };
 
         maybe_truncate_columns(&mut headers, &mut entries, termwidth);
+        let max_per_column = max_per_column(&headers, &entries, values.len());
         let headers_len = headers.len();
 
         // Measure how big our columns need to be (accounting for separators also)
@@ -170,13 +179,14 @@ fn maybe_truncate_columns(headers: &mut Vec<String>, entries: &mut Entries, term
 
     // If we have too many columns, truncate the table
     if max_num_of_columns < headers.len() {
+        let dropped = headers.len() - max_num_of_columns;
         headers.truncate(max_num_of_columns);
 
         for entry in entries.iter_mut() {
             entry.truncate(max_num_of_columns);
         }
 
-        headers.push("...".to_owned());
+        headers.push(format!("...{} more", dropped));
 
         for entry in entries.iter_mut() {
             entry.push(("...".to_owned(), "c")); // ellipsis is centred
@@ -445,3 +455,38 @@ fn str_to_style(s: String) -> Option<Attr> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TableView;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn wide_row() -> Vec<Value> {
+        let mut row = TaggedDictBuilder::new(Tag::unknown());
+
+        for column in 1..=12 {
+            row.insert_untagged(format!("c{:02}", column), UntaggedValue::int(column));
+        }
+
+        vec![row.into_value()]
+    }
+
+    #[test]
+    fn drops_the_columns_wider_than_the_terminal() {
+        let view = TableView::from_list(&wide_row(), 0, Some(40)).unwrap();
+        // The headers are the index column, the columns that fit, and the note of what was dropped
+        let dropped = 12 - (view.headers.len() - 2);
+
+        assert_eq!(view.headers.last(), Some(&format!("...{} more", dropped)));
+        assert!(!view.headers.contains(&"c12".to_string()));
+    }
+
+    #[test]
+    fn expand_shows_every_column() {
+        let view = TableView::from_list(&wide_row(), 0, None).unwrap();
+
+        assert_eq!(view.headers.len(), 13);
+        assert_eq!(view.headers.last(), Some(&"c12".to_string()));
+    }
+}
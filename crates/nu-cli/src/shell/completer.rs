@@ -1,109 +1,274 @@
 use crate::context::CommandRegistry;
 
 use derive_new::new;
-use rustyline::completion::{Completer, FilenameCompleter};
-use std::path::PathBuf;
+use indexmap::{IndexMap, IndexSet};
+use nu_protocol::{PositionalType, SyntaxShape, Value};
+use parking_lot::Mutex;
+use std::path::{is_separator, Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(new)]
 pub(crate) struct NuCompleter {
-    pub file_completer: FilenameCompleter,
     pub commands: CommandRegistry,
     pub homedir: Option<PathBuf>,
 }
 
+/// What kind of word the cursor is sitting on, judged from the text before it.
+#[derive(Debug, PartialEq)]
+pub(crate) enum CompletionLocation {
+    /// The first word of a pipeline stage, where a command name goes
+    Command,
+    /// A word starting with `-`, which can only be a flag
+    Flag,
+    /// Any later word, along with the name of the command it is an argument to
+    Argument(String),
+}
+
+/// Splits the line at the cursor into the position where the word under the cursor starts,
+/// and the kind of word it is.
+pub(crate) fn completion_location(line: &str, pos: usize) -> (usize, CompletionLocation) {
+    let before = &line[..pos];
+    let replace_pos = before.rfind(' ').map(|idx| idx + 1).unwrap_or(0);
+
+    if before[replace_pos..].starts_with('-') {
+        return (replace_pos, CompletionLocation::Flag);
+    }
+
+    let stage_start = before[..replace_pos]
+        .rfind(|c| c == '|' || c == ';')
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+
+    match before[stage_start..replace_pos].split_whitespace().next() {
+        Some(command) => (
+            replace_pos,
+            CompletionLocation::Argument(command.to_string()),
+        ),
+        None => (replace_pos, CompletionLocation::Command),
+    }
+}
+
+/// The command names starting with `prefix`, in alphabetical order.
+pub(crate) fn command_completions(prefix: &str, names: &[String]) -> Vec<String> {
+    let mut completions: Vec<String> = names
+        .iter()
+        .filter(|name| name.starts_with(prefix))
+        .cloned()
+        .collect();
+
+    completions.sort();
+    completions
+}
+
+/// The files and directories that `partial` could be completed to, resolving relative paths
+/// against `cwd` and a leading `~` against `homedir`. Directories end in a separator so that
+/// completion can carry on into them.
+pub(crate) fn path_completions(partial: &str, cwd: &Path, homedir: Option<&Path>) -> Vec<String> {
+    let (dir_part, file_prefix) = match partial.rfind(is_separator) {
+        Some(idx) => (&partial[..=idx], &partial[idx + 1..]),
+        None => ("", partial),
+    };
+
+    let base = if dir_part.starts_with('~') {
+        match homedir {
+            Some(home) => home.join(dir_part[1..].trim_start_matches(is_separator)),
+            None => return vec![],
+        }
+    } else {
+        cwd.join(dir_part)
+    };
+
+    let entries = match base.read_dir() {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut completions: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if !name.starts_with(file_prefix) || (name.starts_with('.') && file_prefix.is_empty()) {
+                return None;
+            }
+
+            let is_dir = entry.file_type().map(|ty| ty.is_dir()).unwrap_or(false);
+            let separator = if is_dir {
+                std::path::MAIN_SEPARATOR.to_string()
+            } else {
+                String::new()
+            };
+
+            Some(format!("{}{}{}", dir_part, name, separator))
+        })
+        .collect();
+
+    completions.sort();
+    completions
+}
+
+/// The commands whose first argument names a column of their input.
+const COLUMN_COMMANDS: [&str; 3] = ["get", "pick", "where"];
+
+/// The columns each command produced the last time it ended a pipeline, so that a later
+/// `get`/`pick`/`where` after the same command can complete them. Commands are keyed by their
+/// whole stage, arguments included, since eg) `open a.csv` and `open b.csv` differ in columns.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ColumnCache {
+    columns: Arc<Mutex<IndexMap<String, IndexSet<String>>>>,
+}
+
+/// How many rows of a pipeline's output are looked at for its columns. Rows after these rarely
+/// bring new ones, and looking at every row would slow down large outputs.
+pub(crate) const OBSERVED_ROWS: usize = 100;
+
+impl ColumnCache {
+    /// Forgets what `command` produced before, ahead of running it again.
+    pub(crate) fn reset(&self, command: &str) {
+        self.columns
+            .lock()
+            .insert(stage_key(command), IndexSet::new());
+    }
+
+    /// Adds any columns of `value` that haven't been seen yet for `command`.
+    pub(crate) fn observe(&self, command: &str, value: &Value) {
+        let mut columns = self.columns.lock();
+        let known = columns
+            .entry(stage_key(command))
+            .or_insert_with(IndexSet::new);
+
+        known.extend(value.data_descriptors());
+    }
+
+    /// The column completions for the word under the cursor, if it is the first argument of a
+    /// column-taking command whose upstream columns are known.
+    pub(crate) fn complete(&self, line: &str, pos: usize) -> Option<(usize, Vec<String>)> {
+        let (replace_pos, location) = completion_location(line, pos);
+
+        let command = match location {
+            CompletionLocation::Argument(command) => command,
+            _ => return None,
+        };
+
+        if !COLUMN_COMMANDS.contains(&command.as_str()) {
+            return None;
+        }
+
+        let stage_start = line[..replace_pos].rfind('|')?;
+
+        if line[stage_start + 1..replace_pos]
+            .split_whitespace()
+            .count()
+            != 1
+        {
+            return None;
+        }
+
+        let upstream = line[..stage_start]
+            .rsplit(|c| c == '|' || c == ';')
+            .next()
+            .map(stage_key)?;
+
+        let columns = self.columns.lock();
+        let known = columns.get(&upstream).filter(|known| !known.is_empty())?;
+        let partial = &line[replace_pos..pos];
+
+        Some((
+            replace_pos,
+            known
+                .iter()
+                .filter(|column| column.starts_with(partial))
+                .cloned()
+                .collect(),
+        ))
+    }
+}
+
+/// The same stage written with different spacing shares its columns
+fn stage_key(stage: &str) -> String {
+    stage.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 impl NuCompleter {
     pub fn complete(
         &self,
         line: &str,
         pos: usize,
-        context: &rustyline::Context,
+        _context: &rustyline::Context,
     ) -> rustyline::Result<(usize, Vec<rustyline::completion::Pair>)> {
-        let commands: Vec<String> = self.commands.names();
-
-        let line_chars: Vec<_> = line[..pos].chars().collect();
-
-        let mut replace_pos = line_chars.len();
-        while replace_pos > 0 {
-            if line_chars[replace_pos - 1] == ' ' {
-                break;
-            }
-            replace_pos -= 1;
-        }
+        let (replace_pos, location) = completion_location(line, pos);
+        let partial = &line[replace_pos..pos];
 
-        let mut completions;
+        let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let homedir = self.homedir.as_deref();
 
-        // See if we're a flag
-        if pos > 0 && replace_pos < line_chars.len() && line_chars[replace_pos] == '-' {
-            if let Ok(lite_block) = nu_parser::lite_parse(line, 0) {
-                completions =
-                    self.get_matching_arguments(&lite_block, &line_chars, line, replace_pos, pos);
-            } else {
-                completions = self.file_completer.complete(line, pos, context)?.1;
-            }
-        } else {
-            completions = self.file_completer.complete(line, pos, context)?.1;
+        let completions = match location {
+            CompletionLocation::Flag => match nu_parser::lite_parse(line, 0) {
+                Ok(lite_block) => self.get_matching_arguments(&lite_block, partial, replace_pos),
+                Err(_) => vec![],
+            },
+            CompletionLocation::Command => {
+                let mut completions: Vec<_> = command_completions(partial, &self.commands.names())
+                    .into_iter()
+                    .map(|command| rustyline::completion::Pair {
+                        display: command.clone(),
+                        replacement: command,
+                    })
+                    .collect();
 
-            for completion in &mut completions {
-                if completion.replacement.contains("\\ ") {
-                    completion.replacement = completion.replacement.replace("\\ ", " ");
-                }
-                if completion.replacement.contains("\\(") {
-                    completion.replacement = completion.replacement.replace("\\(", "(");
+                // Paths can also start a pipeline, to run a script or to change into a directory
+                if partial.starts_with('.') || partial.starts_with('~') || partial.contains('/') {
+                    completions.extend(path_pairs(path_completions(partial, &cwd, homedir)));
                 }
 
-                if completion.replacement.contains(' ') || completion.replacement.contains('(') {
-                    if !completion.replacement.starts_with('\"') {
-                        completion.replacement = format!("\"{}", completion.replacement);
-                    }
-                    if !completion.replacement.ends_with('\"') {
-                        completion.replacement = format!("{}\"", completion.replacement);
-                    }
+                completions
+            }
+            CompletionLocation::Argument(command) => {
+                if self.takes_path(&command) {
+                    path_pairs(path_completions(partial, &cwd, homedir))
+                } else {
+                    vec![]
                 }
             }
         };
 
-        for command in commands.iter() {
-            let mut pos = replace_pos;
-            let mut matched = false;
-            if pos < line_chars.len() {
-                for chr in command.chars() {
-                    if line_chars[pos] != chr {
-                        break;
-                    }
-                    pos += 1;
-                    if pos == line_chars.len() {
-                        matched = true;
-                        break;
-                    }
-                }
-            }
+        Ok((replace_pos, completions))
+    }
 
-            if matched {
-                completions.push(rustyline::completion::Pair {
-                    display: command.clone(),
-                    replacement: command.clone(),
-                });
-            }
-        }
+    /// Whether the arguments of `command` are worth completing as paths. Externals could take
+    /// anything, so they always are.
+    fn takes_path(&self, command: &str) -> bool {
+        let signature = match self.commands.get_command(command) {
+            Some(command) => command.signature(),
+            None => return true,
+        };
 
-        Ok((replace_pos, completions))
+        let is_path =
+            |shape: &SyntaxShape| matches!(shape, SyntaxShape::Path | SyntaxShape::Pattern);
+
+        signature
+            .positional
+            .iter()
+            .any(|(positional, _)| match positional {
+                PositionalType::Mandatory(_, shape) | PositionalType::Optional(_, shape) => {
+                    is_path(shape)
+                }
+            })
+            || signature
+                .rest_positional
+                .as_ref()
+                .map(|(shape, _)| is_path(shape))
+                .unwrap_or(false)
     }
 
     fn get_matching_arguments(
         &self,
         lite_block: &nu_parser::LiteBlock,
-        line_chars: &[char],
-        line: &str,
+        substring: &str,
         replace_pos: usize,
-        pos: usize,
     ) -> Vec<rustyline::completion::Pair> {
         let mut matching_arguments = vec![];
 
-        let mut line_copy = line.to_string();
-        let substring = line_chars[replace_pos..pos].iter().collect::<String>();
-        let replace_string = (replace_pos..pos).map(|_| " ").collect::<String>();
-        line_copy.replace_range(replace_pos..pos, &replace_string);
-
         let result = nu_parser::classify_block(&lite_block, &self.commands);
 
         for pipeline in &result.block.block {
@@ -117,7 +282,7 @@ impl NuCompleter {
                             for (name, _) in named.iter() {
                                 let full_flag = format!("--{}", name);
 
-                                if full_flag.starts_with(&substring) {
+                                if full_flag.starts_with(substring) {
                                     matching_arguments.push(rustyline::completion::Pair {
                                         display: full_flag.clone(),
                                         replacement: full_flag,
@@ -133,3 +298,137 @@ impl NuCompleter {
         matching_arguments
     }
 }
+
+fn path_pairs(paths: Vec<String>) -> Vec<rustyline::completion::Pair> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let replacement = if path.contains(' ') || path.contains('(') {
+                format!("\"{}\"", path)
+            } else {
+                path.clone()
+            };
+
+            rustyline::completion::Pair {
+                display: path,
+                replacement,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        command_completions, completion_location, path_completions, ColumnCache, CompletionLocation,
+    };
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue};
+
+    #[test]
+    fn finds_completion_location() {
+        assert_eq!(
+            completion_location("wh", 2),
+            (0, CompletionLocation::Command)
+        );
+        assert_eq!(
+            completion_location("ls | wh", 7),
+            (5, CompletionLocation::Command)
+        );
+        assert_eq!(
+            completion_location("open fo", 7),
+            (5, CompletionLocation::Argument("open".to_string()))
+        );
+        assert_eq!(
+            completion_location("ls --fu", 7),
+            (3, CompletionLocation::Flag)
+        );
+    }
+
+    #[test]
+    fn completes_command_names_from_prefix() {
+        let names = vec![
+            "where".to_string(),
+            "ls".to_string(),
+            "which".to_string(),
+            "wrap".to_string(),
+        ];
+
+        assert_eq!(
+            command_completions("wh", &names),
+            vec!["where".to_string(), "which".to_string()]
+        );
+        assert!(command_completions("xyz", &names).is_empty());
+    }
+
+    #[test]
+    fn completes_paths_in_directory() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("notes.txt"), "")?;
+        std::fs::write(dir.path().join("numbers.csv"), "")?;
+        std::fs::write(dir.path().join("other.txt"), "")?;
+        std::fs::create_dir(dir.path().join("nested"))?;
+        std::fs::write(dir.path().join("nested").join("inner.txt"), "")?;
+
+        let sep = std::path::MAIN_SEPARATOR;
+
+        assert_eq!(
+            path_completions("n", dir.path(), None),
+            vec![
+                format!("nested{}", sep),
+                "notes.txt".to_string(),
+                "numbers.csv".to_string()
+            ]
+        );
+        assert_eq!(
+            path_completions("nested/i", dir.path(), None),
+            vec!["nested/inner.txt".to_string()]
+        );
+        assert!(path_completions("missing/", dir.path(), None).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn completes_columns_of_upstream_command() {
+        let columns = ColumnCache::default();
+
+        let mut row = TaggedDictBuilder::new(nu_source::Tag::unknown());
+        row.insert_untagged("name", UntaggedValue::string("Cargo.toml"));
+        row.insert_untagged("type", UntaggedValue::string("File"));
+        row.insert_untagged("size", UntaggedValue::string("4 KB"));
+
+        columns.reset("ls");
+        columns.observe("ls", &row.into_value());
+
+        assert_eq!(
+            columns.complete("ls | get n", 10),
+            Some((9, vec!["name".to_string()]))
+        );
+        assert_eq!(
+            columns.complete("ls | where ", 11),
+            Some((
+                11,
+                vec!["name".to_string(), "type".to_string(), "size".to_string()]
+            ))
+        );
+        assert_eq!(columns.complete("ps | get n", 10), None);
+        assert_eq!(columns.complete("ls | get name n", 15), None);
+    }
+
+    #[test]
+    fn keeps_columns_apart_for_different_arguments() {
+        let columns = ColumnCache::default();
+
+        let mut row = TaggedDictBuilder::new(nu_source::Tag::unknown());
+        row.insert_untagged("name", UntaggedValue::string("Cargo.toml"));
+
+        columns.reset("open a.csv");
+        columns.observe("open a.csv", &row.into_value());
+
+        assert_eq!(
+            columns.complete("open  a.csv | get n", 19),
+            Some((18, vec!["name".to_string()]))
+        );
+        assert_eq!(columns.complete("open b.csv | get n", 18), None);
+    }
+}
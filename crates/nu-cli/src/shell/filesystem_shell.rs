@@ -12,7 +12,6 @@ use crate::shell::completer::NuCompleter;
 use crate::shell::shell::Shell;
 use crate::utils::FileStructure;
 
-use rustyline::completion::FilenameCompleter;
 use rustyline::hint::{Hinter, HistoryHinter};
 use std::collections::HashMap;
 use std::path::{Component, Path, PathBuf};
@@ -44,7 +43,6 @@ impl Clone for FilesystemShell {
             path: self.path.clone(),
             last_path: self.path.clone(),
             completer: NuCompleter {
-                file_completer: FilenameCompleter::new(),
                 commands: self.completer.commands.clone(),
                 homedir: self.homedir(),
             },
@@ -61,7 +59,6 @@ impl FilesystemShell {
             path: path.to_string_lossy().to_string(),
             last_path: path.to_string_lossy().to_string(),
             completer: NuCompleter {
-                file_completer: FilenameCompleter::new(),
                 commands,
                 homedir: dirs::home_dir(),
             },
@@ -81,7 +78,6 @@ impl FilesystemShell {
             path,
             last_path,
             completer: NuCompleter {
-                file_completer: FilenameCompleter::new(),
                 commands,
                 homedir: dirs::home_dir(),
             },
@@ -6,6 +6,7 @@ use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
 use crate::commands::rm::RemoveArgs;
 use crate::prelude::*;
+use crate::shell::completer::ColumnCache;
 use crate::shell::filesystem_shell::FilesystemShell;
 use crate::shell::shell::Shell;
 use crate::stream::OutputStream;
@@ -20,6 +21,7 @@ use std::sync::Arc;
 pub struct ShellManager {
     pub(crate) current_shell: Arc<AtomicUsize>,
     pub(crate) shells: Arc<Mutex<Vec<Box<dyn Shell + Send>>>>,
+    pub(crate) columns: ColumnCache,
 }
 
 impl ShellManager {
@@ -29,6 +31,7 @@ impl ShellManager {
             shells: Arc::new(Mutex::new(vec![Box::new(FilesystemShell::basic(
                 commands,
             )?)])),
+            columns: ColumnCache::default(),
         })
     }
 
@@ -87,6 +90,19 @@ impl ShellManager {
         pos: usize,
         ctx: &rustyline::Context<'_>,
     ) -> Result<(usize, Vec<rustyline::completion::Pair>), rustyline::error::ReadlineError> {
+        if let Some((replace_pos, columns)) = self.columns.complete(line, pos) {
+            return Ok((
+                replace_pos,
+                columns
+                    .into_iter()
+                    .map(|column| rustyline::completion::Pair {
+                        display: column.clone(),
+                        replacement: column,
+                    })
+                    .collect(),
+            ));
+        }
+
         self.shells.lock()[self.current_shell()].complete(line, pos, ctx)
     }
 
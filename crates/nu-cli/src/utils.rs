@@ -5,6 +5,31 @@ use nu_errors::ShellError;
 use nu_protocol::{UntaggedValue, Value};
 use std::path::{Component, Path, PathBuf};
 
+/// The name in `names` that `name` was most likely meant to be: the nearest one no more than
+/// a third of its length in edits away, or else the shortest one that starts with it.
+pub fn closest_name<'a>(name: &str, names: &'a [String]) -> Option<&'a str> {
+    let most_edits = (name.chars().count() + 2) / 3;
+
+    names
+        .iter()
+        .map(|candidate| {
+            (
+                natural::distance::levenshtein_distance(candidate, name),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= most_edits)
+        .min()
+        .map(|(_, candidate)| candidate)
+        .or_else(|| {
+            names
+                .iter()
+                .filter(|candidate| !name.is_empty() && candidate.starts_with(name))
+                .min_by_key(|candidate| candidate.len())
+        })
+        .map(|candidate| &candidate[..])
+}
+
 fn is_value_tagged_dir(value: &Value) -> bool {
     match &value.value {
         UntaggedValue::Row(_) | UntaggedValue::Table(_) => true,
@@ -168,7 +193,7 @@ impl FileStructure {
 
 #[cfg(test)]
 mod tests {
-    use super::{FileStructure, Res, ValueResource, ValueStructure};
+    use super::{closest_name, FileStructure, Res, ValueResource, ValueStructure};
     use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
     use nu_source::Tag;
     use nu_test_support::fs;
@@ -179,6 +204,15 @@ mod tests {
         dunce::canonicalize(fixtures).expect("Wrong path")
     }
 
+    #[test]
+    fn suggests_the_closest_name() {
+        let names = vec!["where".to_string(), "which".to_string(), "wrap".to_string()];
+
+        assert_eq!(closest_name("whre", &names), Some("where"));
+        assert_eq!(closest_name("wh", &names), Some("where"));
+        assert_eq!(closest_name("xyzzyqq", &names), None);
+    }
+
     fn structured_sample_record(key: &str, value: &str) -> Value {
         let mut record = TaggedDictBuilder::new(Tag::unknown());
         record.insert_untagged(key, UntaggedValue::string(value));
@@ -293,6 +327,10 @@ mod tests {
                     loc: fixtures().join("jonathan.xml"),
                     at: 0
                 },
+                Res {
+                    loc: fixtures().join("latin1.txt"),
+                    at: 0
+                },
                 Res {
                     loc: fixtures().join("sample.bson"),
                     at: 0
@@ -28,8 +28,9 @@ pub fn columns_sorted(
                     let date = NaiveDate::parse_from_str(k, "%B %d-%Y");
 
                     let date = match date {
-                        Ok(parsed) => UntaggedValue::Primitive(Primitive::Date(
-                            DateTime::<Utc>::from_utc(parsed.and_hms(12, 34, 56), Utc),
+                        Ok(parsed) => UntaggedValue::date(DateTime::<Utc>::from_utc(
+                            parsed.and_hms(12, 34, 56),
+                            Utc,
                         )),
                         Err(_) => UntaggedValue::string(k),
                     };
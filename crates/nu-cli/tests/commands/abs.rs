@@ -0,0 +1,48 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn takes_absolute_value_of_negative_integer() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"x": -5}]'
+            | from-json
+            | abs x
+            | get x
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "5");
+}
+
+#[test]
+fn takes_absolute_value_of_negative_decimal() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"x": -2.5}]'
+            | from-json
+            | get x
+            | abs
+            | where $it == 2.5
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn errors_on_non_numeric_input() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "hello"
+            | abs
+        "#
+    ));
+
+    assert!(actual.contains("Can't take the absolute value of string"));
+}
@@ -0,0 +1,77 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn sums_each_group() {
+    Playground::setup("aggregate_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_luck,type
+                Andrés,Robalino,1,A
+                Jonathan,Turner,2,B
+                Yehuda,Katz,3,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv --raw
+                | from-csv --infer
+                | group-by type --to-table
+                | aggregate { get rusty_luck | sum } --as total
+                | to-json
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"group":"A","total":4},{"group":"B","total":2}]"#
+        );
+    })
+}
+
+#[test]
+fn counts_each_group() {
+    Playground::setup("aggregate_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_luck,type
+                Andrés,Robalino,1,A
+                Jonathan,Turner,2,B
+                Yehuda,Katz,3,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by type --to-table
+                | aggregate { count }
+                | where group == A
+                | get value
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+    })
+}
+
+#[test]
+fn errors_without_grouped_rows() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés"}]'
+            | from-json
+            | aggregate { count }
+        "#
+    ));
+
+    assert!(actual.contains("Expected groups from group-by --to-table"));
+}
@@ -0,0 +1,57 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn checks_all_rows_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 4 6]
+            | wrap number
+            | all? number > 0
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn stops_at_the_first_row_not_matching() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 -1 "not a number"]
+            | wrap number
+            | all? number > 0
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "false");
+}
+
+#[test]
+fn is_true_without_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | all? number > 0
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn errors_without_a_condition() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2] | wrap number | all? { echo $it | count }
+        "#
+    ));
+
+    assert!(actual.contains("Expected a condition"));
+}
@@ -0,0 +1,60 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn finds_a_matching_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 5 2]
+            | wrap number
+            | any? number > 4
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn stops_at_the_first_matching_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [5 "not a number"]
+            | wrap number
+            | any? number > 4
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn is_false_when_nothing_matches() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | wrap number
+            | any? number > 4
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "false");
+}
+
+#[test]
+fn is_false_without_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | any? number > 4
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "false");
+}
@@ -0,0 +1,44 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn puts_values_under_the_given_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | as number
+            | get number
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "6");
+}
+
+#[test]
+fn gives_the_same_table_as_wrap() {
+    let with_as = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Andrés Jonathan Yehuda]
+            | as name
+            | to-json
+        "#
+    ));
+
+    let with_wrap = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Andrés Jonathan Yehuda]
+            | wrap name
+            | to-json
+        "#
+    ));
+
+    assert_eq!(with_as, with_wrap);
+    assert_eq!(
+        with_as,
+        r#"[{"name":"Andrés"},{"name":"Jonathan"},{"name":"Yehuda"}]"#
+    );
+}
@@ -0,0 +1,42 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn encodes_a_string() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "foobar"
+            | base32
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "MZXW6YTBOI======");
+}
+
+#[test]
+fn decodes_a_string() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "MZXW6YTBOI======"
+            | base32 --decode
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "foobar");
+}
+
+#[test]
+fn errors_decoding_malformed_input() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "MZXW6YT!"
+            | base32 --decode
+        "#
+    ));
+
+    assert!(actual.contains("Could not decode base32"));
+}
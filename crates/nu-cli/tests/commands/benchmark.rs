@@ -0,0 +1,60 @@
+use nu_test_support::{nu, nu_error, pipeline};
+use std::time::{Duration, Instant};
+
+#[test]
+fn reports_a_positive_duration() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark { sleep 1s }
+            | get duration
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    let seconds: i64 = actual.parse().expect("duration in seconds");
+    assert!(seconds >= 1);
+}
+
+#[test]
+fn keeps_the_output_of_the_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark { echo "nu" }
+            | get output
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nu");
+}
+
+#[test]
+fn runs_the_block_once_per_round() {
+    let start = Instant::now();
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark --rounds 2 { sleep 1s }
+            | get duration
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+    assert!(start.elapsed() >= Duration::from_secs(2));
+}
+
+#[test]
+fn errors_on_more_rounds_than_it_can_average() {
+    let actual = nu_error!(
+        cwd: ".",
+        "benchmark --rounds 5000000000 { echo nu }"
+    );
+
+    assert!(actual.contains("Too many rounds to benchmark"));
+}
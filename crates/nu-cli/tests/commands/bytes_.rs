@@ -0,0 +1,44 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn counts_the_bytes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.bson --raw
+            | bytes --length
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "561");
+}
+
+#[test]
+fn slices_the_bytes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.bson --raw
+            | bytes --at "4,8"
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"$binary":"B19pZA=="}"#);
+}
+
+#[test]
+fn clamps_slices_past_the_end() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.bson --raw
+            | bytes --at "555,1000" --length
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "6");
+}
@@ -0,0 +1,75 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn drops_rows_without_a_number() {
+    Playground::setup("clean_numbers_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.csv",
+            r#"
+                sensor,reading
+                a,1.5
+                b,NaN
+                c,inf
+                d,broken
+                e,4
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open readings.csv --raw
+                | from-csv --infer
+                | clean-numbers reading
+                | get sensor
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["a","e"]"#);
+    })
+}
+
+#[test]
+fn flags_rows_without_a_number() {
+    Playground::setup("clean_numbers_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "readings.csv",
+            r#"
+                sensor,reading
+                a,1.5
+                b,NaN
+                c,4
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open readings.csv --raw
+                | from-csv --infer
+                | clean-numbers reading --flag
+                | get invalid
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, "[false,true,false]");
+    })
+}
+
+#[test]
+fn errors_when_asked_to_drop_and_flag() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"reading": 1}]'
+            | from-json
+            | clean-numbers reading --drop --flag
+        "#
+    ));
+
+    assert!(actual.contains("either drop or flag rows"));
+}
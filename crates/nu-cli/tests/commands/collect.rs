@@ -0,0 +1,87 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn collects_the_stream_into_one_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | collect
+            | count
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn keeps_the_values_in_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | collect
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
+
+#[test]
+fn runs_the_block_over_the_collected_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | collect { = $it.2 }
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn a_list_at_the_head_of_a_pipeline_streams_its_items() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            = [1 2 3]
+            | count
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn collect_then_flatten_gives_back_the_stream() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            = [1 2 3]
+            | collect
+            | flatten
+            | each { = $it * 2 }
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[2,4,6]");
+}
+
+#[test]
+fn flatten_then_collect_gives_back_the_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | collect
+            | flatten
+            | collect
+            | each { = $it.1 }
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+}
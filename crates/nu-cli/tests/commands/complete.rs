@@ -0,0 +1,32 @@
+use nu_test_support::{nu, pipeline};
+
+#[cfg(not(windows))]
+#[test]
+fn captures_stdout_and_stderr() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            complete { sh -c "echo out; echo err 1>&2" }
+            | pick stdout stderr
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"stdout":"out\n","stderr":"err\n"}"#);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn captures_a_nonzero_exit_code() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            complete { sh -c "exit 3" }
+            | get exit_code
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
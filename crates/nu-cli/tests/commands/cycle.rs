@@ -0,0 +1,31 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn repeats_the_rows_until_first_has_enough() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | cycle
+            | first 7
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,1,2,3,1]");
+}
+
+#[test]
+fn repeats_a_single_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo nu
+            | cycle
+            | first 3
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["nu","nu","nu"]"#);
+}
@@ -0,0 +1,41 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn formats_dates_with_a_common_pattern() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-02-27 2020-02-28
+            | date-format "%Y/%m/%d"
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["2020/02/27","2020/02/28"]"#);
+}
+
+#[test]
+fn formats_weekday_and_month_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-02-29T13:45:00Z"
+            | date-format "%A, %B %e %H:%M"
+        "#
+    ));
+
+    assert_eq!(actual, "Saturday, February 29 13:45");
+}
+
+#[test]
+fn errors_on_an_invalid_pattern() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-02-27 2020-02-27
+            | date-format "%Y-%Q"
+        "#
+    ));
+
+    assert!(actual.contains("Invalid date format"));
+}
@@ -0,0 +1,74 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn converts_utc_to_the_given_timezone() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-02-29T13:45:00Z"
+            | date-to-timezone America/New_York
+            | date-format "%Y-%m-%d %H:%M"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2020-02-29 08:45");
+}
+
+#[test]
+fn keeps_the_same_instant() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-02-29T13:45:00Z"
+            | date-to-timezone Asia/Tokyo
+            | date-format "%s"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1582983900");
+}
+
+#[test]
+fn carries_the_offset_of_the_timezone() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-02-29T13:45:00Z"
+            | date-to-timezone America/New_York
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#""2020-02-29T08:45:00-05:00""#);
+}
+
+#[test]
+fn moves_to_the_previous_day_behind_utc() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-02-28 2020-02-28
+            | date-to-timezone Pacific/Honolulu
+            | date-format "%d"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "27");
+}
+
+#[test]
+fn errors_with_hints_on_an_unknown_timezone() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-02-29T13:45:00Z"
+            | date-to-timezone America/New_Yrok
+        "#
+    ));
+
+    assert!(actual.contains("Unknown timezone"));
+    assert!(actual.contains("America/New_York"));
+}
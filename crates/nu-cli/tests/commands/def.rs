@@ -0,0 +1,59 @@
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error};
+
+#[test]
+fn defines_a_command_with_one_parameter() {
+    Playground::setup("def_test_1", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.root(),
+            r#"
+                def greet [name] {echo $name}
+                greet nushell | echo $it
+            "#
+        );
+
+        assert_eq!(actual, "nushell");
+    })
+}
+
+#[test]
+fn errors_when_an_argument_is_missing() {
+    Playground::setup("def_test_2", |dirs, _| {
+        let actual = nu_error!(
+            cwd: dirs.root(),
+            r#"
+                def greet [name] {echo $name}
+                greet
+            "#
+        );
+
+        assert!(actual.contains("requires name parameter"));
+    })
+}
+
+#[test]
+fn binds_typed_optional_and_flag_parameters() {
+    Playground::setup("def_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.root(),
+            r#"
+                def greet [name: string, times?: int, --loud] {echo $name $times $loud}
+                greet nushell 2 --loud | to-json
+            "#
+        );
+
+        assert_eq!(actual, r#"["nushell",2,true]"#);
+    })
+}
+
+#[test]
+fn errors_on_an_unknown_parameter_type() {
+    Playground::setup("def_test_4", |dirs, _| {
+        let actual = nu_error!(
+            cwd: dirs.root(),
+            r#"def greet [name: strng] {echo $name}"#
+        );
+
+        assert!(actual.contains("Expected type"));
+    })
+}
@@ -9,3 +9,77 @@ fn drop_rows() {
 
     assert_eq!(actual, "3");
 }
+
+#[test]
+fn drops_the_last_row_by_default() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"foo": 3}, {"foo": 8}, {"foo": 4}]' | from-json | drop | get foo | sum | echo $it"#
+    );
+
+    assert_eq!(actual, "11");
+}
+
+#[test]
+fn drops_every_row_when_more_rows_requested_than_present() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"foo": 3}, {"foo": 8}, {"foo": 4}]' | from-json | drop 5 | count | echo $it"#
+    );
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn drops_the_last_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"a": 1, "b": 2, "c": 3}]' | from-json | drop --column | to-json | echo $it"#
+    );
+
+    assert_eq!(actual, r#"{"a":1,"b":2}"#);
+}
+
+#[test]
+fn drops_every_column_when_more_columns_requested_than_present() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"a": 1, "b": 2}]' | from-json | drop 5 --column | to-json | echo $it"#
+    );
+
+    assert_eq!(actual, "{}");
+}
+
+#[test]
+fn drops_the_first_rows_when_negative() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [1 2 3 4 5] | drop -2 | to-json | echo $it"#
+    );
+
+    assert_eq!(actual, "[3,4,5]");
+}
+
+#[test]
+fn drops_the_first_columns_when_negative() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo '[{"a": 1, "b": 2, "c": 3}]' | from-json | drop -1 --column | to-json | echo $it"#
+    );
+
+    assert_eq!(actual, r#"{"b":2,"c":3}"#);
+}
+
+#[test]
+fn negative_take_matches_a_positive_drop() {
+    let take = nu!(
+        cwd: ".",
+        r#"echo [1 2 3 4 5] | take -2 | to-json | echo $it"#
+    );
+    let drop = nu!(
+        cwd: ".",
+        r#"echo [1 2 3 4 5] | drop 2 | to-json | echo $it"#
+    );
+
+    assert_eq!(take, drop);
+}
@@ -1,4 +1,4 @@
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn each_works_separately() {
@@ -11,3 +11,91 @@ fn each_works_separately() {
 
     assert_eq!(actual, "[11,12,13]");
 }
+
+#[test]
+fn each_keeps_going_past_a_failed_row_with_keep_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}, {"a": 3}]'
+            | from-json
+            | each --keep-errors { echo $it.a }
+            | nth 0 2
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,3]");
+}
+
+#[test]
+fn each_gives_the_error_in_place_of_a_failed_row() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}, {"a": 3}]'
+            | from-json
+            | each --keep-errors { echo $it.a }
+            | nth 1
+            | get error
+        "#
+    ));
+
+    assert!(actual.contains("Unknown column"));
+}
+
+#[test]
+fn each_stops_at_a_failed_row_without_keep_errors() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}, {"a": 3}]'
+            | from-json
+            | each { echo $it.a }
+        "#
+    ));
+
+    assert!(actual.contains("Unknown column"));
+}
+
+#[test]
+fn each_numbered_gives_the_index_and_item() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres"}, {"name": "jonathan"}, {"name": "yehuda"}]'
+            | from-json
+            | each --numbered { echo $it.index }
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[0,1,2]");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres"}, {"name": "jonathan"}, {"name": "yehuda"}]'
+            | from-json
+            | each --numbered { echo $it.item.name }
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["andres","jonathan","yehuda"]"#);
+}
+
+#[test]
+fn each_without_numbered_gives_the_bare_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres"}, {"name": "jonathan"}]'
+            | from-json
+            | each { echo $it.name }
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["andres","jonathan"]"#);
+}
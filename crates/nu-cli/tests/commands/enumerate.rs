@@ -0,0 +1,65 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn wraps_scalars_with_their_index() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | enumerate
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"[{"index":0,"item":"a"},{"index":1,"item":"b"},{"index":2,"item":"c"}]"#
+    );
+}
+
+#[test]
+fn adds_an_index_column_to_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés"}, {"name": "Jonathan"}]'
+            | from-json
+            | enumerate
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"[{"index":0,"name":"Andrés"},{"index":1,"name":"Jonathan"}]"#
+    );
+}
+
+#[test]
+fn starts_from_the_given_index() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | enumerate --start 1
+            | get index
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
+
+#[test]
+fn errors_on_rows_that_already_have_an_index_column() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"index": "a", "name": "Andrés"}]'
+            | from-json
+            | enumerate
+        "#
+    ));
+
+    assert!(actual.contains("Row already has an index column"));
+}
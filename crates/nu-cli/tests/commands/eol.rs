@@ -0,0 +1,59 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn converts_crlf_to_lf() {
+    Playground::setup("eol_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("windows.txt", "one\r\ntwo\r\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open windows.txt
+                | eol --to lf
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#""one\ntwo\n""#);
+    })
+}
+
+#[test]
+fn converts_lf_to_crlf() {
+    Playground::setup("eol_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("unix.txt", "one\ntwo\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open unix.txt
+                | eol --to crlf
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#""one\r\ntwo\r\n""#);
+    })
+}
+
+#[test]
+fn detects_the_dominant_ending_of_mixed_input() {
+    Playground::setup("eol_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "mixed.txt",
+            "one\r\ntwo\nthree\r\nfour\r\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open mixed.txt
+                | eol --detect
+            "#
+        ));
+
+        assert_eq!(actual, "crlf");
+    })
+}
@@ -0,0 +1,58 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn formats_bytes_with_a_decimal_base() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1500000
+            | filesize
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1.5 MB");
+}
+
+#[test]
+fn formats_a_column_with_a_binary_base() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu.tar", "size": 1572864}]'
+            | from-json
+            | filesize size --binary
+            | get size
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1.5 MiB");
+}
+
+#[test]
+fn keeps_small_byte_counts_in_bytes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 512
+            | filesize
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "512 B");
+}
+
+#[test]
+fn errors_when_given_both_bases() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo 512
+            | filesize --binary --decimal
+        "#
+    ));
+
+    assert!(actual.contains("not both"));
+}
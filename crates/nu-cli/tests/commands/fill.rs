@@ -0,0 +1,57 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn pads_on_the_right_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu"
+            | fill 5 --character "*"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nu***");
+}
+
+#[test]
+fn pads_on_the_left_when_aligned_right() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 42
+            | fill 5 --character "0" --alignment right
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "00042");
+}
+
+#[test]
+fn leaves_values_longer_than_the_width_unchanged() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nushell"
+            | fill 3 --character "*"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nushell");
+}
+
+#[test]
+fn pads_wide_characters_to_their_display_width() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "日本"
+            | fill 6 --character "*"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "日本**");
+}
@@ -0,0 +1,104 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn keeps_rows_with_a_field_containing_the_term() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | find mexichem
+            | get shipper
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "OMYA ANDINA S A");
+}
+
+#[test]
+fn drops_values_not_containing_the_term() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [ "nushell" "bash" "zsh" ]
+            | find fish
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn matches_numbers_by_their_digits() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres", "luck": 1425}, {"name": "jonathan", "luck": 7}]'
+            | from-json
+            | find 42
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "andres");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1425 7 42]
+            | find 42
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+}
+
+#[test]
+fn matches_case_sensitively_when_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [ "Nushell" "nushell" "NUSHELL" ]
+            | find Nu --case-sensitive
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Nushell");
+}
+
+#[test]
+fn emits_line_numbers_with_matching_lines() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [ "nushell" "bash" "nu plugins" ]
+            | find nu --line-numbers
+            | get line_number
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1,3]");
+}
+
+#[test]
+fn emits_the_lines_not_matching_when_inverted() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [ "nushell" "bash" "nu plugins" ]
+            | find nu --line-numbers --invert
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"line_number":2,"text":"bash"}"#);
+}
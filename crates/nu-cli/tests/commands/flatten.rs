@@ -0,0 +1,74 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn flattens_one_level_of_nested_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "address": {"city": "Lima", "geo": {"lat": 12}}}'
+            | from-json
+            | flatten
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"name":"nu","address.city":"Lima","address.geo":{"lat":12}}"#
+    );
+}
+
+#[test]
+fn flattens_nested_rows_recursively_with_all() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "address": {"city": "Lima", "geo": {"lat": 12}}}'
+            | from-json
+            | flatten --all
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"name":"nu","address.city":"Lima","address.geo.lat":12}"#
+    );
+}
+
+#[test]
+fn errors_on_a_flattened_element_underline_it_in_the_source_list() {
+    let actual = nu_error!(
+        cwd: ".",
+        "echo [[1 two]] | flatten | abs"
+    );
+
+    let actual = strip_colors(&actual);
+    let source = actual.lines().find(|line| line.contains("echo [[1 two]]"));
+    let label = actual
+        .lines()
+        .find(|line| line.contains("^^^ expected a number"));
+
+    match (source, label) {
+        (Some(source), Some(label)) => assert_eq!(label.find("^^^"), source.find("two")),
+        _ => panic!("expected the error to underline the source: {}", actual),
+    }
+}
+
+fn strip_colors(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_escape = false;
+
+    for c in text.chars() {
+        match c {
+            '\u{1b}' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => stripped.push(c),
+        }
+    }
+
+    stripped
+}
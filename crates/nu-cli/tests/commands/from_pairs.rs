@@ -0,0 +1,43 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn builds_a_row_from_pairs() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name Andrés] [rusty_luck 1]]
+            | from-pairs
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"Andrés","rusty_luck":1}"#);
+}
+
+#[test]
+fn last_value_wins_for_repeated_keys() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name Andrés] [name Jonathan]]
+            | from-pairs
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Jonathan");
+}
+
+#[test]
+fn errors_on_anything_but_pairs() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name Andrés Robalino]]
+            | from-pairs
+        "#
+    ));
+
+    assert!(actual.contains("Expected a pair of key and value"));
+}
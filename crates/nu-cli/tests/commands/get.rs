@@ -126,12 +126,15 @@ fn fetches_more_than_one_column_path() {
             r#"
                 open sample.toml
                 | get fortune_tellers.2.name fortune_tellers.0.name fortune_tellers.1.name
-                | nth 2
+                | to-json
                 | echo $it
             "#
         ));
 
-        assert_eq!(actual, "Jonathan Turner");
+        assert_eq!(
+            actual,
+            r#"{"fortune_tellers.2.name":"Yehuda Katz","fortune_tellers.0.name":"Andrés N. Robalino","fortune_tellers.1.name":"Jonathan Turner"}"#
+        );
     })
 }
 
@@ -245,3 +248,260 @@ fn quoted_column_access() {
 
     assert_eq!(actual, "4");
 }
+
+#[test]
+fn fetches_a_single_column_path_as_a_value() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo '{"name": "nu", "version": "0.13.1"}' | from-json | get version | echo $it"#
+    );
+
+    assert_eq!(actual, "0.13.1");
+}
+
+#[test]
+fn errors_fetching_a_missing_column_among_several() {
+    let actual = nu_error!(
+        cwd: ".",
+        r#"echo '{"name": "nu", "version": "0.13.1"}' | from-json | get name taco | echo $it"#
+    );
+
+    assert!(actual.contains("Unknown column"));
+}
+
+#[test]
+fn errors_list_available_columns_and_suggest_a_near_miss() {
+    let actual = nu_error!(
+        cwd: ".",
+        r#"echo '{"name": "bob", "age": 30}' | from-json | get nmae"#
+    );
+
+    assert!(
+        actual.contains("column `nmae` not found. Perhaps you meant 'name'? available: name, age"),
+        format!("actual: {:?}", actual)
+    );
+}
+
+#[test]
+fn errors_list_available_columns_across_rows() {
+    let actual = nu_error!(
+        cwd: ".",
+        r#"echo '{"people": [{"name": "bob"}, {"name": "alice", "age": 30}]}' | from-json | get people.agee"#
+    );
+
+    assert!(
+        actual.contains("Perhaps you meant 'age'? available: name, age"),
+        format!("actual: {:?}", actual)
+    );
+}
+
+#[test]
+fn errors_without_suggesting_an_unrelated_column() {
+    let actual = nu_error!(
+        cwd: ".",
+        r#"echo '{"name": "bob", "age": 30}' | from-json | get zip"#
+    );
+
+    assert!(
+        actual.contains("column `zip` not found; available: name, age"),
+        format!("actual: {:?}", actual)
+    );
+}
+
+#[test]
+fn maps_the_remaining_path_over_a_list_of_records() {
+    Playground::setup("get_test_9", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.json",
+            r#"
+                {
+                    "users": [
+                        { "name": "andres", "shell": "nu" },
+                        { "name": "jonathan", "shell": "nu" }
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.json
+                | get users.name
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, r#"["andres","jonathan"]"#);
+    })
+}
+
+#[test]
+fn maps_the_remaining_path_over_nested_lists_of_records() {
+    Playground::setup("get_test_10", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.json",
+            r#"
+                {
+                    "teams": [
+                        { "members": [ { "name": "andres" }, { "name": "jonathan" } ] },
+                        { "members": [ { "name": "yehuda" } ] }
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.json
+                | get teams.members.name
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, r#"[["andres","jonathan"],["yehuda"]]"#);
+    })
+}
+
+#[test]
+fn fetches_columns_ignoring_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"Name": {"First": "Andrés"}}'
+            | from-json
+            | get --ignore-case name.first
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Andrés");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"Name": "Andrés", "Luck": 1}'
+            | from-json
+            | get --ignore-case name luck
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"Name":"Andrés","Luck":1}"#);
+}
+
+#[test]
+fn indexes_into_list_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Andrés Jonathan Yehuda]
+            | get 1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Jonathan");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés"}, {"name": "Jonathan"}]'
+            | from-json
+            | get 0.name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Andrés");
+}
+
+#[test]
+fn indexes_into_an_unending_stream() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll 0s { echo Andrés }
+            | get 1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Andrés");
+}
+
+#[test]
+fn errors_indexing_past_the_end_of_list_input() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Andrés Jonathan Yehuda]
+            | get 3
+        "#
+    ));
+
+    assert!(actual.contains("There isn't a row indexed at 3"));
+}
+
+#[test]
+fn gives_nothing_for_a_missing_optional_member() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "bob"}'
+            | from-json
+            | get address?.city
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn fetches_through_a_present_optional_member() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "bob", "address": {"city": "Lima"}}'
+            | from-json
+            | get address?.city
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Lima");
+}
+
+#[test]
+fn errors_fetching_a_missing_required_member_after_an_optional_one() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "bob", "address": {"city": "Lima"}}'
+            | from-json
+            | get address?.zip
+        "#
+    ));
+
+    assert!(actual.contains("Unknown column"));
+}
+
+#[test]
+fn fetches_a_column_ending_in_a_question_mark_when_quoted() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"address": {"verified?": "yes"}}'
+            | from-json
+            | get address."verified?"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "yes");
+}
@@ -0,0 +1,116 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn expands_a_pattern_in_the_current_directory() {
+    Playground::setup("glob_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("andres.txt"),
+            EmptyFile("jonathan.txt"),
+            EmptyFile("yehuda.csv"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob "*.txt"
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["andres.txt","jonathan.txt"]"#);
+    })
+}
+
+#[test]
+fn expands_a_recursive_pattern_within_the_depth() {
+    Playground::setup("glob_test_2", |dirs, sandbox| {
+        sandbox
+            .within("src")
+            .with_files(vec![EmptyFile("main.rs")])
+            .within("src/commands")
+            .with_files(vec![EmptyFile("glob.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob "**/*.rs"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob "**/*.rs" --depth 2
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "1");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob "**/*.rs" --depth 2
+                | echo $it
+            "#
+        ));
+
+        assert!(actual.ends_with("main.rs"));
+    })
+}
+
+#[test]
+fn expands_a_pattern_in_a_directory_named_like_a_pattern() {
+    Playground::setup("glob_test_[5]", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob "*.txt"
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#""andres.txt""#);
+    })
+}
+
+#[test]
+fn gives_nothing_when_nothing_matches() {
+    Playground::setup("glob_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob "*.csv"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0");
+    })
+}
+
+#[test]
+fn errors_when_nothing_matches_and_strict() {
+    Playground::setup("glob_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "glob *.csv --strict"
+        );
+
+        assert!(actual.contains("No matches found"));
+    })
+}
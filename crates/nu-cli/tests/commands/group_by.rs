@@ -54,3 +54,72 @@ fn errors_if_given_unknown_column_name_is_missing() {
         assert!(actual.contains("Unknown column"));
     })
 }
+
+#[test]
+fn groups_by_block_key() {
+    Playground::setup("group_by_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by { echo $it.rusty_at | split-column "/" month day year | get day }
+                | get "12"
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Jonathan");
+    })
+}
+
+#[test]
+fn groups_to_table_of_group_and_items() {
+    Playground::setup("group_by_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by type --to-table
+                | get group
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["A","B"]"#);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by type --to-table
+                | where group == A
+                | get items
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+    })
+}
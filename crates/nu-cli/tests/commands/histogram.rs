@@ -31,6 +31,73 @@ fn summarizes() {
     })
 }
 
+#[test]
+fn caps_the_bar_width() {
+    Playground::setup("histogram_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at
+                Andrés,Robalino,Ecuador
+                Jonathan,Turner,Estados Unidos
+                Yehuda,Katz,Estados Unidos
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | histogram rusty_at countries --width 10
+                | get countries
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["*****","**********"]"#);
+    })
+}
+
+#[test]
+fn adds_a_percentage_column_when_asked() {
+    Playground::setup("histogram_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at
+                Andrés,Robalino,Ecuador
+                Jonathan,Turner,Estados Unidos
+                Yehuda,Katz,Estados Unidos
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | histogram rusty_at --percentage-col
+                | get percentage
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["33.33%","66.67%"]"#);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | histogram rusty_at
+                | first
+                | to-json
+            "#
+        ));
+
+        assert!(actual.contains("frequency"));
+        assert!(!actual.contains("percentage"));
+    })
+}
+
 #[test]
 fn help() {
     Playground::setup("histogram_test_help", |dirs, _sandbox| {
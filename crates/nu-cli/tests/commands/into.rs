@@ -0,0 +1,133 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn converts_strings_into_integers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"count": "3"}, {"count": "4"}]'
+            | from-json
+            | into --int count
+            | get count
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "7");
+}
+
+#[test]
+fn converts_integers_into_strings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 42
+            | into --string
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#""42""#);
+}
+
+#[test]
+fn errors_when_the_value_can_not_be_converted() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "forty two"
+            | into --int
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.contains("Can't convert string to integer"));
+}
+
+#[test]
+fn parses_strings_into_filesizes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"size": "1.5 MB"}, {"size": "512 B"}]'
+            | from-json
+            | into --filesize size
+            | get size
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1572864,512]");
+}
+
+#[test]
+fn parses_filesize_units_like_filesize_literals() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2kb"
+            | into --filesize
+            | = $it == 2kb
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '["2kb", "3 MB"]'
+            | from-json
+            | into --filesize
+            | into --int
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[2048,3145728]");
+}
+
+#[test]
+fn parses_strings_into_durations() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '["2h30m", "1d 30s", "2:30:00"]'
+            | from-json
+            | into --duration
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[9000,86430,9000]");
+}
+
+#[test]
+fn errors_on_malformed_filesizes_and_durations() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "lots of bytes"
+            | into --filesize
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.contains("Can't parse string as a filesize"));
+
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2 fortnights"
+            | into --duration
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.contains("Can't parse string as a duration"));
+}
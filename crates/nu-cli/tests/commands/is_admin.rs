@@ -0,0 +1,36 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn returns_a_boolean() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            is-admin
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert!(actual == "true" || actual == "false");
+}
+
+#[cfg(unix)]
+#[test]
+fn matches_the_effective_user() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            is-admin
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    let expected = if users::get_effective_uid() == 0 {
+        "true"
+    } else {
+        "false"
+    };
+
+    assert_eq!(actual, expected);
+}
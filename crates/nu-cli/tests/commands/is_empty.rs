@@ -19,7 +19,8 @@ fn adds_value_provided_if_column_is_empty() {
         let actual = nu!(
             cwd: dirs.test(), pipeline(
             r#"
-                open likes.csv
+                open likes.csv --raw
+                | from-csv --infer
                 | empty? likes 1
                 | get likes
                 | sum
@@ -52,7 +53,7 @@ fn adds_value_provided_for_columns_that_are_empty() {
             r#"
                 open checks.json
                 | empty? boost check 1
-                | get boost check
+                | each { echo $it.boost $it.check }
                 | sum
                 | echo $it
             "#
@@ -94,3 +95,32 @@ fn value_emptiness_check() {
         assert_eq!(actual, "4");
     })
 }
+
+#[test]
+fn an_empty_stream_is_empty() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | is-empty
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn a_stream_with_rows_is_not_empty_without_reading_it_all() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | cycle
+            | is-empty
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "false");
+}
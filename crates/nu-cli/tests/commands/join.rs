@@ -0,0 +1,52 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn joins_rows_sharing_the_column_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"id": 1, "name": "Andrés"}, {"id": 2, "name": "Jonathan"}]'
+            | from-json
+            | join { echo '[{"id": 2, "lang": "rust"}, {"id": 1, "lang": "go"}]' | from-json } id
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"[{"id":1,"name":"Andrés","lang":"go"},{"id":2,"name":"Jonathan","lang":"rust"}]"#
+    );
+}
+
+#[test]
+fn left_join_keeps_unmatched_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"id": 1, "name": "Andrés"}, {"id": 3, "name": "Yehuda"}]'
+            | from-json
+            | join --left { echo '[{"id": 1, "lang": "go"}]' | from-json } id
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"[{"id":1,"name":"Andrés","lang":"go"},{"id":3,"name":"Yehuda","lang":null}]"#
+    );
+}
+
+#[test]
+fn right_side_wins_on_conflicting_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"id": 1, "name": "Andrés"}]'
+            | from-json
+            | join { echo '[{"id": 1, "name": "Andres"}]' | from-json } id
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual, "Andres");
+}
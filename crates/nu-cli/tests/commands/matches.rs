@@ -0,0 +1,56 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn is_true_for_a_matching_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo andres
+            | matches "^an"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn is_false_for_a_regex_that_does_not_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo jonathan
+            | matches "^an"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "false");
+}
+
+#[test]
+fn tests_each_string_in_a_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [andres jonathan yehuda]
+            | matches "a$"
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[false,false,true]");
+}
+
+#[test]
+fn errors_on_an_invalid_regex() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo andres
+            | matches "(an"
+        "#
+    ));
+
+    assert!(actual.contains("Invalid regex"));
+}
@@ -1,4 +1,4 @@
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn one_arg() {
@@ -24,6 +24,30 @@ fn add() {
     assert_eq!(actual, "2");
 }
 
+#[test]
+fn modulo_takes_the_sign_of_the_divisor() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [[-7 2] [7 -2] [-7 -2]] | each { = $it.0 % $it.1 } | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,-1,-1]");
+}
+
+#[test]
+fn integer_division_and_modulo_add_back_up_to_the_dividend() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = (-7 // 2) * 2 + (-7 % 2)
+        "#
+    ));
+
+    assert_eq!(actual, "-7");
+}
+
 #[test]
 fn add_compount() {
     let actual = nu!(
@@ -143,3 +167,184 @@ fn compound_where_paren() {
 
     assert_eq!(actual, r#"[{"a":2,"b":1},{"a":2,"b":2}]"#);
 }
+
+#[test]
+fn addition_past_the_i64_bounds_stays_exact() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 9223372036854775807 + 1
+        "#
+    ));
+
+    assert_eq!(actual, "9223372036854775808");
+}
+
+#[test]
+fn multiplication_past_the_i64_bounds_stays_exact() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 9223372036854775807 * 4
+        "#
+    ));
+
+    assert_eq!(actual, "36893488147419103228");
+}
+
+#[test]
+fn subtracting_more_bytes_than_there_are_errors() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1kb - 2kb
+        "#
+    ));
+
+    assert!(actual.contains("Integer overflow"));
+}
+
+#[test]
+fn division_of_ints_that_do_not_divide_evenly() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 7 / 2
+        "#
+    ));
+
+    assert_eq!(actual, "3.5");
+}
+
+#[test]
+fn integer_division() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 7 // 2
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn integer_division_rounds_down() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = -7 // 2
+        "#
+    ));
+
+    assert_eq!(actual, "-4");
+}
+
+#[test]
+fn modulo() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 7 % 3 + 1
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+}
+
+#[test]
+fn division_by_zero_errors() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1 / 0
+        "#
+    ));
+
+    assert!(actual.contains("Division by zero"));
+}
+
+#[test]
+fn modulo_by_zero_errors() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1 % 0
+        "#
+    ));
+
+    assert!(actual.contains("Division by zero"));
+}
+
+#[test]
+fn concatenates_strings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = "foo" + "bar"
+        "#
+    ));
+
+    assert_eq!(actual, "foobar");
+}
+
+#[test]
+fn concatenates_lines_and_strings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo "foo" | lines | each { = $it + "bar" } | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "foobar");
+}
+
+#[test]
+fn adding_a_string_and_an_int_errors() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = "foo" + 1
+        "#
+    ));
+
+    assert!(actual.contains("Coercion error"));
+}
+
+#[test]
+fn concatenates_lists() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = [1 2] + [3]
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
+
+#[test]
+fn value_in_list() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 2 in [1 2 3]
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn substring_in_string() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = "ell" in "hello"
+        "#
+    ));
+
+    assert_eq!(actual, "true");
+}
@@ -1,44 +1,94 @@
+mod abs;
+mod aggregate;
 mod alias;
+mod all;
+mod any;
 mod append;
+mod as_;
+mod base32;
+mod benchmark;
+mod bytes_;
 mod calc;
 mod cd;
+mod clean_numbers;
+mod collect;
 mod compact;
+mod complete;
 mod cp;
+mod cycle;
+mod date_format;
+mod date_to_timezone;
 mod default;
+mod def;
 mod drop;
 mod each;
 mod edit;
 mod enter;
+mod enumerate;
+mod eol;
+mod filesize;
+mod fill;
+mod find;
 mod first;
+mod flatten;
 mod format;
+mod from_pairs;
 mod get;
+mod glob;
 mod group_by;
 mod headers;
 mod histogram;
 mod insert;
+mod into;
+mod is_admin;
 mod is_empty;
+mod join;
 mod last;
 mod lines;
 mod ls;
+mod matches;
 mod math;
 mod mkdir;
+mod mode;
+mod move_;
 mod mv;
+mod normalize;
 mod open;
+mod par_each;
 mod parse;
+mod path;
 mod pick;
+mod poll;
+mod pow;
 mod prepend;
+mod print;
 mod range;
+mod reject;
 mod rename;
 mod reverse;
 mod rm;
+mod roll;
+mod rotate;
+mod round;
 mod save;
 mod semicolon;
+mod seq_date;
+mod shells;
+mod sleep;
+mod sort;
 mod sort_by;
+mod source;
 mod split_by;
 mod split_column;
+mod stddev;
 mod sum;
+mod take;
+mod term_size;
+mod timeout;
 mod touch;
 mod trim;
 mod uniq;
+mod upsert;
+mod unwrap;
 mod where_;
 mod wrap;
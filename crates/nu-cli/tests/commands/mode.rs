@@ -0,0 +1,57 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn gives_the_most_frequent_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [3 1 3 2 3 1]
+            | mode
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn gives_every_value_tied_for_most_frequent() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 1 2 1 3]
+            | mode
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[2,1]");
+}
+
+#[test]
+fn no_values_errors() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | mode
+        "#
+    ));
+
+    assert!(actual.contains("Expected values to find the mode of"));
+}
+
+#[test]
+fn gives_the_most_frequent_value_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"kind": "dir", "size": 1}, {"kind": "file", "size": 2}, {"kind": "file", "size": 1}]'
+            | from-json
+            | mode kind size
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"kind":"file","size":1}"#);
+}
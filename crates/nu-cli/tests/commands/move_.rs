@@ -0,0 +1,31 @@
+use nu_test_support::{nu, nu_error};
+
+#[test]
+fn moves_a_column_before_another() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"a": 1, "b": 2, "c": 3}]' | from-json | move c --before a | to-json | echo $it"#
+    );
+
+    assert_eq!(actual, r#"{"c":3,"a":1,"b":2}"#);
+}
+
+#[test]
+fn moves_a_column_after_another() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"a": 1, "b": 2, "c": 3}]' | from-json | move a --after b | to-json | echo $it"#
+    );
+
+    assert_eq!(actual, r#"{"b":2,"a":1,"c":3}"#);
+}
+
+#[test]
+fn errors_on_unknown_anchor() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"a": 1, "b": 2, "c": 3}]' | from-json | move a --after z | to-json | echo $it"#
+    );
+
+    assert!(actual.contains("Unknown column"));
+}
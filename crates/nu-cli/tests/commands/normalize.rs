@@ -0,0 +1,55 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn flattens_nested_rows_into_dotted_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "address": {"city": "Lima", "geo": {"lat": 12, "lng": 77}}}'
+            | from-json
+            | normalize
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"name":"nu","address.city":"Lima","address.geo.lat":12,"address.geo.lng":77}"#
+    );
+}
+
+#[test]
+fn stops_at_the_given_depth() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "address": {"city": "Lima", "geo": {"lat": 12}}}'
+            | from-json
+            | normalize --depth 1
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"name":"nu","address.city":"Lima","address.geo":{"lat":12}}"#
+    );
+}
+
+#[test]
+fn explodes_list_columns_into_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "tags": ["shell", "rust"]}'
+            | from-json
+            | normalize --explode
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"[{"name":"nu","tags":"shell"},{"name":"nu","tags":"rust"}]"#
+    );
+}
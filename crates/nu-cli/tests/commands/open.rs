@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::fs::Stub::{FileWithContent, FileWithContentToBeTrimmed};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, nu_error, pipeline};
 
@@ -218,6 +218,23 @@ fn parses_utf16_ini() {
     assert_eq!(actual, "-236")
 }
 
+#[test]
+fn parses_json_with_a_byte_order_mark() {
+    Playground::setup("open_test_bom", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "nu.json",
+            "\u{feff}{\"name\": \"nu\"}",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open nu.json | get name | echo $it"
+        );
+
+        assert_eq!(actual, "nu");
+    })
+}
+
 #[test]
 fn errors_if_file_not_found() {
     let actual = nu_error!(
@@ -228,3 +245,60 @@ fn errors_if_file_not_found() {
     assert!(actual.contains("File could not be opened"));
     assert!(actual.contains("file not found"));
 }
+
+#[test]
+fn decodes_latin1_text() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open latin1.txt | lines | first | echo $it"
+    );
+
+    assert!(actual.contains("Andrés, José y Ramón"));
+}
+
+#[test]
+fn shows_the_detected_encoding() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open latin1.txt --show-encoding | echo $it"
+    );
+
+    assert_eq!(actual, "windows-1252");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open appveyor.yml --show-encoding | echo $it"
+    );
+
+    assert_eq!(actual, "UTF-8");
+}
+
+#[test]
+fn gives_back_bytes_for_text_that_is_not_utf8_with_raw() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open latin1.txt --raw | to-json | echo $it"
+    );
+
+    assert!(actual.contains("$binary"));
+}
+
+#[test]
+fn gives_back_text_for_utf16_with_a_byte_order_mark_with_raw() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open utf16.ini --raw | to-json | echo $it"
+    );
+
+    assert!(actual.contains(".ShellClassInfo"));
+}
+
+#[test]
+fn gives_back_bytes_for_binary_files_with_raw() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open sample.bson --raw | to-json | echo $it"
+    );
+
+    assert!(actual.contains("$binary"));
+}
@@ -0,0 +1,104 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn matches_the_results_of_each() {
+    let each = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6 7 8] | each { echo $it 10 | sum } | to-json | echo $it
+        "#
+    ));
+
+    let par_each = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6 7 8] | par-each { echo $it 10 | sum } | to-json | echo $it
+        "#
+    ));
+
+    assert_eq!(par_each, each);
+}
+
+#[test]
+fn keeps_the_order_of_the_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [3 2 1] | par-each { sleep $it | echo $it } | to-json | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,2,1]");
+}
+
+#[test]
+fn keeps_going_past_a_failed_row_with_keep_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}, {"a": 3}]'
+            | from-json
+            | par-each --keep-errors { echo $it.a }
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}, {"a": 3}]'
+            | from-json
+            | par-each --keep-errors { echo $it.a }
+            | nth 1
+            | get error
+        "#
+    ));
+
+    assert!(actual.contains("Unknown column"));
+}
+
+#[test]
+fn matches_the_results_of_each_with_one_thread() {
+    let each = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6 7 8] | each { echo $it 10 | sum } | to-json | echo $it
+        "#
+    ));
+
+    let par_each = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4 5 6 7 8] | par-each --threads 1 { echo $it 10 | sum } | to-json | echo $it
+        "#
+    ));
+
+    assert_eq!(par_each, each);
+}
+
+#[test]
+fn keeps_the_order_of_the_rows_with_a_thread_limit() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [5 4 3 2 1] | par-each --threads 2 { echo $it } | to-json | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[5,4,3,2,1]");
+}
+
+#[test]
+fn errors_without_any_threads() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3] | par-each --threads 0 { echo $it }
+        "#
+    ));
+
+    assert!(actual.contains("par-each needs at least one thread"));
+}
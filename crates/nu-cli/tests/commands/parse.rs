@@ -1,6 +1,6 @@
 use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
 use nu_test_support::playground::Playground;
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn extracts_fields_from_the_given_the_pattern() {
@@ -28,3 +28,132 @@ fn extracts_fields_from_the_given_the_pattern() {
         assert_eq!(actual, "JonathanParsed");
     })
 }
+
+#[test]
+fn extracts_fields_with_named_capture_groups() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu 0.13.0"
+            | parse --regex "(?P<name>\w+) (?P<version>[\d.]+)"
+            | get version
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0.13.0");
+}
+
+#[test]
+fn skips_lines_that_do_not_match_the_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [ "nu 0.13.0" "no version here" ]
+            | parse --regex "(?P<name>\w+) (?P<version>\d[\d.]*)"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn errors_on_lines_that_do_not_match_the_regex_when_strict() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "no version here"
+            | parse --regex --strict "(?P<version>\d[\d.]*)"
+        "#
+    ));
+
+    assert!(actual.contains("Line does not match the pattern"));
+}
+
+#[test]
+fn errors_on_strict_without_regex() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "no version here"
+            | parse --strict "{name}: {version}"
+        "#
+    ));
+
+    assert!(actual.contains("Expected --regex with --strict"));
+}
+
+#[test]
+fn errors_with_an_invalid_regex() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu 0.13.0"
+            | parse --regex "(?P<name>\w+"
+        "#
+    ));
+
+    assert!(actual.contains("Could not parse regex"));
+}
+
+#[test]
+fn converts_int_typed_captures() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [ "apples: 3" "pears: 4" ]
+            | parse "{fruit}: {count:int}"
+            | get count
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "7");
+}
+
+#[test]
+fn converts_float_typed_regex_captures() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "pi is 3.5"
+            | parse --regex "(?P<name>\w+) is (?P<value__float>[\d.]+)"
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"pi","value":3.5}"#);
+}
+
+#[test]
+fn keeps_regex_capture_names_whose_suffix_is_not_a_type() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "Andrés Robalino"
+            | parse --regex "(?P<first__name>\w+) (?P<last__name>\w+)"
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"first__name":"Andrés","last__name":"Robalino"}"#
+    );
+}
+
+#[test]
+fn errors_when_a_typed_capture_does_not_convert() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "apples: lots"
+            | parse "{fruit}: {count:int}"
+        "#
+    ));
+
+    assert!(actual.contains("Could not convert the capture to int"));
+}
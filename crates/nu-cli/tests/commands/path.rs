@@ -0,0 +1,48 @@
+use nu_test_support::{nu, nu_error, pipeline};
+use std::path::PathBuf;
+
+#[test]
+fn makes_a_subpath_relative_to_its_parent() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "/home/nu/projects/shell"
+            | path --relative-to /home/nu
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        PathBuf::from(actual),
+        PathBuf::from("projects").join("shell")
+    );
+}
+
+#[test]
+fn makes_a_sibling_path_relative_by_going_up() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "docs", "location": "/home/nu/docs"}]'
+            | from-json
+            | path location --relative-to /home/nu/projects
+            | get location
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(PathBuf::from(actual), PathBuf::from("..").join("docs"));
+}
+
+#[test]
+fn errors_without_an_operation() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo "/home/nu"
+            | path
+        "#
+    ));
+
+    assert!(actual.contains("Path requires an operation"));
+}
@@ -99,3 +99,36 @@ fn allows_if_given_unknown_column_name_is_missing() {
         assert_eq!(actual, "3");
     })
 }
+
+#[test]
+fn picks_columns_ignoring_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"First_Name": "Andrés", "Rusty_Luck": 1}]'
+            | from-json
+            | pick --ignore-case first_name
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"First_Name":"Andrés"}"#);
+}
+
+#[test]
+fn picks_a_nested_path_into_a_flat_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés", "address": {"city": "Guayaquil"}}, {"name": "Jonathan", "address": {"city": "Wellington"}}]'
+            | from-json
+            | pick name address.city
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"[{"name":"Andrés","address.city":"Guayaquil"},{"name":"Jonathan","address.city":"Wellington"}]"#
+    );
+}
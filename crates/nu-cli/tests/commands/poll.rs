@@ -0,0 +1,32 @@
+use nu_test_support::{nu, pipeline};
+use std::time::{Duration, Instant};
+
+#[test]
+fn runs_the_block_as_many_times_as_counted() {
+    let start = Instant::now();
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll 1s --count 3 { echo "ping" }
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+    assert!(start.elapsed() >= Duration::from_secs(2));
+}
+
+#[test]
+fn streams_out_what_each_run_gives() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll 1s --count 2 { echo [1 2] }
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,1,2]");
+}
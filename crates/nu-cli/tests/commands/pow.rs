@@ -0,0 +1,99 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn takes_square_root_of_perfect_square() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 16
+            | sqrt
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "4.0");
+}
+
+#[test]
+fn takes_square_root_as_decimal() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 2
+            | sqrt
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.starts_with("1.414"));
+}
+
+#[test]
+fn errors_on_square_root_of_negative_number() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[-4]'
+            | from-json
+            | sqrt
+        "#
+    ));
+
+    assert!(actual.contains("Can't take the square root of a negative number"));
+}
+
+#[test]
+fn gives_nothing_for_square_root_of_negative_number_when_lenient() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[-4]'
+            | from-json
+            | sqrt --lenient
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "null");
+}
+
+#[test]
+fn raises_integers_to_whole_powers_exactly() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 2
+            | pow 100
+        "#
+    ));
+
+    assert_eq!(actual, "1267650600228229401496703205376");
+}
+
+#[test]
+fn errors_on_an_exponent_too_large_to_compute() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo 2
+            | pow 100000000
+        "#
+    ));
+
+    assert!(actual.contains("Result is too large to compute"));
+}
+
+#[test]
+fn raises_zero_and_one_to_any_power() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [0 1 -1]
+            | pow 100000001
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[0,1,-1]");
+}
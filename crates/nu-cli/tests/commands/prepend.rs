@@ -28,3 +28,17 @@ fn adds_a_row_to_the_beginning() {
         assert_eq!(actual, "pollo loco");
     })
 }
+
+#[test]
+fn adds_several_rows_to_the_beginning_in_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [4 5]
+            | prepend 1 2 3
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,4,5]");
+}
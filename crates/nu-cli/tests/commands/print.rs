@@ -0,0 +1,41 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn prints_its_arguments_to_stdout() {
+    let actual = nu!(
+        cwd: ".",
+        "print hello"
+    );
+
+    assert_eq!(actual, "hello");
+}
+
+#[test]
+fn prints_from_inside_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | each { print $it }
+        "#
+    ));
+
+    assert_eq!(actual, "123");
+}
+
+#[test]
+fn prints_to_stderr_with_stderr_flag() {
+    let actual = nu_error!(
+        cwd: ".",
+        "print --stderr oops"
+    );
+
+    assert!(actual.contains("oops"));
+
+    let actual = nu!(
+        cwd: ".",
+        "print --stderr oops"
+    );
+
+    assert_eq!(actual, "");
+}
@@ -45,3 +45,56 @@ fn selects_some_rows() {
         assert_eq!(actual, "2");
     });
 }
+
+#[test]
+fn leaves_out_the_end_of_an_exclusive_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | range 1..<3
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["b","c"]"#);
+}
+
+#[test]
+fn iterates_over_an_exclusive_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..<5
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,4]");
+}
+
+#[test]
+fn iterates_over_an_inclusive_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..=5
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,4,5]");
+}
+
+#[test]
+fn iterates_over_a_range_inclusively_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..5
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,4,5]");
+}
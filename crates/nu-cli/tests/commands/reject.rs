@@ -0,0 +1,82 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rejects_columns_ignoring_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"Name": "Andrés", "Luck": 1}'
+            | from-json
+            | reject --ignore-case luck
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"Name":"Andrés"}"#);
+}
+
+#[test]
+fn rejects_only_exact_names_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"Name": "Andrés", "Luck": 1}'
+            | from-json
+            | reject luck
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"Name":"Andrés","Luck":1}"#);
+}
+
+#[test]
+fn rejects_a_nested_leaf_keeping_its_parent() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "address": {"city": "Guayaquil", "country": "Ecuador"}}'
+            | from-json
+            | reject address.city
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"name":"Andrés","address":{"country":"Ecuador"}}"#
+    );
+}
+
+#[test]
+fn rejects_the_last_nested_leaf_leaving_an_empty_parent() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "address": {"city": "Guayaquil"}}'
+            | from-json
+            | reject address.city
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"Andrés","address":{}}"#);
+}
+
+#[test]
+fn rejects_a_nested_leaf_ignoring_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "address": {"city": "Guayaquil", "country": "Ecuador"}}'
+            | from-json
+            | reject --ignore-case Address.CITY
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"name":"Andrés","address":{"country":"Ecuador"}}"#
+    );
+}
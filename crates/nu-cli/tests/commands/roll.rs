@@ -0,0 +1,51 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rolls_rows_down() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"n": 1}, {"n": 2}, {"n": 3}]'
+            | from-json
+            | roll
+            | get n
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,1,2]");
+}
+
+#[test]
+fn rolls_rows_up_when_reversed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"n": 1}, {"n": 2}, {"n": 3}]'
+            | from-json
+            | roll 2 --reverse
+            | get n
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,1,2]");
+}
+
+#[test]
+fn rolls_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1, "b": 2, "c": 3}]'
+            | from-json
+            | roll --columns
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"c":3,"a":1,"b":2}"#);
+}
@@ -0,0 +1,59 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rotates_clockwise() {
+    Playground::setup("rotate_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+                Jonathan,Turner
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | rotate
+                | to-json
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"Column1":"Jonathan","Column2":"Andrés","Column3":"first_name"},{"Column1":"Turner","Column2":"Robalino","Column3":"last_name"}]"#
+        );
+    })
+}
+
+#[test]
+fn rotates_counterclockwise() {
+    Playground::setup("rotate_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+                Jonathan,Turner
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | rotate --ccw
+                | to-json
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"Column1":"last_name","Column2":"Robalino","Column3":"Turner"},{"Column1":"first_name","Column2":"Andrés","Column3":"Jonathan"}]"#
+        );
+    })
+}
@@ -0,0 +1,82 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn rounds_to_decimal_places() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 3.14159
+            | round --precision 2
+        "#
+    ));
+
+    assert_eq!(actual, "3.14");
+}
+
+#[test]
+fn rounds_half_away_from_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"x": 2.5}, {"x": 2.4}]'
+            | from-json
+            | round x
+            | get x
+            | sum
+        "#
+    ));
+
+    assert_eq!(actual, "5");
+}
+
+#[test]
+fn rounds_down_with_floor() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 3.99
+            | round --floor
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn rounds_up_with_ceil() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 3.01
+            | round --ceil
+        "#
+    ));
+
+    assert_eq!(actual, "4");
+}
+
+#[test]
+fn leaves_integers_unchanged() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 7
+            | round --ceil --precision 2
+        "#
+    ));
+
+    assert_eq!(actual, "7");
+}
+
+#[test]
+fn errors_when_floor_and_ceil_are_both_given() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo 3.5
+            | round --floor --ceil
+        "#
+    ));
+
+    assert!(actual.contains("Round can either floor or ceil, not both"));
+}
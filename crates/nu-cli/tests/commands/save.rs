@@ -1,5 +1,5 @@
 use nu_test_support::fs::{file_contents, Stub::FileWithContent};
-use nu_test_support::nu;
+use nu_test_support::{nu, nu_error};
 use nu_test_support::playground::Playground;
 
 #[test]
@@ -42,6 +42,68 @@ fn writes_out_csv() {
         );
 
         let actual = file_contents(expected_file);
-        assert!(actual.contains("[Table],a new type of shell,2018,ISC,nu,0.1.1"));
+        assert!(actual.contains("nu,0.1.1,[Table],a new type of shell,ISC,2018"));
+    })
+}
+
+#[test]
+fn writes_out_a_record_as_json() {
+    Playground::setup("save_test_3", |dirs, _| {
+        let expected_file = dirs.test().join("package.json");
+
+        nu!(
+            cwd: dirs.root(),
+            "open {}/cargo_sample.toml | get package | pick name version | save save_test_3/package.json",
+            dirs.formats()
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(actual, r#"{"name":"nu","version":"0.1.1"}"#);
+    })
+}
+
+#[test]
+fn writes_out_a_record_as_csv() {
+    Playground::setup("save_test_4", |dirs, _| {
+        let expected_file = dirs.test().join("package.csv");
+
+        nu!(
+            cwd: dirs.root(),
+            "open {}/cargo_sample.toml | get package | pick name version | save save_test_4/package.csv",
+            dirs.formats()
+        );
+
+        let actual = file_contents(expected_file);
+        assert!(actual.contains("name,version"));
+        assert!(actual.contains("nu,0.1.1"));
+    })
+}
+
+#[test]
+fn errors_saving_structured_data_to_an_unknown_extension() {
+    Playground::setup("save_test_5", |dirs, _| {
+        let actual = nu_error!(
+            cwd: dirs.root(),
+            "open {}/cargo_sample.toml | get package | save save_test_5/package.unknown",
+            dirs.formats()
+        );
+
+        assert!(actual.contains("Save requires string data"));
+    })
+}
+
+#[test]
+fn writes_structured_data_as_text_with_raw() {
+    Playground::setup("save_test_6", |dirs, _| {
+        let expected_file = dirs.test().join("package.json");
+
+        nu!(
+            cwd: dirs.root(),
+            "open {}/cargo_sample.toml | get package | pick name | save --raw save_test_6/package.json",
+            dirs.formats()
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(actual, "name: nu");
     })
 }
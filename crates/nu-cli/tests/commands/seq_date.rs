@@ -0,0 +1,45 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn steps_a_day_at_a_time_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-02-27 2020-03-01
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"["2020-02-27T00:00:00+00:00","2020-02-28T00:00:00+00:00","2020-02-29T00:00:00+00:00","2020-03-01T00:00:00+00:00"]"#
+    );
+}
+
+#[test]
+fn steps_by_the_given_duration() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-01-01 2020-01-31 --step 1w
+            | to-json
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"["2020-01-01T00:00:00+00:00","2020-01-08T00:00:00+00:00","2020-01-15T00:00:00+00:00","2020-01-22T00:00:00+00:00","2020-01-29T00:00:00+00:00"]"#
+    );
+}
+
+#[test]
+fn errors_on_a_date_it_cannot_parse() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-13-01 2020-12-31
+        "#
+    ));
+
+    assert!(actual.contains("Could not parse date"));
+}
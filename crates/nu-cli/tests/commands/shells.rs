@@ -0,0 +1,39 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn lists_one_shell_per_enter_with_the_last_one_active() {
+    Playground::setup("shells_test_1", |dirs, sandbox| {
+        sandbox.mkdir("red_pill").mkdir("blue_pill");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                enter red_pill
+                enter ../blue_pill
+                shells | get active | to-json
+            "#
+        );
+
+        assert_eq!(actual, "[false,false,true]");
+    })
+}
+
+#[test]
+fn marks_the_shell_moved_to_as_active() {
+    Playground::setup("shells_test_2", |dirs, sandbox| {
+        sandbox.mkdir("red_pill").mkdir("blue_pill");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                enter red_pill
+                enter ../blue_pill
+                p
+                shells | get active | to-json
+            "#
+        );
+
+        assert_eq!(actual, "[false,true,false]");
+    })
+}
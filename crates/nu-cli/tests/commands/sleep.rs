@@ -0,0 +1,19 @@
+use nu_test_support::{nu, pipeline};
+use std::time::{Duration, Instant};
+
+#[test]
+fn sleeps_then_passes_the_input_through() {
+    let start = Instant::now();
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu"
+            | sleep 1s
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nu");
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
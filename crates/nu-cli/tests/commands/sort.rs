@@ -0,0 +1,60 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn sorts_integers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [3 1 2]
+            | sort
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
+
+#[test]
+fn sorts_integers_in_reverse() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [3 1 2]
+            | sort --reverse
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,2,1]");
+}
+
+#[test]
+fn sorts_strings_ignoring_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [banana Apple cherry]
+            | sort --insensitive
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["Apple","banana","cherry"]"#);
+}
+
+#[test]
+fn errors_on_records() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "bob"}]'
+            | from-json
+            | sort
+        "#
+    ));
+
+    assert!(actual.contains("use sort-by to sort rows by a column"));
+}
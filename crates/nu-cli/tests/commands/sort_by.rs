@@ -39,3 +39,50 @@ fn sort_primitive_values() {
 
     assert_eq!(actual, "authors = [\"The Nu Project Contributors\"]");
 }
+
+#[test]
+fn sorts_a_column_with_mixed_types_by_type_group() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"id": 1, "v": "b"}, {"id": 2, "v": 2}, {"id": 3, "v": null}, {"id": 4, "v": true}, {"id": 5, "v": 1.5}, {"id": 6, "v": "a"}, {"id": 7, "v": 10}]'
+            | from-json
+            | sort-by v
+            | get id
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[3,4,5,2,7,6,1]");
+}
+
+#[test]
+fn sorts_rows_and_lists_after_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"id": 1, "v": [1]}, {"id": 2, "v": "z"}, {"id": 3, "v": {"a": 1}}, {"id": 4, "v": 0}]'
+            | from-json
+            | sort-by v
+            | get id
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[4,2,3,1]");
+}
+
+#[test]
+fn sorts_mixed_plain_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '["b", 2, "a", 1.5]'
+            | from-json
+            | sort-by
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"[1.5,2,"a","b"]"#);
+}
@@ -0,0 +1,66 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error};
+
+#[test]
+fn keeps_the_commands_a_script_defines() {
+    Playground::setup("source_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "greetings.nu",
+            r#"
+                def greet [name] {echo $name}
+                alias shout [name] {echo $name}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                source greetings.nu
+                greet nushell | echo $it
+            "#
+        );
+
+        assert_eq!(actual, "nushell");
+    })
+}
+
+#[test]
+fn later_statements_use_commands_defined_earlier_in_the_script() {
+    Playground::setup("source_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                def greet [name] {echo $name}
+                greet nushell
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "source script.nu | echo $it"
+        );
+
+        assert_eq!(actual, "nushell");
+    })
+}
+
+#[test]
+fn reports_the_file_of_a_parse_error() {
+    Playground::setup("source_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "broken.nu",
+            r#"
+                echo fine
+                debug --ferris
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "source broken.nu"
+        );
+
+        assert!(actual.contains("Could not parse broken.nu:2"));
+    })
+}
@@ -53,3 +53,38 @@ fn errors_if_no_table_given_as_input() {
         assert!(actual.contains("Expected table from pipeline"));
     })
 }
+
+#[test]
+fn splits_each_group_into_lists_by_the_second_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres", "lang": "rust", "team": "core"}, {"name": "jonathan", "lang": "rust", "team": "core"}, {"name": "yehuda", "lang": "ruby", "team": "core"}, {"name": "jt", "lang": "rust", "team": "docs"}]'
+            | from-json
+            | group-by team
+            | split-by lang
+            | to-json
+        "#
+    ));
+
+    // The second column's values hold the groups, not the other way around
+    assert_eq!(
+        actual,
+        r#"{"rust":{"core":[{"name":"andres","lang":"rust","team":"core"},{"name":"jonathan","lang":"rust","team":"core"}],"docs":[{"name":"jt","lang":"rust","team":"docs"}]},"ruby":{"core":[{"name":"yehuda","lang":"ruby","team":"core"}]}}"#
+    );
+}
+
+#[test]
+fn errors_if_a_group_is_missing_the_column() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres", "lang": "rust", "team": "core"}, {"name": "jt", "team": "docs"}]'
+            | from-json
+            | group-by team
+            | split-by lang
+        "#
+    ));
+
+    assert!(actual.contains("Unknown column"));
+}
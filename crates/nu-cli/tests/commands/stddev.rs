@@ -0,0 +1,70 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn population_stddev_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 4 4 4 5 5 7 9]
+            | stddev
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "2.0");
+}
+
+#[test]
+fn stddev_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "size": 2}, {"name": "b", "size": 4}, {"name": "c", "size": 4}, {"name": "d", "size": 4}, {"name": "e", "size": 5}, {"name": "f", "size": 5}, {"name": "g", "size": 7}, {"name": "h", "size": 9}]'
+            | from-json
+            | stddev size
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"size":2.0}"#);
+}
+
+#[test]
+fn sample_variance() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | variance --sample
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "2.5");
+}
+
+#[test]
+fn sample_of_one_number_errors() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1]
+            | variance --sample
+        "#
+    ));
+
+    assert!(actual.contains("A sample needs at least two numbers"));
+}
+
+#[test]
+fn no_numbers_errors() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | stddev
+        "#
+    ));
+
+    assert!(actual.contains("Expected numbers to compute with"));
+}
@@ -0,0 +1,44 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn takes_the_first_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | take 2
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2]");
+}
+
+#[test]
+fn takes_all_but_the_last_rows_when_negative() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | take -2
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
+
+#[test]
+fn takes_from_an_endless_stream() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | cycle
+            | take 3
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,1]");
+}
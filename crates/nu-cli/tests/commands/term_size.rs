@@ -0,0 +1,29 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_positive_dimensions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            term-size
+            | where columns > 0
+            | where rows > 0
+            | count
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn falls_back_to_80_by_24_when_not_writing_to_a_terminal() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            term-size
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"columns":80,"rows":24}"#);
+}
@@ -0,0 +1,31 @@
+use nu_test_support::{nu, nu_error, pipeline};
+use std::time::{Duration, Instant};
+
+#[test]
+fn gives_back_what_the_block_outputs_when_it_finishes_in_time() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            timeout 5s { echo "nu" | sleep 0s }
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nu");
+}
+
+#[test]
+fn errors_when_the_block_runs_too_long() {
+    let start = Instant::now();
+
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            timeout 1s { echo "nu" | sleep 10s }
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.contains("timed out"));
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
@@ -140,3 +140,33 @@ fn uniq_when_keys_out_of_order() {
 
     assert_eq!(actual, "1");
 }
+
+#[test]
+fn returns_only_repeated_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b a c b a]
+            | uniq --repeated
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["a","b"]"#);
+}
+
+#[test]
+fn returns_only_values_seen_once() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b a c b d]
+            | uniq --unique
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["c","d"]"#);
+}
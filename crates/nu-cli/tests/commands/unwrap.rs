@@ -0,0 +1,47 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn unwraps_single_column_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | wrap number
+            | unwrap
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "6");
+}
+
+#[test]
+fn unwraps_rows_from_json() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres"}, {"name": "jonathan"}]'
+            | from-json
+            | unwrap
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["andres","jonathan"]"#);
+}
+
+#[test]
+fn errors_on_rows_with_more_than_one_column() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres", "age": 10}]'
+            | from-json
+            | unwrap
+        "#
+    ));
+
+    assert!(actual.contains("Unwrap needs rows with exactly one column"));
+}
@@ -0,0 +1,47 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn inserts_a_missing_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés"}'
+            | from-json
+            | upsert rusty_luck 1
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"Andrés","rusty_luck":1}"#);
+}
+
+#[test]
+fn updates_an_existing_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "rusty_luck": 0}'
+            | from-json
+            | upsert rusty_luck 1
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"Andrés","rusty_luck":1}"#);
+}
+
+#[test]
+fn computes_the_value_from_the_row_with_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés", "rusty_luck": 1}, {"name": "Jonathan", "rusty_luck": 2}]'
+            | from-json
+            | upsert rusty_luck {= $it.rusty_luck * 10}
+            | get rusty_luck
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[10,20]");
+}
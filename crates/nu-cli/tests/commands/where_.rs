@@ -166,3 +166,121 @@ fn contains_operator() {
 
     assert_eq!(actual, "2");
 }
+
+#[test]
+fn block_condition_can_reference_columns_through_it() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "andres", "age": 28}, {"name": "jonathan", "age": 35}, {"name": "yehuda", "age": 40}]'
+            | from-json
+            | where { $it.age > 30 }
+            | get name
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["jonathan","yehuda"]"#);
+}
+
+#[test]
+fn block_condition_drops_rows_that_do_not_match() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "andres", "age": 28}, {"name": "jonathan", "age": 35}]'
+            | from-json
+            | where { $it.age > 50 }
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn block_condition_checks_membership_in_a_list_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "andres", "roles": ["admin", "dev"]}, {"name": "jonathan", "roles": ["dev"]}, {"name": "yehuda", "roles": ["admin"]}]'
+            | from-json
+            | where { "admin" in $it.roles }
+            | get name
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["andres","yehuda"]"#);
+}
+
+#[test]
+fn block_condition_excludes_rows_by_membership_in_a_list_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "andres", "roles": ["admin", "dev"]}, {"name": "jonathan", "roles": ["dev"]}, {"name": "yehuda", "roles": ["admin"]}]'
+            | from-json
+            | where { "admin" not-in: $it.roles }
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "jonathan");
+}
+
+#[test]
+fn filters_with_bare_comparison_on_a_column() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo '[{"name": "bob", "age": 42}, {"name": "alice", "age": 25}]' | from-json | where age > 30 | get name | echo $it"#
+    );
+
+    assert_eq!(actual, "bob");
+}
+
+#[test]
+fn filters_with_bare_string_equality_on_a_column() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo '[{"name": "bob", "age": 42}, {"name": "alice", "age": 25}]' | from-json | where name == "bob" | get age | echo $it"#
+    );
+
+    assert_eq!(actual, "42");
+}
+
+#[test]
+fn leaves_out_rows_with_nothing_instead_of_erroring() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "size": 12}, {"name": "b", "size": null}, {"name": "c", "size": 3}]'
+            | from-json
+            | where size > -1
+            | get name
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["a","c"]"#);
+}
+
+#[test]
+fn compares_nothing_with_strings_without_erroring() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "city": "Lima"}, {"name": "b", "city": null}]'
+            | from-json
+            | where city < "Quito"
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a");
+}
@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::fs::{file_contents, Stub::FileWithContentToBeTrimmed};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
@@ -89,7 +89,8 @@ fn infers_types() {
         let actual = nu!(
             cwd: dirs.test(), pipeline(
             r#"
-                open los_cuatro_mosqueteros.csv
+                open los_cuatro_mosqueteros.csv --raw
+                | from-csv --infer
                 | where rusty_luck > 0
                 | count
                 | echo $it
@@ -210,3 +211,314 @@ fn from_csv_text_skipping_headers_to_table() {
         assert_eq!(actual, "3");
     })
 }
+
+#[test]
+fn from_csv_text_larger_than_a_read_buffer() {
+    Playground::setup("filter_from_csv_test_large", |dirs, sandbox| {
+        let mut content = String::from("index,square\n");
+        for i in 0..20_000 {
+            content.push_str(&format!("{},{}\n", i, i * i));
+        }
+
+        sandbox.with_files(vec![nu_test_support::fs::Stub::FileWithContent(
+            "large.txt",
+            &content,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open large.txt
+                | from-csv --infer
+                | where index == 19999
+                | get square
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "399960001");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open large.txt
+                | from-csv
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "20000");
+    })
+}
+
+#[test]
+fn from_csv_text_with_trim_strips_unquoted_cells() {
+    Playground::setup("filter_from_csv_test_trim_1", |dirs, sandbox| {
+        sandbox.with_files(vec![nu_test_support::fs::Stub::FileWithContent(
+            "padded.txt",
+            "first_name ,  last_name\n  Andrés  ,   Robalino \n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open padded.txt
+                | from-csv --trim
+                | format "[{first_name}|{last_name}]"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "[Andrés|Robalino]");
+    })
+}
+
+#[test]
+fn from_csv_text_with_trim_keeps_quoted_whitespace() {
+    Playground::setup("filter_from_csv_test_trim_2", |dirs, sandbox| {
+        sandbox.with_files(vec![nu_test_support::fs::Stub::FileWithContent(
+            "padded.txt",
+            "name,motto\n  Jonathan  ,  \"  hello  \"  \n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open padded.txt
+                | from-csv --trim
+                | format "[{name}|{motto}]"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "[Jonathan|  hello  ]");
+    })
+}
+
+#[test]
+fn from_csv_text_with_infer_converts_cell_types() {
+    Playground::setup("filter_from_csv_test_infer_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.txt",
+            r#"
+                first_name,rusty_luck,height,admin
+                Andrés,1,1.75,true
+                Jonathan,1,1.8,false
+                Yehuda,1,1.7,false
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.txt
+                | from-csv --infer
+                | where admin
+                | get height
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, "1.75");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.txt
+                | from-csv --infer
+                | get rusty_luck
+                | sum
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "3");
+    })
+}
+
+#[test]
+fn from_csv_text_leaves_cells_as_strings_without_infer() {
+    Playground::setup("filter_from_csv_test_no_infer", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "zip_codes.txt",
+            r#"
+                city,zip,offices
+                Boston,02134,1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open zip_codes.txt
+                | from-csv
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"city":"Boston","zip":"02134","offices":"1"}"#);
+    })
+}
+
+#[test]
+fn from_csv_text_with_infer_leaves_ambiguous_cells_as_strings() {
+    Playground::setup("filter_from_csv_test_infer_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "zip_codes.txt",
+            r#"
+                city,zip
+                Boston,02134
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open zip_codes.txt
+                | from-csv --infer
+                | get zip
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "02134");
+    })
+}
+
+#[test]
+fn to_csv_headerless_leaves_out_the_header_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés", "luck": 1}, {"name": "Jonathan", "luck": 2}]'
+            | from-json
+            | to-csv --headerless
+        "#
+    ));
+
+    assert!(!actual.contains("name"));
+    assert_eq!(actual, "Andrés,1Jonathan,2");
+}
+
+#[test]
+fn to_csv_headerless_keeps_the_first_row_column_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"b": 1, "a": 2}, {"a": 3, "b": 4}]'
+            | from-json
+            | to-csv --headerless
+        "#
+    ));
+
+    assert_eq!(actual, "1,24,3");
+}
+
+#[test]
+fn from_csv_strips_a_leading_byte_order_mark() {
+    Playground::setup("filter_from_csv_test_bom_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.csv",
+            "
+                \u{feff}first_name,rusty_luck
+                Andrés,1
+                Jonathan,2
+            ",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.csv
+                | get first_name
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["Andrés","Jonathan"]"#);
+    })
+}
+
+#[test]
+fn from_csv_keeps_the_first_header_without_a_byte_order_mark() {
+    Playground::setup("filter_from_csv_test_bom_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.txt",
+            r#"
+                first_name,rusty_luck
+                Andrés,1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.txt
+                | from-csv
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Andrés");
+    })
+}
+
+#[test]
+fn to_csv_ends_lines_with_crlf_when_asked() {
+    Playground::setup("to_csv_test_crlf_1", |dirs, _| {
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo '[{"name": "Andrés", "rusty_luck": 1}, {"name": "Jonathan", "rusty_luck": 0}]'
+                | from-json
+                | to-csv --crlf
+                | save --raw amigos.csv
+            "#
+        ));
+
+        let actual = file_contents(dirs.test().join("amigos.csv"));
+
+        assert_eq!(actual, "name,rusty_luck\r\nAndrés,1\r\nJonathan,0\r\n");
+    })
+}
+
+#[test]
+fn to_csv_ends_lines_with_lf_by_default() {
+    Playground::setup("to_csv_test_crlf_2", |dirs, _| {
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo '[{"name": "Andrés", "rusty_luck": 1}, {"name": "Jonathan", "rusty_luck": 0}]'
+                | from-json
+                | to-csv
+                | save --raw amigos.csv
+            "#
+        ));
+
+        let actual = file_contents(dirs.test().join("amigos.csv"));
+
+        assert!(!actual.contains('\r'));
+        assert_eq!(actual, "name,rusty_luck\nAndrés,1\nJonathan,0\n");
+    })
+}
+
+#[test]
+fn from_csv_reads_crlf_lines() {
+    Playground::setup("to_csv_test_crlf_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo '[{"name": "Andrés", "rusty_luck": 1}, {"name": "Jonathan", "rusty_luck": 0}]'
+                | from-json
+                | to-csv --crlf
+                | from-csv
+                | get name
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["Andrés","Jonathan"]"#);
+    })
+}
@@ -1,6 +1,6 @@
 use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
 use nu_test_support::playground::Playground;
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn table_to_json_text_and_from_json_text_back_into_table() {
@@ -101,3 +101,390 @@ fn table_to_json_text() {
         assert_eq!(actual, "JonAndrehudaTZ");
     })
 }
+
+#[test]
+fn integers_beyond_i64_round_trip() {
+    Playground::setup("filter_to_json_test_big_ints", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "big.json",
+            r#"
+                {"big": 18446744073709551615}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open big.json | to-json | echo $it"
+        );
+
+        assert_eq!(actual, r#"{"big":18446744073709551615}"#);
+    })
+}
+
+#[test]
+fn integers_beyond_u64_are_written_as_floats() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "= 10000000000 * 10000000000 | to-json"
+    );
+
+    assert_eq!(actual.parse::<f64>().unwrap(), 1e20);
+}
+
+#[test]
+fn writes_compact_json_by_default() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"name": "nu", "tags": [1, 2]}'
+            | from-json
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"nu","tags":[1,2]}"#);
+}
+
+#[test]
+fn writes_compact_json_with_raw() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"name": "nu"}'
+            | from-json
+            | to-json --raw
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"nu"}"#);
+}
+
+#[test]
+fn writes_pretty_json() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"name": "nu"}'
+            | from-json
+            | to-json --pretty
+            | lines
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn errors_when_pretty_and_raw_are_both_given() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"name": "nu"}'
+            | from-json
+            | to-json --pretty --raw
+        "#
+    ));
+
+    assert!(actual.contains("Conflicting flags: --pretty and --raw"));
+}
+
+#[test]
+fn writes_finite_decimals() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo 1.5 | to-json | echo $it"
+    );
+
+    assert_eq!(actual, "1.5");
+}
+
+#[test]
+fn errors_on_decimals_without_a_finite_json_number() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        r#"echo "1e400" | into --float | to-json"#
+    );
+
+    assert!(actual.contains("Could not convert non-finite decimal to JSON"));
+}
+
+#[test]
+fn writes_null_for_decimals_without_a_finite_json_number_when_lenient() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo "1e400" | into --float | to-json --lenient"#
+    );
+
+    assert_eq!(actual, "null");
+}
+
+#[test]
+fn keeps_null_in_decimal_columns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"x": 1.5}, {"x": null}]'
+            | from-json
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"[{"x":1.5},{"x":null}]"#);
+}
+
+#[test]
+fn keeps_record_key_order() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"zebra": 1, "apple": 2, "mango": 3}'
+            | from-json
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"zebra":1,"apple":2,"mango":3}"#);
+}
+
+#[test]
+fn binary_round_trips_through_json() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.bson --raw
+            | to-json
+            | from-json
+            | from-bson
+            | get root
+            | get 1.b
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "whel");
+}
+
+#[test]
+fn dates_are_written_as_iso_8601_strings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq-date 2020-02-29 2020-02-29
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#""2020-02-29T00:00:00+00:00""#);
+}
+
+#[test]
+fn from_json_reads_iso_8601_strings_as_dates_with_dates_flag() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"at": "2020-02-29T13:45:00+01:00"}'
+            | from-json --dates
+            | get at
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#""2020-02-29T12:45:00+00:00""#);
+}
+
+#[test]
+fn from_json_keeps_iso_8601_strings_as_strings_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"at": "2020-02-29T13:45:00+01:00"}'
+            | from-json
+            | get at
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#""2020-02-29T13:45:00+01:00""#);
+}
+
+#[test]
+fn to_json_indents_with_tabs() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "langs": ["rust"]}'
+            | from-json
+            | to-json --tabs
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        "{\t\"name\": \"Andrés\",\t\"langs\": [\t\t\"rust\"\t]}"
+    );
+}
+
+#[test]
+fn to_json_indents_with_the_given_number_of_spaces() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés"}'
+            | from-json
+            | to-json --indent 4
+        "#
+    ));
+
+    assert_eq!(actual, "{    \"name\": \"Andrés\"}");
+}
+
+#[test]
+fn to_json_tabs_conflicts_with_indent() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés"}'
+            | from-json
+            | to-json --tabs --indent 4
+        "#
+    ));
+
+    assert!(actual.contains("Conflicting flags: --tabs and --indent"));
+}
+
+#[test]
+fn from_json_errors_name_the_line_and_column_of_the_syntax_error() {
+    Playground::setup("filter_from_json_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "broken.txt",
+            r#"
+                {
+                    "name": "Yehuda",
+                    "rusty_luck": ]
+                }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open broken.txt | from-json"
+        );
+
+        assert!(actual.contains("Could not parse as JSON"));
+        assert!(actual.contains("at line 3 column"));
+    })
+}
+
+#[test]
+fn from_json_objects_errors_name_the_line_within_the_whole_input() {
+    Playground::setup("filter_from_json_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.txt",
+            r#"
+                {"name":   "Yehuda", "rusty_luck": 1}
+                {"name": "Jonathan", "rusty_luck": 1}
+                {"name":   "Andres", "rusty_luck": }
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(),
+            "open katz.txt | from-json -o | to-json"
+        );
+
+        assert!(actual.contains("at line 3 column"));
+    })
+}
+
+#[test]
+fn from_json_errors_point_at_the_syntax_error_in_pipeline_text() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "rusty_luck": ]}'
+            | from-json
+        "#
+    ));
+
+    assert!(actual.contains(
+        "(found a punctuator character when expecting a quoteless string at line 1 column"
+    ));
+    assert!(actual.contains("syntax error at line 1"));
+}
+
+#[test]
+fn to_json_refuses_values_nested_past_max_depth() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": {"b": {"c": 1}}}'
+            | from-json
+            | to-json --max-depth 2
+        "#
+    ));
+
+    assert!(actual.contains("Could not convert to JSON: nested more than 2 levels deep"));
+}
+
+#[test]
+fn to_json_writes_values_nested_up_to_max_depth() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": {"b": {"c": 1}}}'
+            | from-json
+            | to-json --max-depth 3
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"a":{"b":{"c":1}}}"#);
+}
+
+#[test]
+fn to_json_refuses_deeply_nested_values_by_default() {
+    let deep = format!("{}1{}", r#"{"a": "#.repeat(200), "}".repeat(200));
+
+    let actual = nu_error!(
+        cwd: ".",
+        &format!("echo '{}' | from-json | to-json", deep)
+    );
+
+    assert!(actual.contains("nested more than 128 levels deep"));
+}
+
+#[test]
+fn to_json_sorts_keys_when_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "age": 4, "meta": {"z": 1, "a": 2}}'
+            | from-json
+            | to-json --sort-keys
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"age":4,"meta":{"a":2,"z":1},"name":"nu"}"#);
+}
+
+#[test]
+fn to_json_keeps_column_order_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "age": 4, "meta": {"z": 1, "a": 2}}'
+            | from-json
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"nu","age":4,"meta":{"z":1,"a":2}}"#);
+}
@@ -5,9 +5,11 @@ mod html;
 mod ics;
 mod json;
 mod markdown;
+mod nuon;
 mod ods;
 mod sqlite;
 mod ssv;
+mod text;
 mod toml;
 mod tsv;
 mod url;
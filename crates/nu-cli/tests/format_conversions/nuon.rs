@@ -0,0 +1,108 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn table_to_nuon_text_keeps_units() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo {name: "nu", size: 2kb, age: 90s}
+            | to-nuon
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{name: "nu", size: 2048b, age: 90s}"#);
+}
+
+#[test]
+fn table_to_nuon_text_and_from_nuon_text_back_into_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo {name: "nu", size: 2kb, age: 90s}
+            | to-nuon
+            | from-nuon
+            | to-nuon
+            | echo $it
+        "#
+    ));
+
+    // A file size or duration read back as a string would come out quoted here
+    assert_eq!(actual, r#"{name: "nu", size: 2048b, age: 90s}"#);
+}
+
+#[test]
+fn nested_table_to_nuon_text_and_back() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo {dir: "nu", files: [{name: "a", size: 1kb} {name: "b", size: 2kb}]}
+            | to-nuon
+            | from-nuon
+            | to-nuon
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{dir: "nu", files: [{name: "a", size: 1024b}, {name: "b", size: 2048b}]}"#
+    );
+}
+
+#[test]
+fn from_nuon_text_to_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{name: "Yehuda", luck: 1}, {name: "Jonathan", luck: 2}]'
+            | from-nuon
+            | get luck
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn errors_reading_a_malformed_row() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{name: "nu", size:}'
+            | from-nuon
+        "#
+    ));
+
+    assert!(actual.contains("Could not parse as nuon"));
+}
+
+#[test]
+fn from_nuon_reads_the_literal_variables() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[$true, $false, $nothing]'
+            | from-nuon
+            | to-nuon
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[$true, $false, $nothing]");
+}
+
+#[test]
+fn errors_reading_a_variable() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{home: $nu.env.HOME}'
+            | from-nuon
+        "#
+    ));
+
+    assert!(actual.contains("nuon can only hold literals"));
+}
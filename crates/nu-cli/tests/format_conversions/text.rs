@@ -0,0 +1,79 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn ends_text_with_a_newline_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b]
+            | to-text
+            | size
+            | get chars
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "4");
+}
+
+#[test]
+fn leaves_out_the_trailing_newline() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b]
+            | to-text --no-newline
+            | size
+            | get chars
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn joins_rows_with_a_custom_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | to-text --no-newline --separator ", "
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a, b, c");
+}
+
+#[test]
+fn renders_records_as_key_value_lines() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "version": 13}'
+            | from-json
+            | to-text --no-newline --separator "|"
+            | lines
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["name: nu","version: 13"]"#);
+}
+
+#[test]
+fn renders_records_as_json() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "version": 13}'
+            | from-json
+            | to-text --json --no-newline
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"name":"nu","version":13}"#);
+}
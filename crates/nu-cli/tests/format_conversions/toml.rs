@@ -15,3 +15,35 @@ fn table_to_toml_text_and_from_toml_text_back_into_table() {
 
     assert_eq!(actual, "nu");
 }
+
+#[test]
+fn keeps_record_key_order_when_writing() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"zebra": 1, "apple": 2, "mango": 3}'
+            | from-json
+            | to-toml
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.contains("zebra = 1apple = 2mango = 3"));
+}
+
+#[test]
+fn keeps_record_key_order_when_reading() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"zebra": 1, "apple": 2, "mango": 3}'
+            | from-json
+            | to-toml
+            | from-toml
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"zebra":1,"apple":2,"mango":3}"#);
+}
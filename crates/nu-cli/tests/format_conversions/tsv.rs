@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::fs::{file_contents, Stub::FileWithContentToBeTrimmed};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
@@ -134,3 +134,69 @@ fn from_tsv_text_skipping_headers_to_table() {
         assert_eq!(actual, "3");
     })
 }
+
+#[test]
+fn from_tsv_text_with_trim_strips_unquoted_cells() {
+    Playground::setup("filter_from_tsv_test_trim", |dirs, sandbox| {
+        sandbox.with_files(vec![nu_test_support::fs::Stub::FileWithContent(
+            "padded.txt",
+            "first_name \t last_name\n Andrés \t \"  Robalino\" \n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open padded.txt
+                | from-tsv --trim
+                | format "[{first_name}|{last_name}]"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "[Andrés|  Robalino]");
+    })
+}
+
+#[test]
+fn from_tsv_strips_a_leading_byte_order_mark() {
+    Playground::setup("filter_from_tsv_test_bom_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.txt",
+            "
+                \u{feff}first_name\trusty_luck
+                Andrés\t1
+            ",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.txt
+                | from-tsv
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Andrés");
+    })
+}
+
+#[test]
+fn to_tsv_ends_lines_with_crlf_when_asked() {
+    Playground::setup("to_tsv_test_crlf_1", |dirs, _| {
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo '[{"name": "Andrés", "rusty_luck": 1}]'
+                | from-json
+                | to-tsv --crlf
+                | save --raw amigos.tsv
+            "#
+        ));
+
+        let actual = file_contents(dirs.test().join("amigos.tsv"));
+
+        assert_eq!(actual, "name\trusty_luck\r\nAndrés\t1\r\n");
+    })
+}
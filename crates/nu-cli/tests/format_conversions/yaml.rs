@@ -1,4 +1,6 @@
-use nu_test_support::{nu, pipeline};
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn table_to_yaml_text_and_from_yaml_text_back_into_table() {
@@ -15,3 +17,172 @@ fn table_to_yaml_text_and_from_yaml_text_back_into_table() {
 
     assert_eq!(actual, "nushell");
 }
+
+#[test]
+fn keeps_record_key_order_when_writing() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"zebra": 1, "apple": 2, "mango": 3}'
+            | from-json
+            | to-yaml
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.contains("zebra: 1apple: 2mango: 3"));
+}
+
+#[test]
+fn keeps_record_key_order_when_reading() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"zebra": 1, "apple": 2, "mango": 3}'
+            | from-json
+            | to-yaml
+            | from-yaml
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"{"zebra":1,"apple":2,"mango":3}"#);
+}
+
+#[test]
+fn binary_round_trips_through_yaml() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.bson --raw
+            | to-yaml
+            | from-yaml
+            | from-bson
+            | get root
+            | get 1.b
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "whel");
+}
+
+#[test]
+fn to_yaml_refuses_values_nested_past_max_depth() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": {"b": {"c": 1}}}'
+            | from-json
+            | to-yaml --max-depth 2
+        "#
+    ));
+
+    assert!(actual.contains("Could not convert to YAML: nested more than 2 levels deep"));
+}
+
+#[test]
+fn from_yaml_reads_each_document_as_a_value() {
+    Playground::setup("filter_from_yaml_test_documents_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "caballeros.txt",
+            r#"
+                ---
+                name: Andrés
+                ---
+                name: Jonathan
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.txt
+                | from-yaml
+                | get name
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["Andrés","Jonathan"]"#);
+    })
+}
+
+#[test]
+fn to_yaml_writes_each_value_as_a_document_with_documents_flag() {
+    Playground::setup("filter_to_yaml_test_documents_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "caballeros.txt",
+            r#"
+                ---
+                name: Andrés
+                ---
+                name: Jonathan
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.txt
+                | from-yaml
+                | to-yaml --documents
+                | lines
+                | where $it == "---"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.txt
+                | from-yaml
+                | to-yaml --documents
+                | from-yaml
+                | get name
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"["Andrés","Jonathan"]"#);
+    })
+}
+
+#[test]
+fn to_yaml_sorts_keys_when_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "age": 4}'
+            | from-json
+            | to-yaml --sort-keys
+            | lines
+            | nth 1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "age: 4");
+}
+
+#[test]
+fn to_yaml_keeps_column_order_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "age": 4}'
+            | from-json
+            | to-yaml
+            | lines
+            | nth 1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "name: nu");
+}
@@ -6,8 +6,8 @@ mod shapes;
 mod signature;
 
 pub use crate::files::Files;
-pub use crate::lite_parse::{lite_parse, LiteBlock};
-pub use crate::parse::{classify_block, garbage};
+pub use crate::lite_parse::{continues_on_next_line, lite_parse, LiteBlock};
+pub use crate::parse::{classify_block, garbage, parse_literal};
 pub use crate::path::expand_ndots;
 pub use crate::shapes::shapes;
-pub use crate::signature::{Signature, SignatureRegistry};
+pub use crate::signature::{parse_signature, signature_from_tokens, Signature, SignatureRegistry};
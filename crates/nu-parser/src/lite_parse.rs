@@ -43,12 +43,37 @@ fn skip_whitespace(src: &mut Input) {
     while let Some((_, x)) = src.peek() {
         if x.is_whitespace() {
             let _ = src.next();
+        } else if *x == '#' {
+            skip_comment(src);
         } else {
             break;
         }
     }
 }
 
+/// Skips whitespace up to the end of the line, as a newline ends the pipeline
+fn skip_whitespace_on_line(src: &mut Input) {
+    while let Some((_, x)) = src.peek() {
+        if x.is_whitespace() && *x != '\n' {
+            let _ = src.next();
+        } else if *x == '#' {
+            skip_comment(src);
+        } else {
+            break;
+        }
+    }
+}
+
+/// A `#` at the start of a word comments out the rest of the line
+fn skip_comment(src: &mut Input) {
+    while let Some((_, x)) = src.peek() {
+        if *x == '\n' {
+            break;
+        }
+        let _ = src.next();
+    }
+}
+
 fn bare(src: &mut Input, span_offset: usize) -> Result<Spanned<String>, ParseError> {
     skip_whitespace(src);
 
@@ -104,6 +129,36 @@ fn bare(src: &mut Input, span_offset: usize) -> Result<Spanned<String>, ParseErr
     Ok(bare.spanned(span))
 }
 
+/// Whether the pipeline written so far carries on to the next line, as it does after a trailing
+/// `|` or while a `[`, `{` or `(` is still open
+pub fn continues_on_next_line(text: &str) -> bool {
+    let mut delimiter = ' ';
+    let mut inside_quote = false;
+    let mut block_level = vec![];
+
+    for c in text.chars() {
+        if inside_quote {
+            if c == delimiter {
+                inside_quote = false;
+            }
+        } else if c == '\'' || c == '"' {
+            inside_quote = true;
+            delimiter = c;
+        } else if c == '[' || c == '{' || c == '(' {
+            block_level.push(c);
+        } else if (c == ']' && block_level.last() == Some(&'['))
+            || (c == '}' && block_level.last() == Some(&'{'))
+            || (c == ')' && block_level.last() == Some(&'('))
+        {
+            let _ = block_level.pop();
+        }
+    }
+
+    let text = text.trim_end();
+
+    !block_level.is_empty() || (text.ends_with('|') && !text.ends_with("||"))
+}
+
 fn command(src: &mut Input, span_offset: usize) -> Result<LiteCommand, ParseError> {
     let command = bare(src, span_offset)?;
     if command.item.is_empty() {
@@ -119,22 +174,31 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
 
     skip_whitespace(src);
 
-    while src.peek().is_some() {
+    while let Some((_, c)) = src.peek() {
+        if *c == ';' && commands.is_empty() {
+            // empty statements between semicolons are skipped
+            let _ = src.next();
+            skip_whitespace(src);
+            continue;
+        }
+
         // If there is content there, let's parse it
 
         let mut cmd = match command(src, span_offset) {
             Ok(cmd) => cmd,
             Err(e) => return Err(e),
         };
+        let mut end_of_pipeline = false;
 
         loop {
-            skip_whitespace(src);
+            skip_whitespace_on_line(src);
 
             if let Some((_, c)) = src.peek() {
                 // The first character tells us a lot about each argument
                 match c {
-                    ';' => {
+                    ';' | '\n' => {
                         // this is the end of the command and the end of the pipeline
+                        end_of_pipeline = true;
                         break;
                     }
                     '|' => {
@@ -171,9 +235,8 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
             }
         }
         commands.push(cmd);
-        skip_whitespace(src);
 
-        if let Some((_, ';')) = src.peek() {
+        if end_of_pipeline {
             let _ = src.next();
 
             if !commands.is_empty() {
@@ -181,6 +244,9 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
                 commands = vec![];
             }
         }
+
+        // a pipeline ending in `|` carries on past the newline
+        skip_whitespace(src);
     }
 
     if !commands.is_empty() {
@@ -215,3 +281,89 @@ fn lite_simple_offset() -> Result<(), ParseError> {
 
     Ok(())
 }
+
+#[test]
+fn lite_pipeline_continues_after_pipe() -> Result<(), ParseError> {
+    let result = lite_parse("ls |\n    where size > 10", 0)?;
+    assert_eq!(result.block.len(), 1);
+    assert_eq!(result.block[0].commands.len(), 2);
+    assert_eq!(result.block[0].commands[1].name.item, "where");
+    assert_eq!(result.block[0].commands[1].args.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn lite_pipeline_continues_inside_brackets() -> Result<(), ParseError> {
+    assert!(continues_on_next_line("ls |"));
+    assert!(continues_on_next_line("echo [1 2"));
+    assert!(continues_on_next_line("ls | where { = $it.size >"));
+    assert!(!continues_on_next_line("echo [1 2]"));
+    assert!(!continues_on_next_line("echo '['"));
+    assert!(!continues_on_next_line("ls || echo"));
+
+    let result = lite_parse("echo [1\n 2 3] | count", 0)?;
+    assert_eq!(result.block.len(), 1);
+    assert_eq!(result.block[0].commands.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn lite_newline_separates_pipelines() -> Result<(), ParseError> {
+    let result = lite_parse("ls\necho 1", 0)?;
+    assert_eq!(result.block.len(), 2);
+    assert_eq!(result.block[0].commands.len(), 1);
+    assert_eq!(result.block[1].commands[0].name.item, "echo");
+    assert_eq!(result.block[1].commands[0].name.span.start(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn lite_semicolons_separate_pipelines() -> Result<(), ParseError> {
+    let result = lite_parse("ls; echo 1;", 0)?;
+    assert_eq!(result.block.len(), 2);
+    assert_eq!(result.block[0].commands[0].name.item, "ls");
+    assert_eq!(result.block[1].commands[0].name.item, "echo");
+
+    Ok(())
+}
+
+#[test]
+fn lite_skips_empty_statements() -> Result<(), ParseError> {
+    let result = lite_parse("; ls;; ;echo 1", 0)?;
+    assert_eq!(result.block.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn lite_skips_full_line_comments() -> Result<(), ParseError> {
+    let result = lite_parse("# list the files\nls", 0)?;
+    assert_eq!(result.block.len(), 1);
+    assert_eq!(result.block[0].commands[0].name.item, "ls");
+    assert!(result.block[0].commands[0].args.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn lite_skips_trailing_comments() -> Result<(), ParseError> {
+    let result = lite_parse("echo a # and not b", 0)?;
+    assert_eq!(result.block.len(), 1);
+    assert_eq!(result.block[0].commands[0].args.len(), 1);
+    assert_eq!(result.block[0].commands[0].args[0].item, "a");
+
+    Ok(())
+}
+
+#[test]
+fn lite_keeps_hashes_inside_strings() -> Result<(), ParseError> {
+    let result = lite_parse("echo \"a # b\" c#d", 0)?;
+    assert_eq!(result.block[0].commands[0].args.len(), 2);
+    assert_eq!(result.block[0].commands[0].args[0].item, "\"a # b\"");
+    assert_eq!(result.block[0].commands[0].args[1].item, "c#d");
+
+    Ok(())
+}
@@ -10,7 +10,10 @@ use nu_protocol::hir::{
     Expression, Flag, FlagKind, InternalCommand, Member, NamedArguments, Operator,
     SpannedExpression, Unit,
 };
-use nu_protocol::{NamedType, PositionalType, Signature, SyntaxShape, UnspannedPathMember};
+use nu_protocol::{
+    NamedType, PathMember, PositionalType, RangeInclusion, Signature, SyntaxShape,
+    UnspannedPathMember,
+};
 use nu_source::{Span, Spanned, SpannedItem};
 use num_bigint::BigInt;
 
@@ -38,12 +41,7 @@ fn parse_simple_column_path(lite_arg: &Spanned<String>) -> (SpannedExpression, O
                 lite_arg.span.start() + idx,
             );
 
-            if let Ok(row_number) = current_part.parse::<u64>() {
-                output.push(Member::Int(BigInt::from(row_number), part_span));
-            } else {
-                let trimmed = trim_quotes(&current_part);
-                output.push(Member::Bare(trimmed.clone().spanned(part_span)));
-            }
+            output.push(simple_member(&current_part, part_span));
             current_part.clear();
             // Note: I believe this is safe because of the delimiter we're using, but if we get fancy with
             // unicode we'll need to change this
@@ -58,12 +56,7 @@ fn parse_simple_column_path(lite_arg: &Spanned<String>) -> (SpannedExpression, O
             lite_arg.span.start() + start_index,
             lite_arg.span.start() + last_index + 1,
         );
-        if let Ok(row_number) = current_part.parse::<u64>() {
-            output.push(Member::Int(BigInt::from(row_number), part_span));
-        } else {
-            let current_part = trim_quotes(&current_part);
-            output.push(Member::Bare(current_part.spanned(part_span)));
-        }
+        output.push(simple_member(&current_part, part_span));
     }
 
     (
@@ -72,6 +65,49 @@ fn parse_simple_column_path(lite_arg: &Spanned<String>) -> (SpannedExpression, O
     )
 }
 
+/// Splits a trailing `?` off a column path part, which marks the part as optional. A quoted
+/// part, like `"foo?"`, ends with its quote instead, so it still names a column ending in `?`
+fn optional_part(part: &str) -> (&str, bool) {
+    if part.len() > 1 && part.ends_with('?') {
+        (&part[..part.len() - 1], true)
+    } else {
+        (part, false)
+    }
+}
+
+fn simple_member(part: &str, part_span: Span) -> Member {
+    let (part, optional) = optional_part(part);
+    let part_span = Span::new(part_span.start(), part_span.start() + part.len());
+
+    let member = if let Ok(row_number) = part.parse::<u64>() {
+        Member::Int(BigInt::from(row_number), part_span)
+    } else {
+        Member::Bare(trim_quotes(part).spanned(part_span))
+    };
+
+    if optional {
+        Member::Optional(Box::new(member))
+    } else {
+        member
+    }
+}
+
+fn path_member(part: &str, part_span: Span) -> PathMember {
+    let (part, optional) = optional_part(part);
+
+    let member = if let Ok(row_number) = part.parse::<u64>() {
+        UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span)
+    } else {
+        UnspannedPathMember::String(trim_quotes(part)).into_path_member(part_span)
+    };
+
+    if optional {
+        member.into_optional()
+    } else {
+        member
+    }
+}
+
 /// Parses a column path, adding in the preceding reference to $it if it's elided
 fn parse_full_column_path(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
     let mut delimiter = '.';
@@ -101,15 +137,8 @@ fn parse_full_column_path(lite_arg: &Spanned<String>) -> (SpannedExpression, Opt
             if head.is_none() && current_part.clone().starts_with('$') {
                 // We have the variable head
                 head = Some(Expression::variable(current_part.clone(), part_span))
-            } else if let Ok(row_number) = current_part.parse::<u64>() {
-                output.push(
-                    UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
-                );
             } else {
-                let current_part = trim_quotes(&current_part);
-                output.push(
-                    UnspannedPathMember::String(current_part.clone()).into_path_member(part_span),
-                );
+                output.push(path_member(&current_part, part_span));
             }
             current_part.clear();
             // Note: I believe this is safe because of the delimiter we're using, but if we get fancy with
@@ -130,21 +159,11 @@ fn parse_full_column_path(lite_arg: &Spanned<String>) -> (SpannedExpression, Opt
             if current_part.starts_with('$') {
                 // We have the variable head
                 head = Some(Expression::variable(current_part, lite_arg.span));
-            } else if let Ok(row_number) = current_part.parse::<u64>() {
-                output.push(
-                    UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
-                );
             } else {
-                let current_part = trim_quotes(&current_part);
-                output.push(UnspannedPathMember::String(current_part).into_path_member(part_span));
+                output.push(path_member(&current_part, part_span));
             }
-        } else if let Ok(row_number) = current_part.parse::<u64>() {
-            output.push(
-                UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
-            );
         } else {
-            let current_part = trim_quotes(&current_part);
-            output.push(UnspannedPathMember::String(current_part).into_path_member(part_span));
+            output.push(path_member(&current_part, part_span));
         }
     }
 
@@ -183,9 +202,21 @@ fn trim_quotes(input: &str) -> String {
     }
 }
 
+fn is_quoted(input: &str) -> bool {
+    input.len() >= 2 && trim_quotes(input).len() == input.len() - 2
+}
+
 /// Parse a numeric range
 fn parse_range(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
-    let numbers: Vec<_> = lite_arg.item.split("..").collect();
+    // `..` and `..=` include the right end of the range, `..<` stops just before it
+    let (dotdot, inclusion) = if lite_arg.item.contains("..=") {
+        ("..=", RangeInclusion::Inclusive)
+    } else if lite_arg.item.contains("..<") {
+        ("..<", RangeInclusion::Exclusive)
+    } else {
+        ("..", RangeInclusion::Inclusive)
+    };
+    let numbers: Vec<_> = lite_arg.item.split(dotdot).collect();
 
     if numbers.len() != 2 {
         (
@@ -200,6 +231,7 @@ fn parse_range(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseEr
                         SpannedExpression::new(Expression::integer(lhs), lite_arg.span),
                         lite_arg.span,
                         SpannedExpression::new(Expression::integer(rhs), lite_arg.span),
+                        inclusion,
                     ),
                     lite_arg.span,
                 ),
@@ -219,6 +251,98 @@ fn parse_range(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseEr
     }
 }
 
+/// Parse a row literal, eg) {name: "bob" age: 30}
+/// Returns None if the braces hold a block instead of a row.
+fn parse_row(
+    registry: &dyn SignatureRegistry,
+    lite_arg: &Spanned<String>,
+) -> Option<(SpannedExpression, Option<ParseError>)> {
+    let mut chars = lite_arg.item.chars();
+    let string: String = match (chars.next(), chars.next_back()) {
+        (Some('{'), Some('}')) => chars.collect(),
+        _ => return None,
+    };
+
+    let lite_block = lite_parse(&string, lite_arg.span.start() + 1).ok()?;
+    let lite_command = match lite_block.block.as_slice() {
+        [pipeline] if pipeline.commands.len() == 1 => &pipeline.commands[0],
+        _ => return None,
+    };
+
+    // A row starts with a column name, which is how we tell it apart from a block
+    if !is_row_key(&lite_command.name) {
+        return None;
+    }
+
+    let mut items = std::iter::once(&lite_command.name)
+        .chain(lite_command.args.iter())
+        .filter_map(trim_list_separator);
+    let mut entries: Vec<(Spanned<String>, SpannedExpression)> = vec![];
+    let mut error = None;
+
+    while let Some(key) = items.next() {
+        if !is_row_key(&key) {
+            return Some((
+                garbage(lite_arg.span),
+                Some(ParseError::mismatch("column name", key)),
+            ));
+        }
+
+        let name = trim_quotes(&key.item[..key.item.len() - 1]);
+        let name = name.spanned(Span::new(key.span.start(), key.span.end() - 1));
+
+        if entries
+            .iter()
+            .any(|(existing, _)| existing.item == name.item)
+        {
+            return Some((
+                garbage(lite_arg.span),
+                Some(ParseError::mismatch("unique column name", name)),
+            ));
+        }
+
+        let value = match items.next() {
+            Some(value) => value,
+            None => {
+                return Some((
+                    garbage(lite_arg.span),
+                    Some(ParseError::unexpected_eof("column value", lite_arg.span)),
+                ))
+            }
+        };
+
+        let (value, err) = parse_arg(SyntaxShape::Any, registry, &value);
+        if error.is_none() {
+            error = err;
+        }
+
+        entries.push((name, value));
+    }
+
+    Some((
+        SpannedExpression::new(Expression::Row(entries), lite_arg.span),
+        error,
+    ))
+}
+
+fn is_row_key(item: &Spanned<String>) -> bool {
+    item.item.len() > 1 && item.item.ends_with(':') && item.item != "in:" && item.item != "not-in:"
+}
+
+/// List items may be separated by commas as well as whitespace, eg) [1, 2, 3]
+fn trim_list_separator(item: &Spanned<String>) -> Option<Spanned<String>> {
+    if item.item == "," {
+        None
+    } else if item.item.ends_with(',') {
+        let trimmed = item.item[..item.item.len() - 1].to_string();
+        let span = Span::new(item.span.start(), item.span.end() - 1);
+
+        Some(trimmed.spanned(span))
+    } else {
+        Some(item.clone())
+    }
+}
+
 /// Parse any allowed operator, including word-based operators
 fn parse_operator(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
     let operator = if lite_arg.item == "==" {
@@ -245,7 +369,11 @@ fn parse_operator(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<Pars
         Operator::Multiply
     } else if lite_arg.item == "/" {
         Operator::Divide
-    } else if lite_arg.item == "in:" {
+    } else if lite_arg.item == "//" {
+        Operator::IntegerDivide
+    } else if lite_arg.item == "%" {
+        Operator::Modulo
+    } else if lite_arg.item == "in:" || lite_arg.item == "in" {
         Operator::In
     } else if lite_arg.item == "not-in:" {
         Operator::NotIn
@@ -390,6 +518,10 @@ fn parse_arg(
         SyntaxShape::ColumnPath => parse_simple_column_path(lite_arg),
         SyntaxShape::FullColumnPath => parse_full_column_path(lite_arg),
         SyntaxShape::Any => {
+            if let Some(row) = parse_row(registry, lite_arg) {
+                return row;
+            }
+
             let shapes = vec![
                 SyntaxShape::Int,
                 SyntaxShape::Number,
@@ -414,8 +546,8 @@ fn parse_arg(
 
             match (chars.next(), chars.next_back()) {
                 (Some('['), Some(']')) => {
-                    // We have a literal row
-                    let string: String = chars.collect();
+                    // We have a literal row, whose items can be laid out over several lines
+                    let string: String = chars.map(|c| if c == '\n' { ' ' } else { c }).collect();
                     let mut error = None;
 
                     // We haven't done much with the inner string, so let's go ahead and work with it
@@ -440,15 +572,12 @@ fn parse_arg(
                     let lite_pipeline = lite_block.block[0].clone();
                     let mut output = vec![];
                     for lite_inner in &lite_pipeline.commands {
-                        let (arg, err) = parse_arg(SyntaxShape::Any, registry, &lite_inner.name);
+                        let items = std::iter::once(&lite_inner.name)
+                            .chain(lite_inner.args.iter())
+                            .filter_map(trim_list_separator);
 
-                        output.push(arg);
-                        if error.is_none() {
-                            error = err;
-                        }
-
-                        for arg in &lite_inner.args {
-                            let (arg, err) = parse_arg(SyntaxShape::Any, registry, &arg);
+                        for item in items {
+                            let (arg, err) = parse_arg(SyntaxShape::Any, registry, &item);
                             output.push(arg);
 
                             if error.is_none() {
@@ -508,6 +637,29 @@ fn parse_arg(
     }
 }
 
+/// Parses text holding a single literal, like a row `{name: "nu"}` or a list `[1kb 2kb]`
+pub fn parse_literal(
+    source: &str,
+    registry: &dyn SignatureRegistry,
+) -> (SpannedExpression, Option<ParseError>) {
+    let trimmed = source.trim();
+    let lite_arg = trimmed.to_string().spanned(Span::new(0, trimmed.len()));
+
+    // Without this, a malformed list or row would be taken as a bare string
+    if trimmed.starts_with('[') {
+        parse_arg(SyntaxShape::Table, registry, &lite_arg)
+    } else if trimmed.starts_with('{') {
+        parse_row(registry, &lite_arg).unwrap_or_else(|| {
+            (
+                garbage(lite_arg.span),
+                Some(ParseError::mismatch("row", lite_arg.clone())),
+            )
+        })
+    } else {
+        parse_arg(SyntaxShape::Any, registry, &lite_arg)
+    }
+}
+
 /// Match the available flags in a signature with what the user provided. This will check both long-form flags (--full) and shorthand flags (-f)
 /// This also allows users to provide a group of shorthand flags (-af) that correspond to multiple shorthand flags at once.
 fn get_flags_from_flag(
@@ -575,12 +727,19 @@ fn get_flags_from_flag(
 fn shorthand_reparse(
     left: SpannedExpression,
     orig_left: Option<Spanned<String>>,
+    right: Option<&SpannedExpression>,
     registry: &dyn SignatureRegistry,
     shorthand_mode: bool,
 ) -> (SpannedExpression, Option<ParseError>) {
     // If we're in shorthand mode, we need to reparse the left-hand side if possible
     if shorthand_mode {
         if let Some(orig_left) = orig_left {
+            // A quoted left-hand side compared against $it is a value, not a column,
+            // eg) `where { "admin" in $it.roles }`
+            if is_quoted(&orig_left.item) && right.map_or(false, |r| r.has_shallow_it_usage()) {
+                return (left, None);
+            }
+
             parse_arg(SyntaxShape::FullColumnPath, registry, &orig_left)
         } else {
             (left, None)
@@ -728,7 +887,8 @@ fn parse_math_expression(
                         working_exprs.pop().expect("This shouldn't be possible");
 
                     // If we're in shorthand mode, we need to reparse the left-hand side if possibe
-                    let (left, err) = shorthand_reparse(left, orig_left, registry, shorthand_mode);
+                    let (left, err) =
+                        shorthand_reparse(left, orig_left, Some(&right), registry, shorthand_mode);
                     if error.is_none() {
                         error = err;
                     }
@@ -767,7 +927,8 @@ fn parse_math_expression(
         let (_, op) = working_exprs.pop().expect("This shouldn't be possible");
         let (orig_left, left) = working_exprs.pop().expect("This shouldn't be possible");
 
-        let (left, err) = shorthand_reparse(left, orig_left, registry, shorthand_mode);
+        let (left, err) =
+            shorthand_reparse(left, orig_left, Some(&right), registry, shorthand_mode);
         if error.is_none() {
             error = err;
         }
@@ -783,7 +944,7 @@ fn parse_math_expression(
     }
 
     let (orig_left, left) = working_exprs.pop().expect("This shouldn't be possible");
-    let (left, err) = shorthand_reparse(left, orig_left, registry, shorthand_mode);
+    let (left, err) = shorthand_reparse(left, orig_left, None, registry, shorthand_mode);
     if error.is_none() {
         error = err;
     }
@@ -791,6 +952,49 @@ fn parse_math_expression(
     (incoming_idx + idx, left, error)
 }
 
+/// Parses an explicit block given where a condition is expected, eg `where { $it.size > 10 }`.
+/// If the block holds a bare comparison rather than a command, it is parsed as a math expression
+/// with the left-hand side allowed to be a column path (implicitly or explicitly rooted at $it).
+fn parse_math_block(
+    lite_arg: &Spanned<String>,
+    registry: &dyn SignatureRegistry,
+) -> (SpannedExpression, Option<ParseError>) {
+    let mut chars = lite_arg.item.chars();
+
+    if let (Some('{'), Some('}')) = (chars.next(), chars.next_back()) {
+        let string: String = chars.collect();
+
+        let lite_block = match lite_parse(&string, lite_arg.span.start() + 1) {
+            Ok(lb) => lb,
+            Err(e) => return (garbage(lite_arg.span), Some(e)),
+        };
+
+        if lite_block.block.len() == 1 && lite_block.block[0].commands.len() == 1 {
+            let lite_cmd = &lite_block.block[0].commands[0];
+
+            if lite_cmd.name.item != "=" && registry.get(&lite_cmd.name.item).is_none() {
+                let mut collection = vec![lite_cmd.name.clone()];
+                collection.extend(lite_cmd.args.iter().cloned());
+
+                let (_, expr, err) = parse_math_expression(0, &collection[..], registry, true);
+
+                let span = expr.span;
+                let mut commands = hir::Commands::new(span);
+                commands.push(ClassifiedCommand::Expr(Box::new(expr)));
+                let mut block = hir::Block::new(span);
+                block.push(commands);
+
+                return (
+                    SpannedExpression::new(Expression::Block(block), lite_arg.span),
+                    err,
+                );
+            }
+        }
+    }
+
+    parse_arg(SyntaxShape::Math, registry, lite_arg)
+}
+
 /// Handles parsing the positional arguments as a batch
 /// This allows us to check for times where multiple arguments are treated as one shape, as is the case with SyntaxShape::Math
 fn parse_positional_argument(
@@ -810,7 +1014,7 @@ fn parse_positional_argument(
             if idx < lite_cmd.args.len() {
                 if lite_cmd.args[idx].item.starts_with('{') {
                     // It's an explicit math expression, so parse it deeper in
-                    let (arg, err) = parse_arg(SyntaxShape::Math, registry, &lite_cmd.args[idx]);
+                    let (arg, err) = parse_math_block(&lite_cmd.args[idx], registry);
                     if error.is_none() {
                         error = err;
                     }
@@ -855,6 +1059,11 @@ fn parse_positional_argument(
     (idx, arg, error)
 }
 
+/// Negative numbers start with a dash like flags do, but are passed along as arguments.
+fn is_negative_number(arg: &str) -> bool {
+    arg.parse::<f64>().is_ok() && arg.chars().nth(1).map_or(false, |c| c.is_ascii_digit())
+}
+
 /// Does a full parse of an internal command using the lite-ly parse command as a starting point
 /// This main focus at this level is to understand what flags were passed in, what positional arguments were passed in, what rest arguments were passed in
 /// and to ensure that the basic requirements in terms of number of each were met.
@@ -878,7 +1087,10 @@ fn parse_internal_command(
     let mut error = None;
 
     while idx < lite_cmd.args.len() {
-        if lite_cmd.args[idx].item.starts_with('-') && lite_cmd.args[idx].item.len() > 1 {
+        if lite_cmd.args[idx].item.starts_with('-')
+            && lite_cmd.args[idx].item.len() > 1
+            && !is_negative_number(&lite_cmd.args[idx].item)
+        {
             let (named_types, err) =
                 get_flags_from_flag(&signature, &lite_cmd.name, &lite_cmd.args[idx]);
 
@@ -980,10 +1192,10 @@ fn parse_internal_command(
     if positional.len() < required_arg_count && error.is_none() {
         // to make "command -h" work even if required arguments are missing
         if !named.named.contains_key("help") {
-            let (_, name) = &signature.positional[positional.len()];
+            let (positional_type, _) = &signature.positional[positional.len()];
             error = Some(ParseError::argument_error(
                 lite_cmd.name.clone(),
-                ArgumentError::MissingMandatoryPositional(name.to_owned()),
+                ArgumentError::MissingMandatoryPositional(positional_type.name().to_owned()),
             ));
         }
     }
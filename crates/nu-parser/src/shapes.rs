@@ -15,6 +15,14 @@ pub fn expression_to_flat_shape(e: &SpannedExpression) -> Vec<Spanned<FlatShape>
             }
             output
         }
+        Expression::Row(entries) => {
+            let mut output = vec![];
+            for (key, expr) in entries.iter() {
+                output.push(FlatShape::BareMember.spanned(key.span));
+                output.append(&mut expression_to_flat_shape(expr));
+            }
+            output
+        }
         Expression::Path(exprs) => {
             let mut output = vec![];
             output.append(&mut expression_to_flat_shape(&exprs.head));
@@ -1,6 +1,9 @@
 use std::fmt::Debug;
 
-use nu_source::{DebugDocBuilder, HasSpan, PrettyDebugWithSource, Span};
+use crate::lite_parse::lite_parse;
+use nu_errors::ParseError;
+use nu_protocol::SyntaxShape;
+use nu_source::{DebugDocBuilder, HasSpan, PrettyDebugWithSource, Span, Spanned, SpannedItem};
 
 pub trait SignatureRegistry: Debug {
     fn has(&self, name: &str) -> bool;
@@ -46,3 +49,171 @@ impl PrettyDebugWithSource for Signature {
         self.unspanned.pretty_debug(source)
     }
 }
+
+/// Parse the bracketed parameter list of a custom command, eg) `[name: string, age?: int, --loud]`
+pub fn parse_signature(
+    name: &str,
+    params: &Spanned<String>,
+) -> (nu_protocol::Signature, Option<ParseError>) {
+    let mut chars = params.item.chars();
+
+    let inner: String = match (chars.next(), chars.next_back()) {
+        (Some('['), Some(']')) => chars.collect(),
+        _ => {
+            return (
+                nu_protocol::Signature::build(name),
+                Some(ParseError::mismatch("parameter list", params.clone())),
+            )
+        }
+    };
+
+    let lite_block = match lite_parse(&inner, params.span.start() + 1) {
+        Ok(lite_block) => lite_block,
+        Err(err) => return (nu_protocol::Signature::build(name), Some(err)),
+    };
+
+    let tokens: Vec<Spanned<String>> = lite_block
+        .block
+        .iter()
+        .flat_map(|pipeline| pipeline.commands.iter())
+        .flat_map(|command| std::iter::once(command.name.clone()).chain(command.args.clone()))
+        .collect();
+
+    signature_from_tokens(name, &tokens)
+}
+
+/// Build a signature from the words of a parameter list. Each parameter is
+/// either a positional `name`, an optional positional `name?` or a flag
+/// `--name`, optionally followed by a type, eg) `name: int`
+pub fn signature_from_tokens(
+    name: &str,
+    tokens: &[Spanned<String>],
+) -> (nu_protocol::Signature, Option<ParseError>) {
+    let mut signature = nu_protocol::Signature::build(name);
+    let mut tokens = tokens
+        .iter()
+        .map(|token| {
+            token
+                .item
+                .trim_end_matches(',')
+                .to_string()
+                .spanned(token.span)
+        })
+        .filter(|token| !token.item.is_empty())
+        .peekable();
+
+    while let Some(token) = tokens.next() {
+        let (param, shape) = match token.item.find(':') {
+            Some(idx) if idx + 1 == token.item.len() => match tokens.next() {
+                Some(shape) => (token.item[..idx].to_string(), Some(shape)),
+                None => {
+                    return (
+                        signature,
+                        Some(ParseError::unexpected_eof("parameter type", token.span)),
+                    )
+                }
+            },
+            Some(idx) => (
+                token.item[..idx].to_string(),
+                Some(token.item[idx + 1..].to_string().spanned(token.span)),
+            ),
+            None => (token.item.clone(), None),
+        };
+
+        let shape = match shape {
+            Some(shape) => match parse_syntax_shape(&shape.item) {
+                Some(shape) => shape,
+                None => return (signature, Some(ParseError::mismatch("type", shape))),
+            },
+            None => SyntaxShape::Any,
+        };
+
+        signature = if param.starts_with("--") {
+            let flag = &param[2..];
+
+            match shape {
+                SyntaxShape::Any if !token.item.contains(':') => signature.switch(flag, "", None),
+                shape => signature.named(flag, shape, "", None),
+            }
+        } else if param.ends_with('?') {
+            signature.optional(&param[..param.len() - 1], shape, "")
+        } else {
+            signature.required(param, shape, "")
+        };
+    }
+
+    (signature, None)
+}
+
+fn parse_syntax_shape(name: &str) -> Option<SyntaxShape> {
+    match name {
+        "any" => Some(SyntaxShape::Any),
+        "string" => Some(SyntaxShape::String),
+        "int" => Some(SyntaxShape::Int),
+        "number" => Some(SyntaxShape::Number),
+        "path" => Some(SyntaxShape::Path),
+        "pattern" => Some(SyntaxShape::Pattern),
+        "range" => Some(SyntaxShape::Range),
+        "block" => Some(SyntaxShape::Block),
+        "table" => Some(SyntaxShape::Table),
+        "unit" => Some(SyntaxShape::Unit),
+        "column-path" => Some(SyntaxShape::ColumnPath),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::{NamedType, PositionalType};
+
+    fn signature(params: &str) -> nu_protocol::Signature {
+        let (signature, err) = parse_signature(
+            "greet",
+            &params.to_string().spanned(Span::new(0, params.len())),
+        );
+        assert!(err.is_none(), "{:?}", err);
+        signature
+    }
+
+    #[test]
+    fn typed_positional() {
+        let signature = signature("[name: string]");
+
+        match &signature.positional[0].0 {
+            PositionalType::Mandatory(name, SyntaxShape::String) => assert_eq!(name, "name"),
+            other => panic!("unexpected positional {:?}", other),
+        }
+    }
+
+    #[test]
+    fn optional_positional() {
+        let signature = signature("[name, times?: int]");
+
+        assert_eq!(signature.positional.len(), 2);
+        match &signature.positional[1].0 {
+            PositionalType::Optional(name, SyntaxShape::Int) => assert_eq!(name, "times"),
+            other => panic!("unexpected positional {:?}", other),
+        }
+    }
+
+    #[test]
+    fn switch() {
+        let signature = signature("[name: string, --loud]");
+
+        match signature.named.get("loud") {
+            Some((NamedType::Switch(None), _)) => {}
+            other => panic!("unexpected flag {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_type() {
+        let (_, err) = parse_signature(
+            "greet",
+            &"[name: str]".to_string().spanned(Span::new(0, 11)),
+        );
+
+        assert!(err.is_some());
+    }
+}
@@ -5,7 +5,7 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{hir, Primitive, UntaggedValue};
+use crate::{hir, Primitive, RangeInclusion, UntaggedValue};
 use crate::{PathMember, ShellTypeName};
 use derive_new::new;
 use num_traits::ToPrimitive;
@@ -314,6 +314,7 @@ pub enum Member {
     String(/* outer */ Span, /* inner */ Span),
     Int(BigInt, Span),
     Bare(Spanned<String>),
+    Optional(Box<Member>),
 }
 
 impl Member {
@@ -324,6 +325,7 @@ impl Member {
             Member::Bare(spanned_string) => {
                 PathMember::string(spanned_string.item.clone(), spanned_string.span)
             }
+            Member::Optional(member) => member.to_path_member().into_optional(),
             _ => unimplemented!("Need to finish to_path_member"),
         }
     }
@@ -335,6 +337,7 @@ impl PrettyDebugWithSource for Member {
             Member::String(outer, _) => b::value(outer.slice(source)),
             Member::Int(int, _) => b::value(format!("{}", int)),
             Member::Bare(span) => b::value(span.span.slice(source)),
+            Member::Optional(member) => member.pretty_debug(source) + b::operator("?"),
         }
     }
 }
@@ -345,6 +348,7 @@ impl HasSpan for Member {
             Member::String(outer, ..) => *outer,
             Member::Int(_, int) => *int,
             Member::Bare(name) => name.span,
+            Member::Optional(member) => member.span(),
         }
     }
 }
@@ -548,7 +552,10 @@ impl SpannedExpression {
                 // Higher precedence binds tighter
 
                 match operator {
-                    Operator::Multiply | Operator::Divide => 100,
+                    Operator::Multiply
+                    | Operator::Divide
+                    | Operator::IntegerDivide
+                    | Operator::Modulo => 100,
                     Operator::Plus | Operator::Minus => 90,
                     Operator::NotContains
                     | Operator::Contains
@@ -632,6 +639,19 @@ impl PrettyDebugWithSource for SpannedExpression {
                     ),
                     "]",
                 ),
+                Expression::Row(row) => b::delimit(
+                    "{",
+                    b::intersperse(
+                        row.iter().map(|(key, value)| {
+                            b::key(key.item.clone())
+                                + b::delimiter(":")
+                                + b::space()
+                                + value.refined_pretty_debug(refine, source)
+                        }),
+                        b::space(),
+                    ),
+                    "}",
+                ),
                 Expression::Path(path) => path.pretty_debug(source),
                 Expression::FilePath(path) => b::typed("path", b::primitive(path.display())),
                 Expression::ExternalCommand(external) => {
@@ -671,6 +691,19 @@ impl PrettyDebugWithSource for SpannedExpression {
                 ),
                 "]",
             ),
+            Expression::Row(row) => b::delimit(
+                "{",
+                b::intersperse(
+                    row.iter().map(|(key, value)| {
+                        b::key(key.item.clone())
+                            + b::delimiter(":")
+                            + b::space()
+                            + value.pretty_debug(source)
+                    }),
+                    b::space(),
+                ),
+                "}",
+            ),
             Expression::Path(path) => path.pretty_debug(source),
             Expression::FilePath(path) => b::typed("path", b::primitive(path.display())),
             Expression::ExternalCommand(external) => b::typed(
@@ -708,6 +741,8 @@ pub enum Operator {
     Minus,
     Multiply,
     Divide,
+    IntegerDivide,
+    Modulo,
     In,
     NotIn,
     And,
@@ -754,6 +789,7 @@ pub struct Range {
     pub left: SpannedExpression,
     pub dotdot: Span,
     pub right: SpannedExpression,
+    pub inclusion: RangeInclusion,
 }
 
 impl PrettyDebugWithSource for Range {
@@ -877,6 +913,7 @@ pub enum Expression {
     Range(Box<Range>),
     Block(hir::Block),
     List(Vec<SpannedExpression>),
+    Row(Vec<(Spanned<String>, SpannedExpression)>),
     Path(Box<Path>),
 
     FilePath(PathBuf),
@@ -901,6 +938,7 @@ impl ShellTypeName for Expression {
             Expression::FilePath(..) => "file path",
             Expression::Variable(..) => "variable",
             Expression::List(..) => "list",
+            Expression::Row(..) => "row",
             Expression::Binary(..) => "binary",
             Expression::Range(..) => "range",
             Expression::Block(..) => "block",
@@ -940,11 +978,17 @@ impl Expression {
         Expression::Literal(Literal::Operator(operator))
     }
 
-    pub fn range(left: SpannedExpression, dotdot: Span, right: SpannedExpression) -> Expression {
+    pub fn range(
+        left: SpannedExpression,
+        dotdot: Span,
+        right: SpannedExpression,
+        inclusion: RangeInclusion,
+    ) -> Expression {
         Expression::Range(Box::new(Range {
             left,
             dotdot,
             right,
+            inclusion,
         }))
     }
 
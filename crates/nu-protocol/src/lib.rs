@@ -25,3 +25,21 @@ pub use crate::value::primitive::Primitive;
 pub use crate::value::primitive::{format_date, format_duration, format_primitive};
 pub use crate::value::range::{Range, RangeInclusion};
 pub use crate::value::{merge_descriptors, UntaggedValue, Value};
+
+#[cfg(test)]
+mod tests {
+    use super::{hir::Block, ReturnValue, Scope, UntaggedValue, Value};
+    use nu_errors::ShellError;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn values_errors_and_scopes_can_be_shared_across_threads() {
+        assert_send_sync::<Value>();
+        assert_send_sync::<UntaggedValue>();
+        assert_send_sync::<Block>();
+        assert_send_sync::<ShellError>();
+        assert_send_sync::<ReturnValue>();
+        assert_send_sync::<Scope>();
+    }
+}
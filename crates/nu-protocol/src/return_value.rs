@@ -1,4 +1,5 @@
 use crate::hir::Block;
+use crate::signature::Signature;
 use crate::value::Value;
 use nu_errors::ShellError;
 use nu_source::{b, DebugDocBuilder, PrettyDebug};
@@ -23,6 +24,8 @@ pub enum CommandAction {
     EnterHelpShell(Value),
     /// Enter the help shell, which allows exploring the help system
     AddAlias(String, Vec<String>, Block),
+    /// Add a custom command with the given signature, which runs the block
+    AddCommand(Signature, Block),
     /// Go to the previous shell in the shell ring buffer
     PreviousShell,
     /// Go to the next shell in the shell ring buffer
@@ -45,6 +48,9 @@ impl PrettyDebug for CommandAction {
             CommandAction::EnterValueShell(v) => b::typed("enter value shell", v.pretty()),
             CommandAction::EnterHelpShell(v) => b::typed("enter help shell", v.pretty()),
             CommandAction::AddAlias(..) => b::description("add alias"),
+            CommandAction::AddCommand(signature, _) => {
+                b::typed("add command", b::description(&signature.name))
+            }
             CommandAction::PreviousShell => b::description("previous shell"),
             CommandAction::NextShell => b::description("next shell"),
             CommandAction::LeaveShell => b::description("leave shell"),
@@ -17,13 +17,15 @@ use crate::value::primitive::Primitive;
 use crate::value::range::{Range, RangeInclusion};
 use crate::{ColumnPath, PathMember};
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_source::{AnchorLocation, HasSpan, Span, Spanned, Tag};
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::time::SystemTime;
 
@@ -215,10 +217,10 @@ impl UntaggedValue {
 
     /// Helper for creating datatime values
     pub fn system_date(s: SystemTime) -> UntaggedValue {
-        UntaggedValue::Primitive(Primitive::Date(s.into()))
+        UntaggedValue::Primitive(Primitive::Date(DateTime::<Utc>::from(s).into()))
     }
 
-    pub fn date(d: impl Into<DateTime<Utc>>) -> UntaggedValue {
+    pub fn date(d: impl Into<DateTime<FixedOffset>>) -> UntaggedValue {
         UntaggedValue::Primitive(Primitive::Date(d.into()))
     }
 
@@ -229,12 +231,45 @@ impl UntaggedValue {
 }
 
 /// The fundamental structured value that flows through the pipeline, with associated metadata
-#[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Hash, Serialize, Deserialize)]
+///
+/// Values compare, order and hash by their contents alone, all the way down through nested
+/// rows and tables. The tag only says where a value came from, so the same value read from two
+/// places is still equal. Rows are equal when they hold the same columns with equal values,
+/// whatever order the columns are in (see `Dictionary`), and decimals, being exact
+/// `BigDecimal`s rather than floats, have no NaN and are equal (and hash equally) however many
+/// trailing zeros they are written with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Value {
     pub value: UntaggedValue,
     pub tag: Tag,
 }
 
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Value) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
 /// Overload deferencing to give back the UntaggedValue inside of a Value
 impl std::ops::Deref for Value {
     type Target = UntaggedValue;
@@ -308,6 +343,14 @@ impl Value {
         }
     }
 
+    /// View the Value as signed 64-bit, if possible
+    pub fn as_i64(&self) -> Result<i64, ShellError> {
+        match &self.value {
+            UntaggedValue::Primitive(primitive) => primitive.as_i64(self.tag.span),
+            _ => Err(ShellError::type_error("integer", self.spanned_type_name())),
+        }
+    }
+
     /// View the Value as boolean, if possible
     pub fn as_bool(&self) -> Result<bool, ShellError> {
         match &self.value {
@@ -18,6 +18,7 @@ impl UnspannedPathMember {
         PathMember {
             unspanned: self,
             span: span.into(),
+            optional: false,
         }
     }
 }
@@ -27,6 +28,9 @@ impl UnspannedPathMember {
 pub struct PathMember {
     pub unspanned: UnspannedPathMember,
     pub span: Span,
+    /// Marked with a trailing `?`, eg) `address?.city`; a missing optional member gives nothing instead of an error
+    #[serde(default)]
+    pub optional: bool,
 }
 
 impl PrettyDebug for &PathMember {
@@ -99,6 +103,14 @@ impl PathMember {
     pub fn int(int: impl Into<BigInt>, span: impl Into<Span>) -> PathMember {
         UnspannedPathMember::Int(int.into()).into_path_member(span)
     }
+
+    /// The same path member, marked as optional
+    pub fn into_optional(self) -> PathMember {
+        PathMember {
+            optional: true,
+            ..self
+        }
+    }
 }
 
 /// Prepares a list of "sounds like" matches for the string you're trying to find
@@ -10,6 +10,10 @@ use std::cmp::{Ord, Ordering, PartialOrd};
 use std::hash::{Hash, Hasher};
 
 /// A dictionary that can hold a mapping from names to Values
+///
+/// Two dictionaries are equal when they hold the same keys with equal values, in any order.
+/// Hashing and ordering follow suit by looking at the entries sorted by key, so column order
+/// never makes otherwise equal rows hash or sort differently.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone, Getters, new)]
 pub struct Dictionary {
     #[get = "pub"]
@@ -20,44 +24,21 @@ pub struct Dictionary {
 impl Hash for Dictionary {
     /// Create the hash function to allow the Hash trait for dictionaries
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut entries = self.entries.clone();
-        entries.sort_keys();
-        entries.keys().collect::<Vec<&String>>().hash(state);
-        entries.values().collect::<Vec<&Value>>().hash(state);
+        self.sorted_entries().hash(state);
     }
 }
 
 impl PartialOrd for Dictionary {
     /// Compare two dictionaries for sort ordering
     fn partial_cmp(&self, other: &Dictionary) -> Option<Ordering> {
-        let this: Vec<&String> = self.entries.keys().collect();
-        let that: Vec<&String> = other.entries.keys().collect();
-
-        if this != that {
-            return this.partial_cmp(&that);
-        }
-
-        let this: Vec<&Value> = self.entries.values().collect();
-        let that: Vec<&Value> = self.entries.values().collect();
-
-        this.partial_cmp(&that)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Dictionary {
-    /// Compare two dictionaries for ordering
+    /// Compare two dictionaries for ordering, entry by entry in key order
     fn cmp(&self, other: &Dictionary) -> Ordering {
-        let this: Vec<&String> = self.entries.keys().collect();
-        let that: Vec<&String> = other.entries.keys().collect();
-
-        if this != that {
-            return this.cmp(&that);
-        }
-
-        let this: Vec<&Value> = self.entries.values().collect();
-        let that: Vec<&Value> = self.entries.values().collect();
-
-        this.cmp(&that)
+        self.sorted_entries().cmp(&other.sorted_entries())
     }
 }
 
@@ -115,6 +96,13 @@ impl From<IndexMap<String, Value>> for Dictionary {
 }
 
 impl Dictionary {
+    /// The entries sorted by key, for comparing and hashing regardless of column order
+    fn sorted_entries(&self) -> Vec<(&String, &Value)> {
+        let mut entries: Vec<(&String, &Value)> = self.entries.iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+    }
+
     /// Find the matching Value for a given key, if possible. If not, return a Primitive::Nothing
     pub fn get_data(&self, desc: &str) -> MaybeOwned<'_, Value> {
         match self.entries.get(desc) {
@@ -239,3 +227,98 @@ impl From<TaggedDictBuilder> for Value {
         input.into_value()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{UntaggedValue, Value};
+    use bigdecimal::BigDecimal;
+    use indexmap::IndexMap;
+    use nu_source::{Span, Tag};
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::str::FromStr;
+
+    fn hash_of(value: &Value) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn at(value: UntaggedValue, start: usize) -> Value {
+        value.into_value(Tag::from(Span::new(start, start + 1)))
+    }
+
+    fn row(entries: Vec<(&str, Value)>) -> Value {
+        let mut map = IndexMap::new();
+        for (key, value) in entries {
+            map.insert(key.to_string(), value);
+        }
+        UntaggedValue::row(map).into_untagged_value()
+    }
+
+    #[test]
+    fn values_from_different_places_are_equal_and_hash_equally() {
+        let here = at(UntaggedValue::string("andres"), 0);
+        let there = at(UntaggedValue::string("andres"), 10);
+
+        assert_eq!(here, there);
+        assert_eq!(hash_of(&here), hash_of(&there));
+    }
+
+    #[test]
+    fn nested_rows_and_tables_compare_by_contents() {
+        let first = UntaggedValue::table(&[row(vec![("name", at(UntaggedValue::int(1), 0))])])
+            .into_untagged_value();
+        let second = UntaggedValue::table(&[row(vec![("name", at(UntaggedValue::int(1), 5))])])
+            .into_untagged_value();
+
+        assert_eq!(first, second);
+        assert_eq!(hash_of(&first), hash_of(&second));
+        assert_eq!(first.cmp(&second), Ordering::Equal);
+    }
+
+    #[test]
+    fn rows_with_the_same_columns_in_another_order_are_equal() {
+        let ordered = row(vec![
+            (
+                "name",
+                UntaggedValue::string("andres").into_untagged_value(),
+            ),
+            ("luck", UntaggedValue::int(1).into_untagged_value()),
+        ]);
+        let reordered = row(vec![
+            ("luck", UntaggedValue::int(1).into_untagged_value()),
+            (
+                "name",
+                UntaggedValue::string("andres").into_untagged_value(),
+            ),
+        ]);
+
+        assert_eq!(ordered, reordered);
+        assert_eq!(hash_of(&ordered), hash_of(&reordered));
+        assert_eq!(ordered.cmp(&reordered), Ordering::Equal);
+    }
+
+    #[test]
+    fn rows_with_different_values_are_ordered_by_them() {
+        let less = row(vec![("luck", UntaggedValue::int(1).into_untagged_value())]);
+        let more = row(vec![("luck", UntaggedValue::int(2).into_untagged_value())]);
+
+        assert_ne!(less, more);
+        assert_eq!(less.cmp(&more), Ordering::Less);
+        assert_eq!(more.cmp(&less), Ordering::Greater);
+    }
+
+    #[test]
+    fn decimals_written_with_trailing_zeros_are_equal_and_hash_equally() {
+        let short = UntaggedValue::decimal(BigDecimal::from_str("1.5").unwrap());
+        let long = UntaggedValue::decimal(BigDecimal::from_str("1.500").unwrap());
+
+        let short = short.into_untagged_value();
+        let long = long.into_untagged_value();
+
+        assert_eq!(short, long);
+        assert_eq!(hash_of(&short), hash_of(&long));
+    }
+}
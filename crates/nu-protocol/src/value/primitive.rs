@@ -3,7 +3,7 @@ use crate::value::column_path::ColumnPath;
 use crate::value::range::Range;
 use crate::value::{serde_bigdecimal, serde_bigint};
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use nu_errors::{ExpectedRange, ShellError};
 use nu_source::{PrettyDebug, Span, SpannedItem};
 use num_bigint::BigInt;
@@ -38,8 +38,8 @@ pub enum Primitive {
     Pattern(String),
     /// A boolean value
     Boolean(bool),
-    /// A date value, in UTC
-    Date(DateTime<Utc>),
+    /// A date value, with the offset it was given in
+    Date(DateTime<FixedOffset>),
     /// A count in the number of seconds
     Duration(i64),
     /// A range of values
@@ -75,6 +75,24 @@ impl Primitive {
         }
     }
 
+    /// Converts a primitive value to an i64, if possible. Uses a span to build an error if the conversion isn't possible.
+    pub fn as_i64(&self, span: Span) -> Result<i64, ShellError> {
+        match self {
+            Primitive::Int(int) => match int.to_i64() {
+                None => Err(ShellError::range_error(
+                    ExpectedRange::I64,
+                    &format!("{}", int).spanned(span),
+                    "converting an integer into a 64-bit integer",
+                )),
+                Some(num) => Ok(num),
+            },
+            other => Err(ShellError::type_error(
+                "integer",
+                other.type_name().spanned(span),
+            )),
+        }
+    }
+
     pub fn into_string(self, span: Span) -> Result<String, ShellError> {
         match self {
             Primitive::String(s) => Ok(s),
@@ -300,8 +318,8 @@ pub fn format_duration(sec: i64) -> String {
 }
 
 #[allow(clippy::cognitive_complexity)]
-/// Format a UTC date value into a humanized string (eg "1 week ago" instead of a formal date string)
-pub fn format_date(d: &DateTime<Utc>) -> String {
+/// Format a date value into a humanized string (eg "1 week ago" instead of a formal date string)
+pub fn format_date(d: &DateTime<FixedOffset>) -> String {
     let utc: DateTime<Utc> = Utc::now();
 
     let duration = utc.signed_duration_since(*d);
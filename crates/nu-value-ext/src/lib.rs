@@ -133,14 +133,26 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                     let mut out = vec![];
 
                     for item in l {
-                        if let Value {
-                            value: UntaggedValue::Row(o),
-                            ..
-                        } = item
-                        {
-                            if let Some(v) = o.get_data_by_key(string[..].spanned(name.span)) {
-                                out.push(v)
+                        match item {
+                            Value {
+                                value: UntaggedValue::Row(o),
+                                ..
+                            } => {
+                                if let Some(v) = o.get_data_by_key(string[..].spanned(name.span)) {
+                                    out.push(v)
+                                }
                             }
+                            // A table nested in a table (eg. a list column fetched from
+                            // each row), map over its rows too
+                            Value {
+                                value: UntaggedValue::Table(_),
+                                ..
+                            } => {
+                                if let Ok(v) = get_data_by_member(item, name) {
+                                    out.push(v)
+                                }
+                            }
+                            _ => {}
                         }
                     }
 
@@ -188,6 +200,7 @@ pub fn get_data_by_column_path(
 
         match value {
             Ok(v) => current = v.clone(),
+            Err(_) if p.optional => return Ok(UntaggedValue::nothing().into_value(&current.tag)),
             Err(e) => return Err(callback((&current, &p.clone(), e))),
         }
     }
@@ -195,6 +208,42 @@ pub fn get_data_by_column_path(
     Ok(current)
 }
 
+/// The column path with each column name swapped for the one `value` actually uses, where the
+/// two only differ by case. Names with an exact match, or no match at all, are left alone.
+pub fn column_path_ignoring_case(value: &Value, path: &ColumnPath) -> ColumnPath {
+    let mut current = Some(value.clone());
+    let mut members = vec![];
+
+    for member in path.iter() {
+        let resolved = match (&member.unspanned, &current) {
+            (UnspannedPathMember::String(name), Some(value)) => {
+                let columns = match &value.value {
+                    UntaggedValue::Table(rows) => nu_protocol::merge_descriptors(rows),
+                    other => other.data_descriptors(),
+                };
+
+                match columns.iter().find(|column| *column == name).or_else(|| {
+                    columns
+                        .iter()
+                        .find(|column| column.eq_ignore_ascii_case(name))
+                }) {
+                    Some(column) => PathMember {
+                        unspanned: UnspannedPathMember::String(column.clone()),
+                        ..member.clone()
+                    },
+                    None => member.clone(),
+                }
+            }
+            _ => member.clone(),
+        };
+
+        current = current.and_then(|value| get_data_by_member(&value, &resolved).ok());
+        members.push(resolved);
+    }
+
+    ColumnPath::new(members)
+}
+
 pub fn insert_data_at_path(value: &Value, path: &str, new_value: Value) -> Option<Value> {
     let mut new_obj = value.clone();
 
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::strutils::ReplaceAction;
+use crate::strutils::{ReplaceAction, Replacements, TrimSide};
 use crate::Str;
 use nu_errors::ShellError;
 use nu_plugin::Plugin;
@@ -16,10 +16,31 @@ impl Plugin for Str {
         Ok(Signature::build("str")
             .desc("Apply string function. Optional use the column of a table")
             .switch("capitalize", "capitalizes the string", Some('c'))
+            .switch(
+                "title-case",
+                "capitalizes each word of the string",
+                Some('T'),
+            )
             .switch("downcase", "convert string to lowercase", Some('d'))
             .switch("upcase", "convert string to uppercase", Some('U'))
             .switch("to-int", "convert string to integer", Some('i'))
             .switch("trim", "trims the string", Some('t'))
+            .named(
+                "trim-chars",
+                SyntaxShape::String,
+                "trims any of the given characters instead of whitespace",
+                None,
+            )
+            .switch(
+                "left",
+                "with --trim or --trim-chars, trims the start of the string only",
+                None,
+            )
+            .switch(
+                "right",
+                "with --trim or --trim-chars, trims the end of the string only",
+                None,
+            )
             .named(
                 "replace",
                 SyntaxShape::String,
@@ -32,31 +53,167 @@ impl Plugin for Str {
                 "finds and replaces [pattern replacement]",
                 Some('f'),
             )
+            .switch(
+                "all",
+                "with --find-replace, replaces every match (the default)",
+                None,
+            )
+            .switch(
+                "first",
+                "with --find-replace, replaces the first match only",
+                None,
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "with --find-replace, replaces no more than the given number of matches",
+                None,
+            )
+            .switch(
+                "report",
+                "with --find-replace, gives back the number of replacements made instead of the string",
+                None,
+            )
             .named(
                 "substring",
                 SyntaxShape::String,
                 "convert string to portion of original, requires \"start,end\"",
                 Some('s'),
             )
+            .named(
+                "split-at",
+                SyntaxShape::Int,
+                "splits the string into two at the character index (negative counts from the end)",
+                None,
+            )
             .named(
                 "to-date-time",
                 SyntaxShape::String,
                 "Convert string to Date/Time",
                 Some('D'),
             )
+            .named(
+                "pad-left",
+                SyntaxShape::Int,
+                "pads the start of the string up to the given display width",
+                None,
+            )
+            .named(
+                "pad-right",
+                SyntaxShape::Int,
+                "pads the end of the string up to the given display width",
+                None,
+            )
+            .named(
+                "pad-char",
+                SyntaxShape::String,
+                "the character to pad with (defaults to a space)",
+                None,
+            )
+            .named(
+                "index-of",
+                SyntaxShape::String,
+                "the character index of the substring in the string, or -1",
+                None,
+            )
+            .switch(
+                "end",
+                "with --index-of, finds the last occurrence instead of the first",
+                None,
+            )
+            .named(
+                "starts-with",
+                SyntaxShape::String,
+                "checks whether the string starts with the prefix",
+                None,
+            )
+            .named(
+                "ends-with",
+                SyntaxShape::String,
+                "checks whether the string ends with the suffix",
+                None,
+            )
+            .switch("length", "counts the characters in the string", Some('l'))
+            .switch(
+                "graphemes",
+                "with --length, counts grapheme clusters so emoji and combining marks count once",
+                Some('g'),
+            )
+            .named(
+                "expand-tabs",
+                SyntaxShape::Int,
+                "replaces tabs with spaces up to the next tab stop of the given width",
+                None,
+            )
+            .named(
+                "contract-spaces",
+                SyntaxShape::Int,
+                "replaces runs of spaces ending on a tab stop of the given width with tabs",
+                None,
+            )
+            .switch("to-bytes", "converts the string to its UTF-8 bytes", None)
+            .switch(
+                "from-bytes",
+                "converts UTF-8 bytes back to a string, erroring on invalid bytes",
+                None,
+            )
+            .switch(
+                "lossy",
+                "with --from-bytes, replaces invalid bytes with U+FFFD instead of erroring",
+                None,
+            )
             .rest(SyntaxShape::ColumnPath, "the column(s) to convert")
             .filter())
     }
 
     fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let name_tag = call_info.name_tag;
         let args = call_info.args;
 
-        if args.has("trim") {
-            self.for_trim();
+        if args.has("graphemes") && !args.has("length") {
+            return Err(ShellError::labeled_error(
+                "Expected --length with --graphemes",
+                "only --length can count graphemes",
+                &name_tag,
+            ));
+        }
+
+        if args.has("lossy") && !args.has("from-bytes") {
+            return Err(ShellError::labeled_error(
+                "Expected --from-bytes with --lossy",
+                "only --from-bytes can replace invalid bytes",
+                &name_tag,
+            ));
+        }
+
+        if (args.has("left") || args.has("right")) && !(args.has("trim") || args.has("trim-chars"))
+        {
+            return Err(ShellError::labeled_error(
+                "Expected --trim or --trim-chars with --left or --right",
+                "only trimming can be done on one side",
+                &name_tag,
+            ));
+        }
+
+        if args.has("trim") || args.has("trim-chars") {
+            let side = match (args.has("left"), args.has("right")) {
+                (true, false) => TrimSide::Left,
+                (false, true) => TrimSide::Right,
+                _ => TrimSide::Both,
+            };
+            let chars = match args.get("trim-chars") {
+                Some(chars) => Some(chars.as_string()?),
+                None => None,
+            };
+
+            self.for_trim(side, chars);
         }
         if args.has("capitalize") {
             self.for_capitalize();
         }
+        if args.has("title-case") {
+            self.for_title_case();
+        }
         if args.has("downcase") {
             self.for_downcase();
         }
@@ -85,6 +242,9 @@ impl Plugin for Str {
                 }
             }
         }
+        if let Some(index) = args.get("split-at") {
+            self.for_split_at(index.as_i64()?);
+        }
         if args.has("replace") {
             if let Some(Value {
                 value: UntaggedValue::Primitive(Primitive::String(replacement)),
@@ -96,6 +256,23 @@ impl Plugin for Str {
         }
 
         if args.has("find-replace") {
+            let limit = match (args.has("all"), args.has("first"), args.get("count")) {
+                (_, false, None) => None,
+                (false, true, None) => Some(1),
+                (false, false, Some(count)) => Some(count.as_u64()? as usize),
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Conflicting flags: --all, --first and --count",
+                        "use only one of --all, --first or --count",
+                        &name_tag,
+                    ))
+                }
+            };
+            let replacements = Replacements {
+                limit,
+                report: args.has("report"),
+            };
+
             if let Some(Value {
                 value: UntaggedValue::Table(arguments),
                 tag,
@@ -122,10 +299,68 @@ impl Plugin for Str {
                             )
                         })?
                         .as_string()?,
+                    replacements,
                 ));
             }
         }
 
+        if args.has("pad-left") || args.has("pad-right") {
+            let character = match args.get("pad-char") {
+                Some(pad_char) => {
+                    let s = pad_char.as_string()?;
+                    let mut chars = s.chars();
+
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => c,
+                        _ => {
+                            return Err(ShellError::labeled_error(
+                                "Pad character must be a single character",
+                                "expected a single character",
+                                &pad_char.tag,
+                            ))
+                        }
+                    }
+                }
+                None => ' ',
+            };
+
+            if let Some(width) = args.get("pad-left") {
+                self.for_pad_left(width.as_u64()? as usize, character);
+            }
+            if let Some(width) = args.get("pad-right") {
+                self.for_pad_right(width.as_u64()? as usize, character);
+            }
+        }
+
+        if let Some(substring) = args.get("index-of") {
+            self.for_index_of(substring.as_string()?, args.has("end"));
+        }
+
+        if let Some(prefix) = args.get("starts-with") {
+            self.for_starts_with(prefix.as_string()?);
+        }
+        if let Some(suffix) = args.get("ends-with") {
+            self.for_ends_with(suffix.as_string()?);
+        }
+
+        if args.has("length") {
+            self.for_length(args.has("graphemes"));
+        }
+
+        if let Some(width) = args.get("expand-tabs") {
+            self.for_expand_tabs(width.as_u64()? as usize);
+        }
+        if let Some(width) = args.get("contract-spaces") {
+            self.for_contract_spaces(width.as_u64()? as usize);
+        }
+
+        if args.has("to-bytes") {
+            self.for_to_bytes();
+        }
+        if args.has("from-bytes") {
+            self.for_from_bytes(args.has("lossy"));
+        }
+
         if let Some(possible_field) = args.nth(0) {
             let possible_field = possible_field.as_column_path()?;
             self.for_field(possible_field);
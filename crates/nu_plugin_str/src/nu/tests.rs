@@ -1,5 +1,5 @@
 mod integration {
-    use crate::strutils::{Action, ReplaceAction};
+    use crate::strutils::{Action, ReplaceAction, Replacements, TrimSide};
     use crate::Str;
     use nu_errors::ShellError;
     use nu_plugin::test_helpers::value::{
@@ -60,7 +60,21 @@ mod integration {
     fn picks_up_trim_flag() {
         plugin(&mut Str::new())
             .args(CallStub::new().with_long_flag("trim").create())
-            .setup(|plugin, _| plugin.expect_action(Action::Trim));
+            .setup(|plugin, _| plugin.expect_action(Action::Trim(TrimSide::Both, None)));
+    }
+
+    #[test]
+    fn picks_up_trim_chars_with_a_side() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("trim-chars", string("xyz"))
+                    .with_long_flag("left")
+                    .create(),
+            )
+            .setup(|plugin, _| {
+                plugin.expect_action(Action::Trim(TrimSide::Left, Some("xyz".to_string())))
+            });
     }
 
     #[test]
@@ -70,6 +84,76 @@ mod integration {
             .setup(|plugin, _| plugin.expect_action(Action::Capitalize));
     }
 
+    #[test]
+    fn picks_up_length_flag() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("length")
+                    .with_long_flag("graphemes")
+                    .create(),
+            )
+            .setup(|plugin, _| plugin.expect_action(Action::Length(true)));
+    }
+
+    #[test]
+    fn picks_up_split_at_flag() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("split-at", int(-2))
+                    .create(),
+            )
+            .setup(|plugin, _| plugin.expect_action(Action::SplitAt(-2)));
+    }
+
+    #[test]
+    fn splits_the_input_into_two_strings_at_the_index() {
+        let run = plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("split-at", int(2))
+                    .create(),
+            )
+            .input(string("nushell"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, table(&[string("nu"), string("shell")]));
+    }
+
+    #[test]
+    fn picks_up_from_bytes_flag() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("from-bytes")
+                    .with_long_flag("lossy")
+                    .create(),
+            )
+            .setup(|plugin, _| plugin.expect_action(Action::FromBytes(true)));
+    }
+
+    #[test]
+    fn picks_up_expand_tabs_width() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("expand-tabs", int(4))
+                    .create(),
+            )
+            .setup(|plugin, _| plugin.expect_action(Action::ExpandTabs(4)));
+    }
+
+    #[test]
+    fn picks_up_title_case_flag() {
+        plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("title-case").create())
+            .setup(|plugin, _| plugin.expect_action(Action::TitleCase));
+    }
+
     #[test]
     fn picks_up_downcase_flag() {
         plugin(&mut Str::new())
@@ -122,11 +206,51 @@ mod integration {
                     .create(),
             )
             .setup(|plugin, _| {
-                let strategy = ReplaceAction::FindAndReplace(search_argument, replace_argument);
+                let strategy = ReplaceAction::FindAndReplace(
+                    search_argument,
+                    replace_argument,
+                    Replacements::default(),
+                );
+                plugin.expect_action(Action::Replace(strategy))
+            });
+    }
+
+    #[test]
+    fn picks_up_the_replacement_limit_and_report_flags() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("find-replace", table(&[string("a"), string("o")]))
+                    .with_named_parameter("count", int(2))
+                    .with_long_flag("report")
+                    .create(),
+            )
+            .setup(|plugin, _| {
+                let strategy = ReplaceAction::FindAndReplace(
+                    "a".to_string(),
+                    "o".to_string(),
+                    Replacements {
+                        limit: Some(2),
+                        report: true,
+                    },
+                );
                 plugin.expect_action(Action::Replace(strategy))
             });
     }
 
+    #[test]
+    fn errors_when_first_and_count_are_both_given() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("find-replace", table(&[string("a"), string("o")]))
+                    .with_long_flag("first")
+                    .with_named_parameter("count", int(2))
+                    .create(),
+            )
+            .setup(|_, result| assert!(result.is_err()));
+    }
+
     #[test]
     fn picks_up_argument_for_field() -> Result<(), ShellError> {
         plugin(&mut Str::new())
@@ -325,6 +449,22 @@ mod integration {
         assert_eq!(actual, string("andres"));
     }
 
+    #[test]
+    fn trims_the_given_characters_from_the_input() {
+        let run = plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("trim-chars", string("xy"))
+                    .create(),
+            )
+            .input(unstructured_sample_record("xyandresyx"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+        assert_eq!(actual, string("andres"));
+    }
+
     #[test]
     fn capitalizes_the_input() {
         let run = plugin(&mut Str::new())
@@ -1,29 +1,58 @@
 extern crate chrono;
 
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use nu_errors::ShellError;
 use nu_protocol::{did_you_mean, ColumnPath, Primitive, ShellTypeName, UntaggedValue, Value};
-use nu_source::{span_for_spanned_list, Tagged};
+use nu_source::{span_for_spanned_list, Tag, Tagged};
 use nu_value_ext::ValueExt;
 use regex::Regex;
 use std::cmp;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Action {
     Capitalize,
+    TitleCase,
     Downcase,
     Upcase,
     ToInteger,
     Substring(usize, usize),
+    SplitAt(i64),
     Replace(ReplaceAction),
     ToDateTime(String),
-    Trim,
+    Trim(TrimSide, Option<String>),
+    PadLeft(usize, char),
+    PadRight(usize, char),
+    IndexOf(String, bool),
+    StartsWith(String),
+    EndsWith(String),
+    Length(bool),
+    ToBytes,
+    FromBytes(bool),
+    ExpandTabs(usize),
+    ContractSpaces(usize),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum TrimSide {
+    Both,
+    Left,
+    Right,
 }
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ReplaceAction {
     Direct(String),
-    FindAndReplace(String, String),
+    FindAndReplace(String, String, Replacements),
+}
+
+/// Which matches a find-and-replace replaces: all of them, unless limited to the first
+/// `limit`. With `report`, the number of replacements is given back instead of the string.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Replacements {
+    pub limit: Option<usize>,
+    pub report: bool,
 }
 
 #[derive(Default)]
@@ -40,21 +69,36 @@ impl Str {
 
     fn apply(&self, input: &str) -> Result<UntaggedValue, ShellError> {
         let applied = match self.action.as_ref() {
-            Some(Action::Trim) => UntaggedValue::string(input.trim()),
-            Some(Action::Capitalize) => {
-                let mut capitalized = String::new();
-
-                for (idx, character) in input.chars().enumerate() {
-                    let out = if idx == 0 {
-                        character.to_uppercase().to_string()
+            Some(Action::Trim(side, chars)) => {
+                let trimmed = |c: char| match chars {
+                    Some(chars) => chars.contains(c),
+                    None => c.is_whitespace(),
+                };
+
+                UntaggedValue::string(match side {
+                    TrimSide::Both => input.trim_matches(trimmed),
+                    TrimSide::Left => input.trim_start_matches(trimmed),
+                    TrimSide::Right => input.trim_end_matches(trimmed),
+                })
+            }
+            Some(Action::Capitalize) => UntaggedValue::string(capitalize(input)),
+            Some(Action::TitleCase) => {
+                let mut titled = String::new();
+                let mut word = String::new();
+
+                for character in input.chars() {
+                    if character.is_whitespace() {
+                        titled.push_str(&capitalize(&word));
+                        titled.push(character);
+                        word.clear();
                     } else {
-                        character.to_lowercase().to_string()
-                    };
-
-                    capitalized.push_str(&out);
+                        word.push(character);
+                    }
                 }
 
-                UntaggedValue::string(capitalized)
+                titled.push_str(&capitalize(&word));
+
+                UntaggedValue::string(titled)
             }
             Some(Action::Downcase) => UntaggedValue::string(input.to_ascii_lowercase()),
             Some(Action::Upcase) => UntaggedValue::string(input.to_ascii_uppercase()),
@@ -73,15 +117,35 @@ impl Str {
                     )
                 }
             }
+            Some(Action::SplitAt(index)) => {
+                let (left, right) = split_at(input, *index);
+
+                UntaggedValue::table(&[
+                    UntaggedValue::string(left).into_untagged_value(),
+                    UntaggedValue::string(right).into_untagged_value(),
+                ])
+            }
             Some(Action::Replace(mode)) => match mode {
                 ReplaceAction::Direct(replacement) => UntaggedValue::string(replacement.as_str()),
-                ReplaceAction::FindAndReplace(find, replacement) => {
+                ReplaceAction::FindAndReplace(find, replacement, replacements) => {
                     let regex = Regex::new(find.as_str());
 
                     match regex {
-                        Ok(re) => UntaggedValue::string(
-                            re.replace(input, replacement.as_str()).to_owned(),
-                        ),
+                        Ok(re) => {
+                            let limit = replacements.limit.unwrap_or(usize::max_value());
+                            let made = re.find_iter(input).take(limit).count();
+
+                            if replacements.report {
+                                UntaggedValue::int(made as i64)
+                            } else if made == 0 {
+                                // `replacen` treats a limit of 0 as no limit at all
+                                UntaggedValue::string(input)
+                            } else {
+                                UntaggedValue::string(
+                                    re.replacen(input, made, replacement.as_str()).to_owned(),
+                                )
+                            }
+                        }
                         Err(_) => UntaggedValue::string(input),
                     }
                 }
@@ -94,15 +158,63 @@ impl Str {
                 }
             }
             Some(Action::ToDateTime(dt)) => match DateTime::parse_from_str(input, dt) {
-                Ok(d) => UntaggedValue::date(d),
+                Ok(d) => UntaggedValue::date(d.with_timezone(&Utc)),
                 Err(_) => UntaggedValue::string(input),
             },
-            None => UntaggedValue::string(input),
+            Some(Action::PadLeft(width, character)) => {
+                UntaggedValue::string(format!("{}{}", padding(input, *width, *character), input))
+            }
+            Some(Action::PadRight(width, character)) => {
+                UntaggedValue::string(format!("{}{}", input, padding(input, *width, *character)))
+            }
+            Some(Action::IndexOf(substring, from_end)) => {
+                let found = if *from_end {
+                    input.rfind(substring.as_str())
+                } else {
+                    input.find(substring.as_str())
+                };
+
+                match found {
+                    Some(byte_idx) => UntaggedValue::int(input[..byte_idx].chars().count()),
+                    None => UntaggedValue::int(-1),
+                }
+            }
+            Some(Action::StartsWith(prefix)) => UntaggedValue::boolean(input.starts_with(prefix)),
+            Some(Action::EndsWith(suffix)) => UntaggedValue::boolean(input.ends_with(suffix)),
+            Some(Action::Length(graphemes)) => {
+                if *graphemes {
+                    UntaggedValue::int(input.graphemes(true).count())
+                } else {
+                    UntaggedValue::int(input.chars().count())
+                }
+            }
+            Some(Action::ExpandTabs(width)) => UntaggedValue::string(expand_tabs(input, *width)),
+            Some(Action::ContractSpaces(width)) => {
+                UntaggedValue::string(contract_spaces(input, *width))
+            }
+            Some(Action::ToBytes) => UntaggedValue::binary(input.as_bytes().to_vec()),
+            Some(Action::FromBytes(_)) | None => UntaggedValue::string(input),
         };
 
         Ok(applied)
     }
 
+    fn apply_bytes(&self, input: &[u8], tag: &Tag) -> Result<UntaggedValue, ShellError> {
+        match self.action {
+            Some(Action::FromBytes(true)) => {
+                Ok(UntaggedValue::string(String::from_utf8_lossy(input)))
+            }
+            _ => match std::str::from_utf8(input) {
+                Ok(s) => Ok(UntaggedValue::string(s)),
+                Err(_) => Err(ShellError::labeled_error(
+                    "Invalid UTF-8",
+                    "these bytes aren't valid UTF-8 (use --lossy to replace the invalid ones)",
+                    tag,
+                )),
+            },
+        }
+    }
+
     pub fn for_field(&mut self, column_path: Tagged<ColumnPath>) {
         self.field = Some(column_path);
     }
@@ -123,8 +235,12 @@ impl Str {
         self.add_action(Action::Capitalize);
     }
 
-    pub fn for_trim(&mut self) {
-        self.add_action(Action::Trim);
+    pub fn for_title_case(&mut self) {
+        self.add_action(Action::TitleCase);
+    }
+
+    pub fn for_trim(&mut self, side: TrimSide, chars: Option<String>) {
+        self.add_action(Action::Trim(side, chars));
     }
 
     pub fn for_downcase(&mut self) {
@@ -160,6 +276,10 @@ impl Str {
         Ok(())
     }
 
+    pub fn for_split_at(&mut self, index: i64) {
+        self.add_action(Action::SplitAt(index));
+    }
+
     pub fn for_replace(&mut self, mode: ReplaceAction) {
         self.add_action(Action::Replace(mode));
     }
@@ -168,6 +288,57 @@ impl Str {
         self.add_action(Action::ToDateTime(dt));
     }
 
+    pub fn for_pad_left(&mut self, width: usize, character: char) {
+        self.add_action(Action::PadLeft(width, character));
+    }
+
+    pub fn for_pad_right(&mut self, width: usize, character: char) {
+        self.add_action(Action::PadRight(width, character));
+    }
+
+    pub fn for_index_of(&mut self, substring: String, from_end: bool) {
+        self.add_action(Action::IndexOf(substring, from_end));
+    }
+
+    pub fn for_starts_with(&mut self, prefix: String) {
+        self.add_action(Action::StartsWith(prefix));
+    }
+
+    pub fn for_ends_with(&mut self, suffix: String) {
+        self.add_action(Action::EndsWith(suffix));
+    }
+
+    pub fn for_length(&mut self, graphemes: bool) {
+        self.add_action(Action::Length(graphemes));
+    }
+
+    pub fn for_expand_tabs(&mut self, width: usize) {
+        self.add_action(Action::ExpandTabs(width));
+    }
+
+    pub fn for_contract_spaces(&mut self, width: usize) {
+        self.add_action(Action::ContractSpaces(width));
+    }
+
+    pub fn for_to_bytes(&mut self) {
+        self.add_action(Action::ToBytes);
+    }
+
+    pub fn for_from_bytes(&mut self, lossy: bool) {
+        self.add_action(Action::FromBytes(lossy));
+    }
+
+    fn is_padding(&self) -> bool {
+        matches!(
+            self.action,
+            Some(Action::PadLeft(..)) | Some(Action::PadRight(..))
+        )
+    }
+
+    fn is_from_bytes(&self) -> bool {
+        matches!(self.action, Some(Action::FromBytes(_)))
+    }
+
     fn add_action(&mut self, act: Action) {
         if self.permit() {
             self.action = Some(act);
@@ -177,7 +348,7 @@ impl Str {
     }
 
     pub fn usage() -> &'static str {
-        "Usage: str field [--capitalize|--downcase|--upcase|--to-int|--substring \"start,end\"|--replace|--find-replace [pattern replacement]|to-date-time|--trim]"
+        "Usage: str field [--capitalize|--title-case|--downcase|--upcase|--to-int|--substring \"start,end\"|--split-at index|--replace|--find-replace [pattern replacement]|to-date-time|--trim [--left|--right]|--trim-chars chars [--left|--right]|--pad-left width|--pad-right width|--index-of substring [--end]|--starts-with prefix|--ends-with suffix|--length [--graphemes]|--to-bytes|--from-bytes [--lossy]|--expand-tabs width|--contract-spaces width]"
     }
 
     pub fn strutils(&self, value: Value) -> Result<Value, ShellError> {
@@ -188,6 +359,15 @@ impl Str {
             UntaggedValue::Primitive(Primitive::Line(ref s)) => {
                 Ok(self.apply(&s)?.into_value(value.tag()))
             }
+            UntaggedValue::Primitive(Primitive::Int(ref i)) if self.is_padding() => {
+                Ok(self.apply(&i.to_string())?.into_value(value.tag()))
+            }
+            UntaggedValue::Primitive(Primitive::Decimal(ref d)) if self.is_padding() => {
+                Ok(self.apply(&d.to_string())?.into_value(value.tag()))
+            }
+            UntaggedValue::Primitive(Primitive::Binary(ref b)) if self.is_from_bytes() => {
+                Ok(self.apply_bytes(b, &value.tag)?.into_value(value.tag()))
+            }
             UntaggedValue::Row(_) => match self.field {
                 Some(ref f) => {
                     let fields = f.clone();
@@ -236,20 +416,162 @@ impl Str {
     }
 }
 
+fn capitalize(input: &str) -> String {
+    let mut capitalized = String::new();
+
+    for (idx, character) in input.chars().enumerate() {
+        let out = if idx == 0 {
+            character.to_uppercase().to_string()
+        } else {
+            character.to_lowercase().to_string()
+        };
+
+        capitalized.push_str(&out);
+    }
+
+    capitalized
+}
+
+/// Splits at a character index, counting from the end when negative. Indices past either
+/// end are clamped, so one of the pieces comes back empty.
+fn split_at(input: &str, index: i64) -> (&str, &str) {
+    let len = input.chars().count() as i64;
+    let index = if index < 0 { len + index } else { index };
+    let index = cmp::max(0, cmp::min(index, len)) as usize;
+
+    let byte_idx = input
+        .char_indices()
+        .nth(index)
+        .map(|(byte_idx, _)| byte_idx)
+        .unwrap_or_else(|| input.len());
+
+    input.split_at(byte_idx)
+}
+
+/// The padding to bring `input` up to `width` columns on screen. Widths are display widths, so
+/// wide characters like CJK and most emoji count as two columns and padded text lines up.
+fn padding(input: &str, width: usize, character: char) -> String {
+    let len = UnicodeWidthStr::width(input);
+    let character_width = cmp::max(UnicodeWidthChar::width(character).unwrap_or(1), 1);
+
+    std::iter::repeat(character)
+        .take(width.saturating_sub(len) / character_width)
+        .collect()
+}
+
+/// Replaces each tab with the spaces needed to reach the next tab stop, so text after
+/// the tab keeps its column.
+fn expand_tabs(input: &str, width: usize) -> String {
+    let mut expanded = String::new();
+    let mut column = 0;
+
+    for character in input.chars() {
+        match character {
+            '\t' if width > 0 => {
+                let spaces = width - column % width;
+                expanded.extend(std::iter::repeat(' ').take(spaces));
+                column += spaces;
+            }
+            '\t' => {}
+            '\n' => {
+                expanded.push(character);
+                column = 0;
+            }
+            _ => {
+                expanded.push(character);
+                column += 1;
+            }
+        }
+    }
+
+    expanded
+}
+
+/// Replaces runs of spaces that end on a tab stop with a tab, the reverse of `expand_tabs`.
+/// A lone space before a tab stop is left alone since a tab would save nothing.
+fn contract_spaces(input: &str, width: usize) -> String {
+    if width == 0 {
+        return input.to_string();
+    }
+
+    let mut contracted = String::new();
+    let mut column = 0;
+    let mut pending = 0;
+
+    for character in input.chars() {
+        match character {
+            ' ' => {
+                pending += 1;
+                column += 1;
+
+                if column % width == 0 {
+                    if pending > 1 {
+                        contracted.push('\t');
+                    } else {
+                        contracted.push(' ');
+                    }
+                    pending = 0;
+                }
+            }
+            '\t' => {
+                contracted.push('\t');
+                column += width - column % width;
+                pending = 0;
+            }
+            _ => {
+                contracted.extend(std::iter::repeat(' ').take(pending));
+                contracted.push(character);
+                pending = 0;
+                column = if character == '\n' { 0 } else { column + 1 };
+            }
+        }
+    }
+
+    contracted.extend(std::iter::repeat(' ').take(pending));
+    contracted
+}
+
 #[cfg(test)]
 pub mod tests {
-    use super::ReplaceAction;
     use super::Str;
-    use nu_plugin::test_helpers::value::{int, string};
+    use super::{contract_spaces, expand_tabs, ReplaceAction, Replacements, TrimSide};
+    use nu_plugin::test_helpers::value::{int, string, table};
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+    use unicode_width::UnicodeWidthStr;
 
     #[test]
     fn trim() -> Result<(), Box<dyn std::error::Error>> {
         let mut strutils = Str::new();
-        strutils.for_trim();
+        strutils.for_trim(TrimSide::Both, None);
         assert_eq!(strutils.apply("andres ")?, string("andres").value);
         Ok(())
     }
 
+    #[test]
+    fn trims_the_given_characters() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_trim(TrimSide::Both, Some("-*".to_string()));
+        assert_eq!(strutils.apply("*-andres-*-")?, string("andres").value);
+        Ok(())
+    }
+
+    #[test]
+    fn trims_the_given_characters_from_one_side() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_trim(TrimSide::Right, Some("0".to_string()));
+        assert_eq!(strutils.apply("0.500")?, string("0.5").value);
+        Ok(())
+    }
+
+    #[test]
+    fn trims_whitespace_from_the_left_only() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_trim(TrimSide::Left, None);
+        assert_eq!(strutils.apply("  andres  ")?, string("andres  ").value);
+        Ok(())
+    }
+
     #[test]
     fn capitalize() -> Result<(), Box<dyn std::error::Error>> {
         let mut strutils = Str::new();
@@ -258,6 +580,25 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn capitalize_lowercases_the_rest() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_capitalize();
+        assert_eq!(strutils.apply("aNDRES")?, string("Andres").value);
+        Ok(())
+    }
+
+    #[test]
+    fn title_cases() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_title_case();
+        assert_eq!(
+            strutils.apply("the nu\tSHELL  project")?,
+            string("The Nu\tShell  Project").value
+        );
+        Ok(())
+    }
+
     #[test]
     fn downcases() -> Result<(), Box<dyn std::error::Error>> {
         let mut strutils = Str::new();
@@ -282,6 +623,237 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn pads_left_with_zeros() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_pad_left(5, '0');
+        assert_eq!(strutils.apply("42")?, string("00042").value);
+        Ok(())
+    }
+
+    #[test]
+    fn pads_right_with_spaces() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_pad_right(5, ' ');
+        assert_eq!(strutils.apply("nu")?, string("nu   ").value);
+        Ok(())
+    }
+
+    #[test]
+    fn padding_leaves_values_at_or_over_the_width_unchanged(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_pad_left(3, '0');
+        assert_eq!(strutils.apply("12345")?, string("12345").value);
+        Ok(())
+    }
+
+    #[test]
+    fn pads_wide_characters_by_display_width() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_pad_right(6, ' ');
+        assert_eq!(strutils.apply("日本")?, string("日本  ").value);
+
+        let mut strutils = Str::new();
+        strutils.for_pad_left(4, ' ');
+        assert_eq!(strutils.apply("\u{1F980}")?, string("  \u{1F980}").value);
+        Ok(())
+    }
+
+    #[test]
+    fn padded_wide_and_narrow_values_line_up() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_pad_right(8, '.');
+
+        for input in &["nu", "日本語", "\u{1F980} crab", "Andrés"] {
+            match strutils.apply(input)? {
+                UntaggedValue::Primitive(Primitive::String(padded)) => {
+                    assert_eq!(UnicodeWidthStr::width(padded.as_str()), 8)
+                }
+                other => panic!("expected a string, got {:?}", other),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn splits_at_a_character_index() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_split_at(2);
+        assert_eq!(
+            strutils.apply("nushell")?,
+            table(&[string("nu"), string("shell")]).value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn split_at_clamps_an_out_of_range_index() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_split_at(20);
+        assert_eq!(
+            strutils.apply("Andrés")?,
+            table(&[string("Andrés"), string("")]).value
+        );
+
+        let mut strutils = Str::new();
+        strutils.for_split_at(-20);
+        assert_eq!(
+            strutils.apply("Andrés")?,
+            table(&[string(""), string("Andrés")]).value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn split_at_a_negative_index_counts_from_the_end() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_split_at(-2);
+        assert_eq!(
+            strutils.apply("Andrés")?,
+            table(&[string("Andr"), string("és")]).value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn finds_the_index_of_a_substring() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_index_of("sh".to_string(), false);
+        assert_eq!(strutils.apply("nushell shell")?, int(2).value);
+        Ok(())
+    }
+
+    #[test]
+    fn index_of_an_absent_substring_is_negative_one() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_index_of("zsh".to_string(), false);
+        assert_eq!(strutils.apply("nushell")?, int(-1).value);
+        Ok(())
+    }
+
+    #[test]
+    fn finds_the_last_index_of_a_substring() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_index_of("sh".to_string(), true);
+        assert_eq!(strutils.apply("nushell shell")?, int(8).value);
+        Ok(())
+    }
+
+    #[test]
+    fn checks_a_matching_prefix() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_starts_with("nu".to_string());
+        assert_eq!(strutils.apply("nushell")?, UntaggedValue::boolean(true));
+        Ok(())
+    }
+
+    #[test]
+    fn checks_a_non_matching_suffix() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_ends_with("bash".to_string());
+        assert_eq!(strutils.apply("nushell")?, UntaggedValue::boolean(false));
+        Ok(())
+    }
+
+    #[test]
+    fn counts_the_characters_of_an_ascii_string() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_length(false);
+        assert_eq!(strutils.apply("nushell")?, int(7).value);
+        Ok(())
+    }
+
+    #[test]
+    fn counts_characters_rather_than_bytes() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_length(false);
+        assert_eq!(strutils.apply("Andrés")?, int(6).value);
+        Ok(())
+    }
+
+    #[test]
+    fn counts_an_emoji_cluster_as_one_grapheme() -> Result<(), Box<dyn std::error::Error>> {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+        let mut strutils = Str::new();
+        strutils.for_length(false);
+        assert_eq!(strutils.apply(family)?, int(5).value);
+
+        let mut strutils = Str::new();
+        strutils.for_length(true);
+        assert_eq!(strutils.apply(family)?, int(1).value);
+        Ok(())
+    }
+
+    #[test]
+    fn converts_a_multibyte_string_to_bytes_and_back() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_to_bytes();
+        let bytes = strutils.apply("Andrés")?;
+        assert_eq!(
+            bytes,
+            UntaggedValue::binary(vec![65, 110, 100, 114, 195, 169, 115])
+        );
+
+        let mut strutils = Str::new();
+        strutils.for_from_bytes(false);
+        assert_eq!(
+            strutils.apply_bytes(&[65, 110, 100, 114, 195, 169, 115], &Tag::unknown())?,
+            string("Andrés").value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors_on_invalid_utf8_bytes() {
+        let mut strutils = Str::new();
+        strutils.for_from_bytes(false);
+        assert!(strutils
+            .apply_bytes(&[b'n', 0xff, b'u'], &Tag::unknown())
+            .is_err());
+    }
+
+    #[test]
+    fn recovers_invalid_utf8_bytes_when_lossy() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_from_bytes(true);
+        assert_eq!(
+            strutils.apply_bytes(&[b'n', 0xff, b'u'], &Tag::unknown())?,
+            string("n\u{FFFD}u").value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expands_tabs_to_the_next_tab_stop() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_expand_tabs(4);
+        assert_eq!(
+            strutils.apply("a\tbc\tdef\n\tx")?,
+            string("a   bc  def\n    x").value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn contracts_spaces_ending_on_a_tab_stop() -> Result<(), Box<dyn std::error::Error>> {
+        let mut strutils = Str::new();
+        strutils.for_contract_spaces(4);
+        assert_eq!(
+            strutils.apply("a   bc  def x   ")?,
+            string("a\tbc\tdef x\t").value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn contracting_undoes_expanding() {
+        let expanded = expand_tabs("fn main()\t{}", 8);
+        assert_eq!(expanded, "fn main()       {}");
+        assert_eq!(contract_spaces(&expanded, 8), "fn main()\t{}");
+    }
+
     #[test]
     fn replaces() -> Result<(), Box<dyn std::error::Error>> {
         let mut strutils = Str::new();
@@ -297,9 +869,60 @@ pub mod tests {
         strutils.for_replace(ReplaceAction::FindAndReplace(
             "kittens".to_string(),
             "jotandrehuda".to_string(),
+            Replacements::default(),
         ));
 
         assert_eq!(strutils.apply("wykittens")?, string("wyjotandrehuda").value);
         Ok(())
     }
+
+    fn find_and_replace(limit: Option<usize>, report: bool) -> Str {
+        let mut strutils = Str::new();
+
+        strutils.for_replace(ReplaceAction::FindAndReplace(
+            "a".to_string(),
+            "o".to_string(),
+            Replacements { limit, report },
+        ));
+
+        strutils
+    }
+
+    #[test]
+    fn find_and_replaces_every_match_by_default() -> Result<(), Box<dyn std::error::Error>> {
+        let strutils = find_and_replace(None, false);
+        assert_eq!(strutils.apply("banana")?, string("bonono").value);
+        Ok(())
+    }
+
+    #[test]
+    fn find_and_replaces_the_first_match_only() -> Result<(), Box<dyn std::error::Error>> {
+        let strutils = find_and_replace(Some(1), false);
+        assert_eq!(strutils.apply("banana")?, string("bonana").value);
+        Ok(())
+    }
+
+    #[test]
+    fn find_and_replaces_up_to_the_count() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            find_and_replace(Some(2), false).apply("banana")?,
+            string("bonona").value
+        );
+        assert_eq!(
+            find_and_replace(Some(0), false).apply("banana")?,
+            string("banana").value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reports_the_number_of_replacements() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(find_and_replace(None, true).apply("banana")?, int(3).value);
+        assert_eq!(
+            find_and_replace(Some(2), true).apply("banana")?,
+            int(2).value
+        );
+        assert_eq!(find_and_replace(None, true).apply("kiwi")?, int(0).value);
+        Ok(())
+    }
 }
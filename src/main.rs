@@ -101,20 +101,30 @@ fn main() -> Result<(), Box<dyn Error>> {
         Some(script) => {
             let file = File::open(script)?;
             let reader = BufReader::new(file);
-            let pipelines: Vec<String> = reader
-                .lines()
-                .filter_map(|x| {
-                    if let Ok(x) = x {
-                        if !x.starts_with('#') {
-                            Some(x)
-                        } else {
-                            None
-                        }
+            let lines = reader.lines().filter_map(|x| {
+                if let Ok(x) = x {
+                    if !x.starts_with('#') {
+                        Some(x)
                     } else {
                         None
                     }
-                })
-                .collect();
+                } else {
+                    None
+                }
+            });
+
+            // A line ending in `|`, or with a bracket left open, carries its pipeline on to the
+            // next line
+            let mut pipelines: Vec<String> = vec![];
+            for line in lines {
+                match pipelines.last_mut() {
+                    Some(pipeline) if nu_parser::continues_on_next_line(pipeline) => {
+                        pipeline.push('\n');
+                        pipeline.push_str(&line);
+                    }
+                    _ => pipelines.push(line),
+                }
+            }
 
             futures::executor::block_on(nu_cli::run_vec_of_pipelines(
                 pipelines,
@@ -9,7 +9,37 @@ fn can_only_apply_one() {
         "open caco3_plastics.csv | first 1 | str origin --downcase --upcase"
     );
 
-    assert!(actual.contains(r#"--capitalize|--downcase|--upcase|--to-int|--substring "start,end"|--replace|--find-replace [pattern replacement]|to-date-time|--trim]"#));
+    assert!(actual.contains(r#"Usage: str field [--capitalize|--title-case|--downcase|--upcase|--to-int|--substring "start,end"|--split-at index|--replace|--find-replace [pattern replacement]|to-date-time|--trim [--left|--right]|--trim-chars chars [--left|--right]|--pad-left width|--pad-right width|--index-of substring [--end]|--starts-with prefix|--ends-with suffix|--length [--graphemes]|--to-bytes|--from-bytes [--lossy]|--expand-tabs width|--contract-spaces width]"#));
+}
+
+#[test]
+fn errors_on_graphemes_without_length() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        "open caco3_plastics.csv | first 1 | str origin --graphemes"
+    );
+
+    assert!(actual.contains("Expected --length with --graphemes"));
+}
+
+#[test]
+fn errors_on_a_trim_side_without_trimming() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        "open caco3_plastics.csv | first 1 | str origin --left"
+    );
+
+    assert!(actual.contains("Expected --trim or --trim-chars with --left or --right"));
+}
+
+#[test]
+fn errors_on_lossy_without_from_bytes() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats",
+        "open caco3_plastics.csv | first 1 | str origin --lossy"
+    );
+
+    assert!(actual.contains("Expected --from-bytes with --lossy"));
 }
 
 #[test]
@@ -73,6 +103,30 @@ fn capitalizes() {
     })
 }
 
+#[test]
+fn title_cases() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "the NU shell"
+            | str --title-case
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "The Nu Shell");
+}
+
+#[test]
+fn capitalize_errors_on_non_string_values() {
+    let actual = nu_error!(
+        cwd: ".",
+        "echo 5 | str --capitalize"
+    );
+
+    assert!(actual.contains("Unrecognized type in stream"));
+}
+
 #[test]
 fn downcases() {
     Playground::setup("plugin_str_test_4", |dirs, sandbox| {
@@ -205,3 +259,90 @@ fn find_and_replaces_without_passing_field() {
         assert_eq!(actual, "1-800-5289");
     })
 }
+
+#[test]
+fn pads_left_with_zeros() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 42
+            | str --pad-left 5 --pad-char "0"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "00042");
+}
+
+#[test]
+fn pads_right_with_spaces() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu"
+            | str --pad-right 5
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#""nu   ""#);
+}
+
+#[test]
+fn padding_leaves_over_width_values_unchanged() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nushell"
+            | str --pad-left 3 --pad-char "*"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nushell");
+}
+
+#[test]
+fn finds_the_index_of_a_substring() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nushell shell"
+            | str --index-of "sh"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+}
+
+#[test]
+fn finds_the_last_index_of_a_substring() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nushell shell"
+            | str --index-of "sh" --end
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "8");
+}
+
+#[test]
+fn checks_a_column_for_a_prefix() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | str importer --starts-with "PLASTI"
+            | where importer == $true
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "4");
+}
@@ -20,6 +20,27 @@ fn shows_error_for_command_not_found() {
     assert!(actual.contains("Command not found"));
 }
 
+#[test]
+fn suggests_close_command_name_for_typo() {
+    let actual = nu_error!(
+        cwd: ".",
+        "echo [1 2] | whre $it > 1"
+    );
+
+    assert!(actual.contains("did you mean `where`?"));
+}
+
+#[test]
+fn does_not_suggest_command_name_when_nothing_is_close() {
+    let actual = nu_error!(
+        cwd: ".",
+        "ferris_is_not_here.exe"
+    );
+
+    assert!(actual.contains("Command not found"));
+    assert!(!actual.contains("did you mean"));
+}
+
 #[test]
 fn automatically_change_directory() {
     use nu_test_support::playground::Playground;
@@ -251,3 +272,23 @@ mod tilde_expansion {
         assert_eq!(actual, "1~1");
     }
 }
+
+#[cfg(unix)]
+mod exec {
+    use super::{nu, nu_error};
+
+    #[test]
+    fn replaces_the_shell_passing_each_argument_through() {
+        let actual = nu!(cwd: ".", r#"exec cococo "a b" c"#);
+
+        // cococo prints each argument on its own line
+        assert_eq!(actual, "a bc");
+    }
+
+    #[test]
+    fn errors_if_the_command_cannot_be_started() {
+        let actual = nu_error!(cwd: ".", "exec ferris_is_not_a_command");
+
+        assert!(actual.contains("Could not exec ferris_is_not_a_command"));
+    }
+}
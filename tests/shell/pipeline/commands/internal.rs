@@ -43,7 +43,7 @@ fn can_process_one_row_from_internal_and_pipes_it_to_stdin_of_external() {
 }
 
 mod parse {
-    use nu_test_support::nu_error;
+    use nu_test_support::{nu, nu_error};
 
     /*
         The debug command's signature is:
@@ -104,6 +104,85 @@ mod parse {
             )
         );
     }
+
+    #[test]
+    fn flat_list_literal() {
+        let actual = nu!(cwd: ".", r#"echo [1, "two", 3] | to-json"#);
+
+        assert_eq!(actual, r#"[1,"two",3]"#);
+    }
+
+    #[test]
+    fn nested_list_literal() {
+        let actual = nu!(cwd: ".", "echo [[1 2] [3]] | to-json");
+
+        assert_eq!(actual, "[[1,2],[3]]");
+    }
+
+    #[test]
+    fn empty_list_literal() {
+        let actual = nu!(cwd: ".", "echo [] | count | echo $it");
+
+        assert_eq!(actual, "0");
+    }
+
+    #[test]
+    fn pipeline_continues_on_the_next_line_after_a_pipe() {
+        let actual = nu!(cwd: ".", "echo a b c |\n count |\n echo $it");
+
+        assert_eq!(actual, "3");
+    }
+
+    #[test]
+    fn pipeline_continues_on_the_next_line_inside_brackets() {
+        let actual = nu!(cwd: ".", "echo [a\n b\n c] | count | echo $it");
+
+        assert_eq!(actual, "3");
+    }
+
+    #[test]
+    fn semicolons_run_each_pipeline_and_show_the_last() {
+        let actual = nu!(cwd: ".", "echo a; echo b");
+
+        assert_eq!(actual, "b");
+    }
+
+    #[test]
+    fn trailing_semicolon_is_ignored() {
+        let actual = nu!(cwd: ".", "echo a;");
+
+        assert_eq!(actual, "a");
+    }
+
+    #[test]
+    fn row_literal() {
+        let actual = nu!(cwd: ".", r#"echo {name: "bob" age: 30} | to-json"#);
+
+        assert_eq!(actual, r#"{"name":"bob","age":30}"#);
+    }
+
+    #[test]
+    fn nested_row_literal() {
+        let actual = nu!(
+            cwd: ".",
+            r#"echo {name: "bob" home: {city: "Paris"}} | get home.city | echo $it"#
+        );
+
+        assert_eq!(actual, "Paris");
+    }
+
+    #[test]
+    fn errors_on_duplicate_row_literal_keys() {
+        let actual = nu_error!(cwd: ".", r#"echo {name: "bob" name: "alice"}"#);
+
+        assert!(
+            actual.contains("unique column name"),
+            format!(
+                "error message '{}' should contain 'unique column name'",
+                actual
+            )
+        );
+    }
 }
 
 mod tilde_expansion {